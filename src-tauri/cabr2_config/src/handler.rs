@@ -142,6 +142,17 @@ pub async fn get_available_languages() -> Result<Vec<LocalizedStringsHeader>> {
 }
 
 pub async fn get_localized_strings(language: String) -> Result<Value> {
+  // the language comes from the clients of the webserver, so it must not point outside of the translation folder
+  if !is_locale(&language)
+    || !get_available_languages()
+      .await?
+      .iter()
+      .any(|header| header.locale == language)
+  {
+    log::warn!("unknown language requested: {:?}", language);
+    return Err(ConfigError::LocalizationNotFound(language));
+  }
+
   let mut translation_path = get_translation_folder();
   translation_path.push(&language);
   let translation_path = translation_path.with_extension("json");
@@ -194,6 +205,14 @@ fn get_translation_folder() -> PathBuf {
   translation_folder
 }
 
+/// Locales are the names of the translation files like `de_de`.
+fn is_locale(language: &str) -> bool {
+  !language.is_empty()
+    && language
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
 #[inline]
 fn get_prompt_folder() -> PathBuf {
   let mut prompt_folder = DATA_DIR.clone();
@@ -290,3 +309,27 @@ fn get_program_data_dir() -> PathBuf {
     program_path.canonicalize().unwrap()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_is_locale() {
+    assert!(is_locale("de_de"));
+    assert!(is_locale("en-us"));
+    for language in [
+      "",
+      "..",
+      "../../etc/passwd",
+      "/etc/passwd",
+      "de_de/../../x",
+      "C:\\x",
+      "de.de",
+    ]
+    .iter()
+    {
+      assert!(!is_locale(language), "{}", language);
+    }
+  }
+}
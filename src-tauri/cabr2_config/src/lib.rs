@@ -7,5 +7,8 @@ mod types;
 #[cfg(feature = "tauri_plugin")]
 pub mod plugin;
 
-pub use handler::{get_hazard_symbols, read_config, DATA_DIR, TMP_DIR};
-pub use types::{BackendConfig, GHSSymbols};
+#[cfg(feature = "webserver")]
+pub mod webserver;
+
+pub use handler::{get_hazard_symbols, read_config, DATA_DIR, PROJECT_DIRS, TMP_DIR};
+pub use types::{BackendConfig, Cache, GHSSymbols, Pdf};
@@ -48,6 +48,10 @@ impl std::convert::From<Global> for FrontendGlobal {
 pub struct BackendConfig {
   pub global: Global,
   pub logging: Logging,
+  #[serde(default)]
+  pub cache: Cache,
+  #[serde(default)]
+  pub pdf: Pdf,
 }
 
 impl BackendConfig {
@@ -56,6 +60,8 @@ impl BackendConfig {
     BackendConfig {
       global: config.global.into(),
       logging: old_config.logging,
+      cache: old_config.cache,
+      pdf: old_config.pdf,
     }
   }
 }
@@ -72,8 +78,10 @@ impl std::default::Default for BackendConfig {
         all: Some(LogLevel::DEBUG),
         cabr2: Some(LogLevel::DEBUG),
         rustls: None,
-        ureq: None,
+        reqwest: None,
       },
+      cache: Cache::default(),
+      pdf: Pdf::default(),
     }
   }
 }
@@ -104,7 +112,30 @@ pub struct Logging {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub rustls: Option<LogLevel>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  pub ureq: Option<LogLevel>,
+  #[serde(alias = "ureq")]
+  pub reqwest: Option<LogLevel>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Cache {
+  pub enabled: bool,
+  /// Hours until a cached entry is fetched again, older entries are only used if the source is not reachable.
+  pub ttl: u64,
+}
+
+impl std::default::Default for Cache {
+  fn default() -> Self {
+    Cache {
+      enabled: true,
+      ttl: 7 * 24,
+    }
+  }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Pdf {
+  /// Lists dangerous combinations of the substances on the second page.
+  pub print_incompatibilities: bool,
 }
 
 /* #endregion */
@@ -117,7 +148,7 @@ pub type GHSSymbols = HashMap<String, String>;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LocalizedStringsHeader {
   name: String,
-  locale: String,
+  pub locale: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
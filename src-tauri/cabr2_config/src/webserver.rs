@@ -0,0 +1,60 @@
+use serde::Deserialize;
+use warp::{reply, Filter, Rejection, Reply};
+
+use cabr2_types::webserver::into_reply;
+
+use crate::handler;
+
+#[derive(Debug, Deserialize)]
+struct LocalizedStringsArguments {
+  language: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PromptHtmlArguments {
+  name: String,
+}
+
+/// Returns all routes of this crate, they are available under `/config/<command>`.
+///
+/// The configuration can only be read, because it belongs to the server and not to the clients.
+pub fn get_routes() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+  let get_program_version = warp::path!("get_program_version")
+    .and(warp::get())
+    .map(|| reply::json(&env!("CARGO_PKG_VERSION")));
+
+  let get_config = warp::path!("get_config")
+    .and(warp::get())
+    .and_then(|| async { Ok::<_, Rejection>(into_reply(handler::get_frontend_config().await)) });
+
+  let get_hazard_symbols = warp::path!("get_hazard_symbols")
+    .and(warp::get())
+    .and_then(|| async { Ok::<_, Rejection>(into_reply(handler::get_hazard_symbols().await)) });
+
+  let get_available_languages = warp::path!("get_available_languages")
+    .and(warp::get())
+    .and_then(|| async { Ok::<_, Rejection>(into_reply(handler::get_available_languages().await)) });
+
+  let get_localized_strings = warp::path!("get_localized_strings")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: LocalizedStringsArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::get_localized_strings(args.language).await))
+    });
+
+  let get_prompt_html = warp::path!("get_prompt_html")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: PromptHtmlArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::get_prompt_html(args.name).await))
+    });
+
+  warp::path("config").and(
+    get_program_version
+      .or(get_config)
+      .or(get_hazard_symbols)
+      .or(get_available_languages)
+      .or(get_localized_strings)
+      .or(get_prompt_html),
+  )
+}
@@ -1,22 +1,32 @@
 mod types;
+mod writer;
 
 use chrono::TimeZone;
 use lazy_static::lazy_static;
 use quick_xml::de::from_reader;
 use regex::Regex;
 
-use cabr2_types::{Amount, Data, Source, SubstanceData, Unit};
+use cabr2_types::{
+  ghs, Amount, Data, Qualifier, Quantity, QuantityValue, Source, SubstanceData, Toxicity, ToxicityType, Unit,
+};
 
 use super::{
   error::{LoadSaveError, Result},
-  types::{CaBr2Document, Header, Loader},
+  types::{CaBr2Document, Header, Loader, OptionKind, OptionSchema, Options, Saver},
 };
-use types::{BerylliumDocument, TemplateCategory};
+use types::{BerylliumDocument, TemplateCategory, LD50};
+
+/// The unit of MAK values that are not given in ppm.
+const MG_PER_M3: &str = "mg/m³";
+/// The unit of densities.
+const G_PER_CM3: &str = "g/cm³";
+/// The option for the language of the statement texts, the language of the ui by default.
+const LANGUAGE: &str = "language";
 
 pub struct Beryllium;
 
 impl Loader for Beryllium {
-  fn load_document(&self, contents: Vec<u8>) -> Result<CaBr2Document> {
+  fn load_document(&self, contents: Vec<u8>, options: &Options) -> Result<CaBr2Document> {
     lazy_static! {
       static ref BEGINNING_OF_TIME: chrono::DateTime<chrono::Utc> = chrono::Utc.ymd(1970, 1, 1).and_hms(0, 0, 0);
       static ref GESTIS_URL_RE: Regex =
@@ -27,6 +37,7 @@ impl Loader for Beryllium {
       Ok(beryllium_doc) => {
         // simplest way for a typedefinition
         let beryllium_doc: BerylliumDocument = beryllium_doc;
+        let language = options.get(LANGUAGE)?.unwrap_or(options.language);
         Ok(CaBr2Document {
           disposal: get_templates_with_category(&beryllium_doc, TemplateCategory::Dumping),
           human_and_environment_danger: get_templates_with_category(&beryllium_doc, TemplateCategory::Danger),
@@ -49,26 +60,43 @@ impl Loader for Beryllium {
               alternative_names: substance.names.split_off(1),
               cas: Data::new(substance.cas),
               molecular_formula: Data::new(substance.chemical_formula),
-              molar_mass: Data::new(substance.molecular_weight),
-              boiling_point: Data::new(substance.boiling_point),
-              melting_point: Data::new(match substance.melting_point {
-                Some(mp) => Some(mp.value),
-                None => None,
-              }),
+              molar_mass: Data::new(
+                substance
+                  .molecular_weight
+                  .map(|mw| quantity(&mw, Unit::GramPerMol, Vec::new())),
+              ),
+              boiling_point: Data::new(
+                substance
+                  .boiling_point
+                  .map(|bp| quantity(&bp, Unit::Celsius, Vec::new())),
+              ),
+              melting_point: Data::new(substance.melting_point.map(|mp| {
+                let qualifiers = match mp.decomposition {
+                  Some(true) => vec![Qualifier::Decomposes],
+                  _ => Vec::new(),
+                };
+                quantity(&mp.value, Unit::Celsius, qualifiers)
+              })),
+              density: Data::new(
+                substance
+                  .density
+                  .map(|density| quantity(&density, Unit::Custom(G_PER_CM3.into()), Vec::new())),
+              ),
+              flash_point: Data::new(substance.flashpoint.map(|fp| quantity(&fp, Unit::Celsius, Vec::new()))),
+              // not part of the Beryllium format
+              ignition_temperature: Data::new(None),
+              explosion_limits: Data::new(None),
               water_hazard_class: Data::new(substance.wgk),
-              h_phrases: Data::new(match substance.harzard_statements {
-                Some(phrases) => phrases
-                  .split('-')
-                  .map(|p| (format!("H{}", p), "".into())) // TODO fill statements
-                  .collect(),
-                None => Vec::new(),
+              h_phrases: Data::new({
+                let mut phrases = split_numbers(substance.harzard_statements.as_deref(), "H");
+                phrases.append(&mut split_numbers(substance.eu_harzard_statements.as_deref(), "EUH"));
+                ghs::fill_statements(&mut phrases, language);
+                phrases
               }),
-              p_phrases: Data::new(match substance.precautionary_statements {
-                Some(phrases) => phrases
-                  .split('-')
-                  .map(|p| (format!("P{}", p), "".into())) // TODO fill statements
-                  .collect(),
-                None => Vec::new(),
+              p_phrases: Data::new({
+                let mut phrases = split_numbers(substance.precautionary_statements.as_deref(), "P");
+                ghs::fill_statements(&mut phrases, language);
+                phrases
               }),
               signal_word: Data::new(substance.signal_word),
               symbols: Data::new(match substance.symbols {
@@ -78,14 +106,29 @@ impl Loader for Beryllium {
                   .collect(),
                 None => Vec::new(),
               }),
-              lethal_dose: Data::new(match substance.lethaldose50 {
-                Some(ld50) => Some(ld50.value),
-                None => None,
-              }),
-              mak: Data::new(match substance.mak {
-                Some(mak) => Some(mak.value),
-                None => None,
-              }),
+              lethal_dose: Data::new(substance.lethaldose50.as_ref().map(lethal_dose)),
+              // Beryllium only stores the value printed on the sheet
+              toxicity: Data::new(
+                substance
+                  .lethaldose50
+                  .map(|ld50| Toxicity {
+                    kind: ToxicityType::LD50,
+                    value: lethal_dose(&ld50),
+                    route: ld50.exposuretype.unwrap_or_default(),
+                    species: ld50.species.unwrap_or_default(),
+                  })
+                  .into_iter()
+                  .collect(),
+              ),
+              mak: Data::new(substance.mak.map(|mak| {
+                let unit = match mak.ppm {
+                  Some(true) => Unit::PartsPerMillion,
+                  _ => Unit::Custom(MG_PER_M3.into()),
+                };
+                quantity(&mak.value, unit, Vec::new())
+              })),
+              // not part of the Beryllium format
+              reactions: Data::new(Vec::new()),
               amount: {
                 let mut unit = None;
                 let mut value = None;
@@ -148,6 +191,67 @@ impl Loader for Beryllium {
       Err(e) => Err(LoadSaveError::DeserializeError(e.to_string())),
     }
   }
+
+  fn load_options(&self) -> Vec<OptionSchema> {
+    vec![OptionSchema {
+      key: LANGUAGE,
+      kind: OptionKind::Choice {
+        choices: vec!["german".into(), "english".into()],
+      },
+      default: None,
+    }]
+  }
+}
+
+impl Saver for Beryllium {
+  fn save_document(&self, document: CaBr2Document, _options: &Options) -> Result<Vec<u8>> {
+    writer::write_document(&document)
+  }
+}
+
+/// Beryllium stores plain numbers in a fixed unit, values that are no number are kept as text.
+fn quantity(value: &str, unit: Unit, qualifiers: Vec<Qualifier>) -> Quantity {
+  match value.trim().parse() {
+    Ok(value) => Quantity::new(QuantityValue::Exact(value), Some(unit), qualifiers),
+    Err(_) => Quantity::parse(value),
+  }
+}
+
+fn lethal_dose(ld50: &LD50) -> Quantity {
+  let unit = ld50.unit.as_deref().unwrap_or("mg/kg");
+  quantity(&ld50.value, Unit::from_symbol(unit), Vec::new())
+}
+
+/// `225-319` -> `[("H225", ""), ("H319", "")]`, combined phrases like `305+351+338` get the prefix for every part.
+fn split_numbers(numbers: Option<&str>, prefix: &str) -> Vec<(String, String)> {
+  numbers
+    .unwrap_or_default()
+    .split('-')
+    .map(|n| n.trim())
+    .filter(|n| !n.is_empty())
+    .map(|n| {
+      let number = n
+        .split('+')
+        .map(|part| format!("{}{}", prefix, part))
+        .collect::<Vec<String>>()
+        .join("+");
+      (number, String::new())
+    })
+    .collect()
+}
+
+/// The inverse of `split_numbers`.
+fn join_numbers(numbers: &[&str], prefix: &str) -> String {
+  numbers
+    .iter()
+    .map(|n| {
+      n.split('+')
+        .map(|part| part.trim_start_matches(prefix))
+        .collect::<Vec<&str>>()
+        .join("+")
+    })
+    .collect::<Vec<String>>()
+    .join("-")
 }
 
 fn get_templates_with_category(doc: &BerylliumDocument, category: TemplateCategory) -> Vec<String> {
@@ -159,3 +263,105 @@ fn get_templates_with_category(doc: &BerylliumDocument, category: TemplateCatego
     .map(|t| t.content.clone())
     .collect()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn load_fixture() -> CaBr2Document {
+    let contents = std::fs::read("tests/assets/beryllium/ethanol.be").unwrap();
+    Beryllium.load_document(contents, &Options::default()).unwrap()
+  }
+
+  #[test]
+  fn test_load_document() {
+    let document = load_fixture();
+
+    assert_eq!(document.header.preparation, "Ethylacetat");
+    assert_eq!(
+      document.rules_of_conduct,
+      vec!["Schutzbrille tragen.", "Im Abzug arbeiten."]
+    );
+    assert_eq!(
+      document.human_and_environment_danger,
+      vec!["Reizt die Augen & die Atemwege."]
+    );
+
+    let ethanol = &document.substance_data[0];
+    assert_eq!(ethanol.alternative_names, vec!["Ethylalkohol"]);
+    assert_eq!(ethanol.symbols.original_data, vec!["ghs02", "ghs07"]);
+    let h_numbers: Vec<&str> = ethanol.h_phrases.original_data.iter().map(|p| p.0.as_str()).collect();
+    assert_eq!(h_numbers, vec!["H225", "H319", "EUH066"]);
+    let p_numbers: Vec<&str> = ethanol.p_phrases.original_data.iter().map(|p| p.0.as_str()).collect();
+    assert_eq!(p_numbers, vec!["P210", "P240", "P305+P351+P338", "P403+P233"]);
+    assert_eq!(ethanol.h_phrases.original_data[1].1, "Verursacht schwere Augenreizung.");
+    assert_eq!(
+      ethanol.p_phrases.original_data[3].1,
+      "An einem gut belüfteten Ort aufbewahren. Behälter dicht verschlossen halten."
+    );
+    let density = ethanol.density.original_data.as_ref().unwrap();
+    assert_eq!(density.unit(), Some(&Unit::Custom(G_PER_CM3.into())));
+    assert_eq!(density.number(), Some(0.79));
+    assert_eq!(ethanol.flash_point.original_data.as_ref().unwrap().number(), Some(12.0));
+    let melting_point = ethanol.melting_point.original_data.as_ref().unwrap();
+    assert_eq!(melting_point.unit(), Some(&Unit::Celsius));
+    assert_eq!(melting_point.number(), Some(-114.0));
+    let mak = ethanol.mak.original_data.as_ref().unwrap();
+    assert_eq!(mak.unit(), Some(&Unit::PartsPerMillion));
+    assert_eq!(mak.number(), Some(200.0));
+    let toxicity = &ethanol.toxicity.original_data[0];
+    assert_eq!((toxicity.route.as_str(), toxicity.species.as_str()), ("oral", "Ratte"));
+    assert_eq!(Some(&toxicity.value), ethanol.lethal_dose.original_data.as_ref());
+    assert_eq!(ethanol.source.provider, "gestis");
+    assert_eq!(ethanol.source.url, "https://gestis-api.dguv.de/api/article/de/010420");
+
+    let water = &document.substance_data[1];
+    assert_eq!(water.source.provider, "custom");
+    assert!(matches!(
+      water.amount,
+      Some(Amount {
+        unit: Unit::SolutionRelative,
+        ..
+      })
+    ));
+  }
+
+  #[test]
+  fn test_language_option() {
+    let contents = std::fs::read("tests/assets/beryllium/ethanol.be").unwrap();
+    let options: Options = serde_json::from_str(r#"{ "language": "english" }"#).unwrap();
+    let document = Beryllium.load_document(contents.clone(), &options).unwrap();
+    assert_eq!(
+      document.substance_data[0].h_phrases.original_data[1].1,
+      "Causes serious eye irritation."
+    );
+
+    let options: Options = serde_json::from_str(r#"{ "language": "latin" }"#).unwrap();
+    assert!(matches!(
+      Beryllium.load_document(contents, &options),
+      Err(LoadSaveError::InvalidOption(key, _)) if key == "language"
+    ));
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let document = load_fixture();
+    let saved = Beryllium.save_document(load_fixture(), &Options::default()).unwrap();
+    let loaded = Beryllium.load_document(saved.clone(), &Options::default()).unwrap();
+
+    assert_eq!(format!("{:?}", loaded), format!("{:?}", document));
+    // saving again has to produce the same file
+    assert_eq!(Beryllium.save_document(loaded, &Options::default()).unwrap(), saved);
+  }
+
+  #[test]
+  fn test_numbers() {
+    let phrases = split_numbers(Some("305+351+338-501"), "P");
+    assert_eq!(phrases[0].0, "P305+P351+P338");
+    assert_eq!(phrases[1].0, "P501");
+    assert!(split_numbers(Some(""), "H").is_empty());
+
+    assert_eq!(join_numbers(&["P305+P351+P338", "P501"], "P"), "305+351+338-501");
+    assert_eq!(join_numbers(&["EUH066"], "EUH"), "066");
+  }
+}
@@ -58,6 +58,17 @@ pub enum TemplateCategory {
   Dumping,
 }
 
+impl TemplateCategory {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      TemplateCategory::Danger => "danger",
+      TemplateCategory::Security => "security",
+      TemplateCategory::Behavior => "behavior",
+      TemplateCategory::Dumping => "dumping",
+    }
+  }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Template {
   pub category: TemplateCategory,
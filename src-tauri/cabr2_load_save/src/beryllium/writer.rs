@@ -0,0 +1,251 @@
+use quick_xml::{
+  events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event},
+  Writer,
+};
+
+use cabr2_types::{Qualifier, Quantity, SubstanceData, Toxicity, ToxicityType, Unit};
+
+use super::{join_numbers, types::TemplateCategory, G_PER_CM3, MG_PER_M3};
+use crate::{error::Result, types::CaBr2Document};
+
+const ROOT: &str = "beryllium";
+// the statements of the signature fields on the second page of the CaBr2 sheet
+const STATEMENT_LEFT: &str = "Hiermit verpflichte ich mich, den Versuch gemäß den in dieser Betriebsanweisung \
+                              aufgeführten Sicherheitsvorschriften durchzuführen.";
+const STATEMENT_RIGHT: &str =
+  "Präparat zur Synthese mit den auf der Vorderseite berechneten Chemikalienmengen freigegeben.";
+const SIGNATURE: &str = "Unterschrift";
+/// The columns of the CaBr2 sheet, Beryllium shows all columns listed here.
+const SHOW_COLUMNS: [&str; 10] = [
+  "molecular-weight",
+  "boiling-point",
+  "melting-point",
+  "GHS-symbol",
+  "harzard-statements",
+  "precautionary-statements",
+  "MAK",
+  "lethaldose50",
+  "WGK",
+  "setting-up",
+];
+
+struct XmlWriter {
+  writer: Writer<Vec<u8>>,
+}
+
+impl XmlWriter {
+  fn start(&mut self, name: &str, attributes: &[(&str, &str)]) -> Result<()> {
+    let mut element = BytesStart::borrowed_name(name.as_bytes());
+    for attribute in attributes {
+      element.push_attribute(*attribute);
+    }
+    self.writer.write_event(Event::Start(element))?;
+    Ok(())
+  }
+
+  fn end(&mut self, name: &str) -> Result<()> {
+    self
+      .writer
+      .write_event(Event::End(BytesEnd::borrowed(name.as_bytes())))?;
+    Ok(())
+  }
+
+  fn element(&mut self, name: &str, attributes: &[(&str, &str)], text: &str) -> Result<()> {
+    self.start(name, attributes)?;
+    self.writer.write_event(Event::Text(BytesText::from_plain_str(text)))?;
+    self.end(name)
+  }
+
+  fn text(&mut self, name: &str, text: &str) -> Result<()> {
+    self.element(name, &[], text)
+  }
+
+  /// Missing values are left out, the loader treats missing elements as `None`.
+  fn optional(&mut self, name: &str, text: &Option<String>) -> Result<()> {
+    match text {
+      Some(text) => self.text(name, text),
+      None => Ok(()),
+    }
+  }
+}
+
+/// Beryllium expects plain numbers in a fixed unit, quantities that can't be converted are written as text.
+fn number(quantity: &Quantity, unit: Unit) -> String {
+  match quantity.convert(unit).and_then(|quantity| quantity.number()) {
+    Some(number) => number.to_string(),
+    None => quantity.to_string(),
+  }
+}
+
+pub fn write_document(document: &CaBr2Document) -> Result<Vec<u8>> {
+  let mut xml = XmlWriter {
+    writer: Writer::new_with_indent(Vec::new(), b' ', 2),
+  };
+  xml
+    .writer
+    .write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+  xml.start(ROOT, &[])?;
+
+  let header = &document.header;
+  xml.start("general", &[])?;
+  xml.text("title", &header.document_title)?;
+  xml.text("title_ghs", &header.document_title)?;
+  xml.text("location", &header.lab_course)?;
+  xml.text("institute", &header.organisation)?;
+  xml.text("statementleft", STATEMENT_LEFT)?;
+  xml.text("signaturleft", SIGNATURE)?;
+  xml.text("statementright", STATEMENT_RIGHT)?;
+  xml.text("signaturright", SIGNATURE)?;
+  xml.end("general")?;
+
+  xml.start("personal", &[])?;
+  xml.text("name", &header.name)?;
+  xml.text("spot", &header.place)?;
+  xml.text("assistant", &header.assistant)?;
+  xml.end("personal")?;
+
+  xml.start("product", &[])?;
+  xml.text("name", &header.preparation)?;
+  xml.text("chemical-formula", "")?;
+  xml.end("product")?;
+
+  xml.start("templates", &[])?;
+  let templates = [
+    (TemplateCategory::Danger, &document.human_and_environment_danger),
+    (TemplateCategory::Security, &document.rules_of_conduct),
+    (TemplateCategory::Behavior, &document.in_case_of_danger),
+    (TemplateCategory::Dumping, &document.disposal),
+  ];
+  for (category, contents) in templates.iter() {
+    for content in contents.iter() {
+      xml.element("template", &[("category", category.as_str())], content)?;
+    }
+  }
+  xml.end("templates")?;
+
+  xml.start("options", &[])?;
+  xml.text("ghs-mode", "true")?;
+  for column in SHOW_COLUMNS.iter() {
+    xml.text("showcolumn", column)?;
+  }
+  xml.end("options")?;
+
+  for substance in document.substance_data.iter() {
+    write_substance(&mut xml, substance)?;
+  }
+
+  xml.end(ROOT)?;
+
+  Ok(xml.writer.into_inner())
+}
+
+fn write_substance(xml: &mut XmlWriter, substance: &SubstanceData) -> Result<()> {
+  xml.start("substance", &[])?;
+
+  xml.text("name", substance.name.current())?;
+  for name in substance.alternative_names.iter() {
+    xml.text("name", name)?;
+  }
+  if let Some(molar_mass) = substance.molar_mass.current() {
+    xml.text("molecular-weight", &number(molar_mass, Unit::GramPerMol))?;
+  }
+  xml.optional("chemical-formula", substance.molecular_formula.current())?;
+  if let Some(density) = substance.density.current() {
+    xml.text("density", &number(density, Unit::Custom(G_PER_CM3.into())))?;
+  }
+  if let Some(melting_point) = substance.melting_point.current() {
+    let decomposition = melting_point.has_qualifier(Qualifier::Decomposes).to_string();
+    xml.element(
+      "melting-point",
+      &[("decomposition", &decomposition)],
+      &number(melting_point, Unit::Celsius),
+    )?;
+  }
+  if let Some(boiling_point) = substance.boiling_point.current() {
+    xml.text("boiling-point", &number(boiling_point, Unit::Celsius))?;
+  }
+  if let Some(flash_point) = substance.flash_point.current() {
+    xml.text("flashpoint", &number(flash_point, Unit::Celsius))?;
+  }
+
+  // Beryllium only stores the number of the pictogram
+  for symbol in substance.symbols.current().iter() {
+    xml.text("GHS-symbol", symbol.trim_start_matches("ghs"))?;
+  }
+  xml.optional("GHS-signalword", substance.signal_word.current())?;
+
+  let (eu_h_phrases, h_phrases): (Vec<_>, Vec<_>) = substance
+    .h_phrases
+    .current()
+    .iter()
+    .map(|(number, _)| number.as_str())
+    .partition(|number| number.starts_with("EUH"));
+  if !h_phrases.is_empty() {
+    xml.text("harzard-statements", &join_numbers(&h_phrases, "H"))?;
+  }
+  if !eu_h_phrases.is_empty() {
+    xml.text("eu-harzard-statements", &join_numbers(&eu_h_phrases, "EUH"))?;
+  }
+  let p_phrases: Vec<&str> = substance.p_phrases.current().iter().map(|p| p.0.as_str()).collect();
+  if !p_phrases.is_empty() {
+    xml.text("precautionary-statements", &join_numbers(&p_phrases, "P"))?;
+  }
+
+  if let Some(mak) = substance.mak.current() {
+    let ppm = mak.unit() == Some(&Unit::PartsPerMillion);
+    let unit = match ppm {
+      true => Unit::PartsPerMillion,
+      false => Unit::Custom(MG_PER_M3.into()),
+    };
+    xml.element("MAK", &[("ppm", &ppm.to_string())], &number(mak, unit))?;
+  }
+  xml.optional("WGK", substance.water_hazard_class.current())?;
+  if let Some(lethal_dose) = substance.lethal_dose.current() {
+    let toxicity = substance.toxicity.current();
+    // the entry the printed value was chosen from, Beryllium stores how it was determined
+    let entry = toxicity
+      .iter()
+      .find(|t| t.kind == ToxicityType::LD50 && t.value.as_str() == lethal_dose.as_str())
+      .or_else(|| Toxicity::preferred(toxicity));
+    let mut attributes = Vec::new();
+    if let Some(entry) = entry {
+      attributes.push(("exposuretype", entry.route.as_str()));
+      attributes.push(("species", entry.species.as_str()));
+    }
+    attributes.retain(|(_, value)| !value.is_empty());
+
+    match lethal_dose.unit() {
+      Some(unit) => {
+        let symbol: String = unit.clone().into();
+        attributes.push(("unit", &symbol));
+        xml.element("lethaldose50", &attributes, &number(lethal_dose, unit.clone()))?;
+      }
+      None => xml.element("lethaldose50", &attributes, lethal_dose.as_str())?,
+    }
+  }
+  xml.optional("CAS", substance.cas.current())?;
+
+  if let Some(amount) = &substance.amount {
+    match amount.unit {
+      Unit::Litre => xml.element("setting-up", &[("volumina", "true")], &amount.value)?,
+      Unit::Gram => xml.element("setting-up", &[("mass", "true")], &amount.value)?,
+      // the loader reads the value from `solution-volumina`, the element needs a value nonetheless
+      Unit::SolutionRelative => {
+        xml.element("concentration", &[("relative", "true")], &amount.value)?;
+        xml.text("solution-volumina", &amount.value)?;
+      }
+      Unit::SolutionMol => {
+        xml.element("concentration", &[("relative", "false")], &amount.value)?;
+        xml.text("solution-volumina", &amount.value)?;
+      }
+      _ => log::debug!("amount can't be saved in beryllium: {:?}", amount),
+    }
+  }
+
+  let source = &substance.source;
+  xml.text("source.provider", &source.provider)?;
+  xml.text("source.fetched", &source.last_updated.to_rfc2822())?;
+  xml.text("source.url", &source.url)?;
+
+  xml.end("substance")
+}
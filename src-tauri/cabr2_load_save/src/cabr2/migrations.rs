@@ -0,0 +1,39 @@
+use serde_json::{Map, Value};
+
+use crate::error::{LoadSaveError, Result};
+
+type Migration = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// `MIGRATIONS[n]` upgrades a document from version `n` to `n + 1`.
+///
+/// Add a migration here whenever `CaBr2Document` or one of its members changes in an incompatible way.
+const MIGRATIONS: &[Migration] = &[
+  // files written before the version field was introduced
+  |_| Ok(()),
+];
+
+pub const CURRENT_VERSION: u64 = MIGRATIONS.len() as u64;
+pub const VERSION_FIELD: &str = "version";
+
+/// Upgrades the document to `CURRENT_VERSION`, files without version are treated as version 0.
+pub fn migrate(document: &mut Map<String, Value>) -> Result<()> {
+  let version = match document.get(VERSION_FIELD) {
+    None => 0,
+    Some(version) => match version.as_u64() {
+      Some(version) => version,
+      None => return Err(LoadSaveError::InvalidVersion(version.to_string())),
+    },
+  };
+
+  if version > CURRENT_VERSION {
+    return Err(LoadSaveError::NewerVersion(version, CURRENT_VERSION));
+  }
+
+  for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+    log::debug!("migrating document from version {} to {}", from, from + 1);
+    migration(document)?;
+  }
+  document.insert(VERSION_FIELD.into(), CURRENT_VERSION.into());
+
+  Ok(())
+}
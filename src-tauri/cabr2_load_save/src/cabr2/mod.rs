@@ -1,18 +1,109 @@
+mod migrations;
+
+use serde_json::Value;
+
+use self::migrations::{CURRENT_VERSION, VERSION_FIELD};
 use super::{
   error::Result,
-  types::{CaBr2Document, Loader, Saver},
+  types::{CaBr2Document, Loader, Options, Saver},
 };
 
 pub struct CaBr2;
 
 impl Loader for CaBr2 {
-  fn load_document(&self, contents: Vec<u8>) -> Result<CaBr2Document> {
-    Ok(serde_json::from_slice(&contents)?)
+  fn load_document(&self, contents: Vec<u8>, _options: &Options) -> Result<CaBr2Document> {
+    match serde_json::from_slice(&contents)? {
+      Value::Object(mut document) => {
+        migrations::migrate(&mut document)?;
+        Ok(serde_json::from_value(Value::Object(document))?)
+      }
+      // not a document at all, let serde produce the error message
+      other => Ok(serde_json::from_value(other)?),
+    }
   }
 }
 
 impl Saver for CaBr2 {
-  fn save_document(&self, document: CaBr2Document) -> Result<Vec<u8>> {
-    Ok(serde_json::to_vec(&document)?)
+  /// The version is stored next to the document fields, so older releases can still read the file.
+  fn save_document(&self, document: CaBr2Document, _options: &Options) -> Result<Vec<u8>> {
+    let mut value = serde_json::to_value(&document)?;
+    if let Value::Object(document) = &mut value {
+      document.insert(VERSION_FIELD.into(), CURRENT_VERSION.into());
+    }
+
+    Ok(serde_json::to_vec(&value)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::error::LoadSaveError;
+
+  fn document() -> Value {
+    json!({
+      "header": {
+        "documentTitle": "Betriebsanweisungen nach EG Nr. 1272/2008",
+        "organisation": "für chemische Laboratorien",
+        "labCourse": "Praktikum",
+        "name": "Max Mustermann",
+        "place": "42",
+        "assistant": "Erika Mustermann",
+        "preparation": "Ethylacetat"
+      },
+      "substanceData": [],
+      "humanAndEnvironmentDanger": [],
+      "rulesOfConduct": ["Schutzbrille tragen"],
+      "inCaseOfDanger": [],
+      "disposal": []
+    })
+  }
+
+  fn load(document: Value) -> Result<CaBr2Document> {
+    CaBr2.load_document(serde_json::to_vec(&document).unwrap(), &Options::default())
+  }
+
+  #[test]
+  fn test_load_unversioned_document() {
+    let document = load(document()).unwrap();
+    assert_eq!(document.header.preparation, "Ethylacetat");
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let saved = CaBr2
+      .save_document(load(document()).unwrap(), &Options::default())
+      .unwrap();
+
+    let value: Value = serde_json::from_slice(&saved).unwrap();
+    assert_eq!(value[VERSION_FIELD], json!(CURRENT_VERSION));
+    assert_eq!(
+      CaBr2
+        .load_document(saved, &Options::default())
+        .unwrap()
+        .rules_of_conduct,
+      vec!["Schutzbrille tragen"]
+    );
+  }
+
+  #[test]
+  fn test_newer_version() {
+    let mut document = document();
+    document[VERSION_FIELD] = json!(CURRENT_VERSION + 1);
+
+    assert!(matches!(
+      load(document),
+      Err(LoadSaveError::NewerVersion(version, CURRENT_VERSION)) if version == CURRENT_VERSION + 1
+    ));
+  }
+
+  #[test]
+  fn test_invalid_version() {
+    let mut document = document();
+    document[VERSION_FIELD] = json!("2.0");
+
+    assert!(matches!(load(document), Err(LoadSaveError::InvalidVersion(_))));
   }
 }
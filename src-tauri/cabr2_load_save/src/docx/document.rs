@@ -0,0 +1,415 @@
+use std::{collections::BTreeSet, fs};
+
+use cabr2_config::DATA_DIR;
+use cabr2_types::{Data, SubstanceData};
+
+use crate::types::CaBr2Document;
+
+type Phrases = Vec<(String, String)>;
+
+/* #region layout */
+
+// lengths are in twips (1/20 pt), 1mm is about 57 twips
+const CONTENT_WIDTH: u32 = 10205;
+const PAGE_MARGIN: u32 = 850;
+/// The same column widths as the pdf, in mm: 45, 17, 19, 30, 27, 24 and 18.
+const SUBSTANCE_COLUMNS: [u32; 7] = [2551, 964, 1077, 1701, 1531, 1361, 1020];
+/// 8.5mm in EMU, three pictograms fit next to each other.
+const GHS_SIZE: u32 = 306_000;
+
+/* #endregion */
+
+const DOCUMENT_START: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture"><w:body>"#;
+
+const IMAGE_RELATIONSHIP: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
+
+#[derive(Clone, Copy, Default)]
+struct Format {
+  bold: bool,
+  italic: bool,
+  /// in half points
+  size: u32,
+}
+
+impl Format {
+  fn new(size: u32) -> Format {
+    Format {
+      size,
+      ..Default::default()
+    }
+  }
+
+  fn bold(mut self) -> Format {
+    self.bold = true;
+    self
+  }
+
+  fn italic(mut self, italic: bool) -> Format {
+    self.italic = italic;
+    self
+  }
+}
+
+/// Collects the body of `word/document.xml` and the embedded pictograms.
+pub struct DocumentWriter {
+  body: String,
+  /// file name and content of the embedded pictograms, the relationship id is `rId{index + 1}`
+  images: Vec<(String, Vec<u8>)>,
+  drawings: u32,
+}
+
+impl DocumentWriter {
+  pub fn new() -> DocumentWriter {
+    DocumentWriter {
+      body: String::new(),
+      images: Vec::new(),
+      drawings: 0,
+    }
+  }
+
+  pub fn document_xml(&self) -> String {
+    format!(
+      "{}{}<w:sectPr><w:pgSz w:w=\"11906\" w:h=\"16838\"/>\
+       <w:pgMar w:top=\"{m}\" w:right=\"{m}\" w:bottom=\"{m}\" w:left=\"{m}\" w:header=\"0\" w:footer=\"0\" w:gutter=\"0\"/>\
+       </w:sectPr></w:body></w:document>",
+      DOCUMENT_START,
+      self.body,
+      m = PAGE_MARGIN
+    )
+  }
+
+  pub fn relationships_xml(&self) -> String {
+    let relationships: String = self
+      .images
+      .iter()
+      .enumerate()
+      .map(|(i, (name, _))| {
+        format!(
+          "<Relationship Id=\"rId{}\" Type=\"{}\" Target=\"media/{}\"/>",
+          i + 1,
+          IMAGE_RELATIONSHIP,
+          name
+        )
+      })
+      .collect();
+
+    format!(
+      "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+       <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">{}</Relationships>",
+      relationships
+    )
+  }
+
+  pub fn images(&self) -> impl Iterator<Item = (&String, &Vec<u8>)> {
+    self.images.iter().map(|(name, image)| (name, image))
+  }
+
+  pub fn write_document(&mut self, document: CaBr2Document) {
+    let header = &document.header;
+
+    for title in [&header.document_title, &header.organisation, &header.lab_course].iter() {
+      self.body += &paragraph(&run(title, Format::new(24).bold()), true);
+    }
+
+    let eighth = CONTENT_WIDTH / 8;
+    let labeled = |label: &str, value: &str| {
+      paragraph(&run(label, Format::new(14)), false) + &paragraph(&run(value, Format::new(20)), false)
+    };
+    self.body += &table(
+      &[3 * eighth, 2 * eighth, 3 * eighth],
+      &[vec![
+        labeled("Name:", &header.name),
+        labeled("Platz:", &header.place),
+        labeled("Assistent/in:", &header.assistant),
+      ]],
+    );
+    self.body += &paragraph(&run("Herzustellendes Präparat:", Format::new(16)), true);
+    self.body += &paragraph(&run(&header.preparation, Format::new(22).bold()), true);
+
+    self.write_substances(&document.substance_data);
+
+    self.body += &page_break();
+
+    let sections = [
+      (
+        "Gefahren für Mensch und Umwelt, die von den Ausgangsmaterialien bzw. dem(n) Produkt ausgehen, soweit sie nicht \
+         durch genannte Angaben abgedeckt sind (z.B. krebserregend, fruchtschädigend, hautresorptiv):",
+        &document.human_and_environment_danger,
+      ),
+      ("Schutzmaßnahmen und Verhaltensregeln:", &document.rules_of_conduct),
+      (
+        "Verhalten im Gefahrenfall, Erste-Hilfe-Maßnahmen (gegebenfalls Kopie der entsprechenden Literaturstelle \
+         beiheften):",
+        &document.in_case_of_danger,
+      ),
+      ("Entsorgung:", &document.disposal),
+    ];
+    for (heading, items) in sections.iter() {
+      self.body += &paragraph(&run(heading, Format::new(18).bold()), false);
+      for item in items.iter() {
+        self.body += &paragraph(&run(&format!("• {}", item), Format::new(18)), false);
+      }
+      self.body += &paragraph("", false);
+    }
+
+    let signature = |statement: &str| {
+      paragraph(&run(statement, Format::new(16)), false)
+        + &paragraph("", false).repeat(4)
+        + &paragraph(&run("Unterschrift", Format::new(14)), true)
+    };
+    self.body += &table(
+      &[CONTENT_WIDTH / 2, CONTENT_WIDTH / 2],
+      &[vec![
+        signature(
+          "Hiermit verpflichte ich mich, den Versuch gemäß den in dieser Betriebsanweisung aufgeführten \
+           Sicherheitsvorschriften durchzuführen.",
+        ),
+        signature("Präparat zur Synthese mit den auf der Vorderseite berechneten Chemikalienmengen freigegeben."),
+      ]],
+    );
+  }
+
+  fn write_substances(&mut self, substances: &[SubstanceData]) {
+    let header = [
+      "eingesetzte Stoffe und Produkte",
+      "MG [g/mol]",
+      "Sdp. Smp.",
+      "GHS-Symbole",
+      "H/P-Sätze (Nummern)",
+      "MAK LD50 WGK",
+      "für Ansatz benötigt",
+    ];
+    let mut rows = vec![header
+      .iter()
+      .map(|h| paragraph(&run(h, Format::new(16).bold()), true))
+      .collect::<Vec<String>>()];
+
+    for substance in substances.iter() {
+      let mut name = paragraph(&data_run(&substance.name, 18), false);
+      if let Some(formula) = substance.molecular_formula.current() {
+        name += &paragraph(
+          &run(
+            formula,
+            Format::new(16).italic(is_modified(&substance.molecular_formula)),
+          ),
+          false,
+        );
+      }
+
+      let pictograms: String = substance
+        .symbols
+        .current()
+        .iter()
+        .filter_map(|s| self.symbol(s))
+        .collect();
+      let signal_word = substance.signal_word.current().as_deref().unwrap_or_default();
+
+      let amount = match &substance.amount {
+        Some(amount) => format!("{} {}", amount.value, String::from(amount.unit.clone())),
+        None => String::new(),
+      };
+
+      rows.push(vec![
+        name,
+        paragraph(&value_or_dash(&substance.molar_mass), true),
+        paragraph(&value_or_dash(&substance.boiling_point), true)
+          + &paragraph(&value_or_dash(&substance.melting_point), true),
+        paragraph(&pictograms, true)
+          + &paragraph(
+            &run(
+              signal_word,
+              Format::new(14).bold().italic(is_modified(&substance.signal_word)),
+            ),
+            true,
+          ),
+        paragraph(&phrase_numbers(&substance.h_phrases), false)
+          + &paragraph(&phrase_numbers(&substance.p_phrases), false),
+        paragraph(&value_or_dash(&substance.mak), true)
+          + &paragraph(&value_or_dash(&substance.lethal_dose), true)
+          + &paragraph(&value_or_dash(&substance.water_hazard_class), true),
+        paragraph(&run(&amount, Format::new(16)), true),
+      ]);
+    }
+    self.body += &table(&SUBSTANCE_COLUMNS, &rows);
+
+    self.body += &paragraph(
+      &run(
+        "Wortlaut der wesentlichen oben genannten H- und P-Sätze:",
+        Format::new(18).bold(),
+      ),
+      false,
+    );
+    let phrases = |select: fn(&SubstanceData) -> &Data<Phrases>| -> String {
+      let phrases: BTreeSet<String> = substances
+        .iter()
+        .flat_map(|s| select(s).current().iter())
+        .map(|(number, text)| format!("{}: {}", number, text))
+        .collect();
+      phrases
+        .iter()
+        .map(|p| paragraph(&run(p, Format::new(14)), false))
+        .collect()
+    };
+    let h_phrases = phrases(|s| &s.h_phrases);
+    let p_phrases = phrases(|s| &s.p_phrases);
+    self.body += &table(&[CONTENT_WIDTH / 2, CONTENT_WIDTH / 2], &[vec![h_phrases, p_phrases]]);
+  }
+
+  /// Embeds the pictogram once and returns a run showing it, missing pictograms are skipped.
+  fn symbol(&mut self, key: &str) -> Option<String> {
+    let name = format!("{}.png", key);
+    let index = match self.images.iter().position(|(n, _)| *n == name) {
+      Some(index) => index,
+      None => {
+        if !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+          log::warn!("invalid ghs symbol: '{}'", key);
+          return None;
+        }
+
+        let mut path = DATA_DIR.clone();
+        path.push("ghs_symbols");
+        path.push(&name);
+        match fs::read(&path) {
+          Ok(image) => self.images.push((name.clone(), image)),
+          Err(err) => {
+            log::warn!("failed to load ghs symbol {:?}: {}", path, err);
+            return None;
+          }
+        }
+        self.images.len() - 1
+      }
+    };
+
+    self.drawings += 1;
+    Some(format!(
+      "<w:r><w:drawing><wp:inline distT=\"0\" distB=\"0\" distL=\"0\" distR=\"0\">\
+       <wp:extent cx=\"{size}\" cy=\"{size}\"/><wp:docPr id=\"{id}\" name=\"{name}\"/>\
+       <a:graphic><a:graphicData uri=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">\
+       <pic:pic><pic:nvPicPr><pic:cNvPr id=\"{id}\" name=\"{name}\"/><pic:cNvPicPr/></pic:nvPicPr>\
+       <pic:blipFill><a:blip r:embed=\"rId{rid}\"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>\
+       <pic:spPr><a:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"{size}\" cy=\"{size}\"/></a:xfrm>\
+       <a:prstGeom prst=\"rect\"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>\
+       </a:graphicData></a:graphic></wp:inline></w:drawing></w:r>",
+      size = GHS_SIZE,
+      id = self.drawings,
+      name = name,
+      rid = index + 1,
+    ))
+  }
+}
+
+/* #region data helpers */
+
+fn is_modified<T>(data: &Data<T>) -> bool {
+  data.modified_data.is_some()
+}
+
+fn data_run(data: &Data<String>, size: u32) -> String {
+  run(data.current(), Format::new(size).italic(is_modified(data)))
+}
+
+/// Writes a `-` if the value is missing, modified values are italic.
+fn value_or_dash<T: ToString>(data: &Data<Option<T>>) -> String {
+  let value = match data.current() {
+    Some(value) => value.to_string(),
+    None => "-".into(),
+  };
+  run(&value, Format::new(16).italic(is_modified(data)))
+}
+
+fn phrase_numbers(phrases: &Data<Phrases>) -> String {
+  let numbers: Vec<&str> = phrases.current().iter().map(|p| p.0.as_str()).collect();
+  run(&numbers.join("-"), Format::new(14).italic(is_modified(phrases)))
+}
+
+/* #endregion */
+
+/* #region xml */
+
+fn escape(text: &str) -> String {
+  text
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
+
+fn run(text: &str, format: Format) -> String {
+  let mut properties = String::new();
+  if format.bold {
+    properties += "<w:b/>";
+  }
+  if format.italic {
+    properties += "<w:i/>";
+  }
+  if format.size > 0 {
+    properties += &format!("<w:sz w:val=\"{}\"/>", format.size);
+  }
+
+  format!(
+    "<w:r><w:rPr>{}</w:rPr><w:t xml:space=\"preserve\">{}</w:t></w:r>",
+    properties,
+    escape(text)
+  )
+}
+
+fn paragraph(runs: &str, centered: bool) -> String {
+  let alignment = if centered { "<w:jc w:val=\"center\"/>" } else { "" };
+  format!(
+    "<w:p><w:pPr><w:spacing w:before=\"0\" w:after=\"0\"/>{}</w:pPr>{}</w:p>",
+    alignment, runs
+  )
+}
+
+fn page_break() -> String {
+  "<w:p><w:r><w:br w:type=\"page\"/></w:r></w:p>".into()
+}
+
+/// Every cell has to contain at least one paragraph.
+fn table(widths: &[u32], rows: &[Vec<String>]) -> String {
+  let border = |side: &str| {
+    format!(
+      "<w:{} w:val=\"single\" w:sz=\"4\" w:space=\"0\" w:color=\"000000\"/>",
+      side
+    )
+  };
+  let borders: String = ["top", "left", "bottom", "right", "insideH", "insideV"]
+    .iter()
+    .map(|side| border(side))
+    .collect();
+  let grid: String = widths.iter().map(|w| format!("<w:gridCol w:w=\"{}\"/>", w)).collect();
+
+  let rows: String = rows
+    .iter()
+    .map(|cells| {
+      let cells: String = cells
+        .iter()
+        .zip(widths.iter())
+        .map(|(content, width)| {
+          let content = if content.is_empty() {
+            paragraph("", false)
+          } else {
+            content.clone()
+          };
+          format!(
+            "<w:tc><w:tcPr><w:tcW w:w=\"{}\" w:type=\"dxa\"/></w:tcPr>{}</w:tc>",
+            width, content
+          )
+        })
+        .collect();
+      format!("<w:tr><w:trPr><w:cantSplit/></w:trPr>{}</w:tr>", cells)
+    })
+    .collect();
+
+  format!(
+    "<w:tbl><w:tblPr><w:tblW w:w=\"{}\" w:type=\"dxa\"/><w:tblBorders>{}</w:tblBorders>\
+     <w:tblLayout w:type=\"fixed\"/></w:tblPr><w:tblGrid>{}</w:tblGrid>{}</w:tbl>",
+    widths.iter().sum::<u32>(),
+    borders,
+    grid,
+    rows
+  )
+}
+
+/* #endregion */
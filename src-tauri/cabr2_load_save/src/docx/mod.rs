@@ -0,0 +1,95 @@
+mod document;
+
+use std::io::{Cursor, Write};
+
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use self::document::DocumentWriter;
+use crate::{
+  error::Result,
+  types::{CaBr2Document, Options, Saver},
+};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Default Extension="png" ContentType="image/png"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+
+const PACKAGE_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+/// Writes the safety sheet as Office Open XML, so it can be edited in Word or LibreOffice.
+pub struct DOCX;
+
+impl Saver for DOCX {
+  fn save_document(&self, document: CaBr2Document, _options: &Options) -> Result<Vec<u8>> {
+    let mut writer = DocumentWriter::new();
+    writer.write_document(document);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file("[Content_Types].xml", options)?;
+    zip.write_all(CONTENT_TYPES.as_bytes())?;
+    zip.start_file("_rels/.rels", options)?;
+    zip.write_all(PACKAGE_RELATIONSHIPS.as_bytes())?;
+    zip.start_file("word/document.xml", options)?;
+    zip.write_all(writer.document_xml().as_bytes())?;
+    zip.start_file("word/_rels/document.xml.rels", options)?;
+    zip.write_all(writer.relationships_xml().as_bytes())?;
+
+    for (name, image) in writer.images() {
+      // pngs are already compressed
+      zip.start_file(
+        format!("word/media/{}", name),
+        options.compression_method(CompressionMethod::Stored),
+      )?;
+      zip.write_all(image)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Read;
+
+  use zip::ZipArchive;
+
+  use super::*;
+  use crate::types::Header;
+
+  #[test]
+  fn test_save_document() {
+    let document = CaBr2Document {
+      header: Header {
+        document_title: "Betriebsanweisungen nach EG Nr. 1272/2008".into(),
+        organisation: "für chemische Laboratorien".into(),
+        lab_course: "Praktikum <Anorganische Chemie>".into(),
+        name: "Max Mustermann".into(),
+        place: "42".into(),
+        assistant: "Erika Mustermann".into(),
+        preparation: "Acetylsalicylsäure".into(),
+      },
+      substance_data: Vec::new(),
+      human_and_environment_danger: vec!["keine".into()],
+      rules_of_conduct: vec!["Schutzbrille & Handschuhe tragen".into()],
+      in_case_of_danger: Vec::new(),
+      disposal: vec!["Sammelbehälter für organische Abfälle".into()],
+    };
+
+    let docx = DOCX.save_document(document, &Options::default()).unwrap();
+    let mut archive = ZipArchive::new(Cursor::new(docx)).unwrap();
+    assert!(archive.by_name("[Content_Types].xml").is_ok());
+    assert!(archive.by_name("word/_rels/document.xml.rels").is_ok());
+
+    let mut xml = String::new();
+    archive
+      .by_name("word/document.xml")
+      .unwrap()
+      .read_to_string(&mut xml)
+      .unwrap();
+    assert!(xml.contains("Praktikum &lt;Anorganische Chemie&gt;"));
+    assert!(xml.contains("• Schutzbrille &amp; Handschuhe tragen"));
+    assert!(xml.contains("Entsorgung:"));
+  }
+}
@@ -10,9 +10,19 @@ pub enum LoadSaveError {
   #[error("failed to load file: '{0}'")]
   DeserializeError(String),
 
+  #[cfg(feature = "beryllium")]
+  #[error("failed to write xml: '{0}'")]
+  XmlError(#[from] quick_xml::Error),
+
   #[error("file already exists: '{0}'")]
   FileExists(String),
 
+  #[error("invalid option '{0}': {1}")]
+  InvalidOption(String, String),
+
+  #[error("conversion failed: {0}")]
+  ConversionError(#[from] cabr2_types::conversion::ConversionError),
+
   #[cfg(feature = "pdf")]
   #[error("merging of pdfs failed: '{0}'")]
   PdfMergeError(String),
@@ -21,6 +31,14 @@ pub enum LoadSaveError {
   #[error("creating template failed: '{0}'")]
   TemplateError(#[from] handlebars::TemplateError),
 
+  #[cfg(feature = "pdf")]
+  #[error("unknown template: '{0}'")]
+  UnknownTemplate(String),
+
+  #[cfg(feature = "pdf")]
+  #[error("invalid template '{0}': {1}")]
+  InvalidTemplate(String, String),
+
   #[cfg(feature = "pdf")]
   #[error("rendering document failed: '{0}'")]
   RenderError(#[from] handlebars::RenderError),
@@ -29,6 +47,22 @@ pub enum LoadSaveError {
   #[error("PDF creation failed: {0}")]
   PdfError(#[from] wkhtmltopdf::Error),
 
+  #[cfg(feature = "native_pdf")]
+  #[error("PDF creation failed: {0}")]
+  NativePdfError(#[from] printpdf::Error),
+
+  #[cfg(feature = "docx")]
+  #[error("writing docx failed: {0}")]
+  ZipError(#[from] zip::result::ZipError),
+
+  #[cfg(feature = "cabr2")]
+  #[error("the file was created by a newer version of CaBr2 (file version {0}, supported up to {1})")]
+  NewerVersion(u64, u64),
+
+  #[cfg(feature = "cabr2")]
+  #[error("invalid file version: '{0}'")]
+  InvalidVersion(String),
+
   #[cfg(feature = "cabr2")]
   #[error("parsing json failed: '{0}'")]
   JsonError(#[from] serde_json::Error),
@@ -3,11 +3,18 @@ use std::{collections::HashMap, sync::Arc};
 use lazy_static::lazy_static;
 use tokio::sync::Mutex;
 
-use cabr2_types::ProviderMapping;
+use cabr2_config::BackendConfig;
+use cabr2_types::{
+  conversion,
+  ghs::{self, Classification, MixtureComponent, ValidationWarning},
+  Amount, Language, ProviderMapping, Quantity, SubstanceData, Unit,
+};
 
 use crate::{
   error::{LoadSaveError, Result},
-  types::{CaBr2Document, DialogFilter, DocumentTypes, Loader, Saver},
+  incompatibilities::{self, Incompatibility},
+  sections::{self, Sections},
+  types::{CaBr2Document, DialogFilter, DocumentTypes, Loader, Options, Saver},
 };
 
 type LoadersMap = Arc<Mutex<HashMap<&'static str, (&'static str, Box<dyn Loader + Send + Sync>)>>>;
@@ -28,38 +35,53 @@ pub async fn init_handlers(_provider_mapping: ProviderMapping) {
   let mut _savers = REGISTERED_SAVERS.lock().await;
   #[cfg(feature = "cabr2")]
   _savers.insert("cb2", ("CaBr2", Box::new(crate::cabr2::CaBr2)));
+  #[cfg(feature = "beryllium")]
+  _savers.insert("be", ("Beryllium", Box::new(crate::beryllium::Beryllium)));
+  #[cfg(feature = "docx")]
+  _savers.insert("docx", ("Word", Box::new(crate::docx::DOCX)));
   #[cfg(feature = "pdf")]
-  _savers.insert("pdf", ("PDF", Box::new(crate::pdf::PDF::new(_provider_mapping))));
+  _savers.insert(
+    "pdf",
+    ("PDF", Box::new(crate::pdf::PDF::new(_provider_mapping.clone()))),
+  );
+  // replaces the wkhtmltopdf saver if both features are enabled
+  #[cfg(feature = "native_pdf")]
+  _savers.insert("pdf", ("PDF", Box::new(crate::pdf::NativePDF::new(_provider_mapping))));
 }
 
-pub async fn save_document(file_type: &str, document: CaBr2Document) -> Result<Vec<u8>> {
+pub async fn save_document(file_type: &str, document: CaBr2Document, options: Options) -> Result<Vec<u8>> {
+  let options = with_config(options).await;
   if let Some((_, saver)) = REGISTERED_SAVERS.lock().await.get(file_type) {
     // This may be a long running, cpu intensive task (e.g. PDF). This informs the runtime to move other waiting tasks
     // to different threads.
-    return tokio::task::block_in_place(|| saver.save_document(document));
+    return tokio::task::block_in_place(|| saver.save_document(document, &options));
   }
 
   Err(LoadSaveError::UnknownFileType)
 }
 
-pub async fn load_document(file_type: &str, contents: Vec<u8>) -> Result<CaBr2Document> {
+pub async fn load_document(file_type: &str, contents: Vec<u8>, options: Options) -> Result<CaBr2Document> {
+  let options = with_config(options).await;
   if let Some((_, loader)) = REGISTERED_LOADERS.lock().await.get(file_type) {
     // This may be a long running, cpu intensive task (e.g. PDF). This informs the runtime to move other waiting tasks
     // to different threads.
-    return tokio::task::block_in_place(|| loader.load_document(contents));
+    return tokio::task::block_in_place(|| loader.load_document(contents, &options));
   }
 
   Err(LoadSaveError::UnknownFileType)
 }
 
+/// Lists the registered file types with the options of their loader or saver, the PDF saver reads the template sets
+/// for its options.
 pub async fn get_available_document_types() -> Result<DocumentTypes> {
   let mut load: Vec<DialogFilter> = REGISTERED_LOADERS
     .lock()
     .await
     .iter()
-    .map(|(ext, (name, _))| DialogFilter {
+    .map(|(ext, (name, loader))| DialogFilter {
       name: name.to_string(),
       extensions: vec![ext.to_string()],
+      options: tokio::task::block_in_place(|| loader.load_options()),
     })
     .collect();
 
@@ -75,11 +97,85 @@ pub async fn get_available_document_types() -> Result<DocumentTypes> {
     .lock()
     .await
     .iter()
-    .map(|(ext, (name, _))| DialogFilter {
+    .map(|(ext, (name, saver))| DialogFilter {
       name: name.to_string(),
       extensions: vec![ext.to_string()],
+      options: tokio::task::block_in_place(|| saver.save_options()),
     })
     .collect();
 
   Ok(DocumentTypes { load, save })
 }
+
+/// Proposes the free text sections of a document in the language of the ui.
+pub async fn generate_sections(substance_data: Vec<SubstanceData>) -> Result<Sections> {
+  Ok(sections::generate_sections(&substance_data, get_language().await))
+}
+
+/// Lists the substances of a document that react dangerously with each other.
+pub async fn check_incompatibilities(substance_data: Vec<SubstanceData>) -> Result<Vec<Incompatibility>> {
+  Ok(incompatibilities::check_incompatibilities(
+    &substance_data,
+    get_language().await,
+  ))
+}
+
+/// Checks the phrases, symbols and signal word of a substance, also works for modified and custom substances.
+pub async fn validate_substance_data(substance_data: SubstanceData) -> Result<Vec<ValidationWarning>> {
+  Ok(ghs::validate(&substance_data, get_language().await))
+}
+
+/// Suggests the hazards of a diluted substance as modified data, substances without a concentration are returned
+/// unchanged.
+pub async fn classify_solution(mut substance_data: SubstanceData) -> Result<SubstanceData> {
+  ghs::classify_solution(&mut substance_data, get_language().await)?;
+  Ok(substance_data)
+}
+
+/// Classifies a mixture of substances in a non hazardous solvent, each with its concentration.
+pub async fn classify_mixture(components: Vec<MixtureComponent>) -> Result<Classification> {
+  Ok(ghs::classify_mixture(&components, get_language().await)?)
+}
+
+/// Converts the amount of a substance, the molar mass and density are only needed to convert between mass,
+/// volume and amount of substance.
+pub async fn convert_amount(
+  amount: Amount,
+  unit: Unit,
+  molar_mass: Option<Quantity>,
+  density: Option<Quantity>,
+) -> Result<Amount> {
+  Ok(conversion::convert(
+    &amount,
+    &unit,
+    molar_mass.as_ref(),
+    density.as_ref(),
+  )?)
+}
+
+/// The language of the ui, German if the config can't be read.
+async fn get_language() -> Language {
+  read_config()
+    .await
+    .map(|config| Language::from_locale(&config.global.language))
+    .unwrap_or_default()
+}
+
+/// Fills the settings of the config into the options, the defaults are kept if the config can't be read.
+async fn with_config(mut options: Options) -> Options {
+  if let Some(config) = read_config().await {
+    options.language = Language::from_locale(&config.global.language);
+    options.print_incompatibilities = config.pdf.print_incompatibilities;
+  }
+  options
+}
+
+async fn read_config() -> Option<BackendConfig> {
+  match cabr2_config::read_config().await {
+    Ok(config) => Some(config),
+    Err(err) => {
+      log::warn!("reading config failed, using defaults: {}", err);
+      None
+    }
+  }
+}
@@ -0,0 +1,335 @@
+use serde::Serialize;
+
+use cabr2_types::{Language, SubstanceData, Unit};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Hazard {
+  /// An oxidiser together with a flammable substance.
+  FireOrExplosion,
+  /// An acid together with a substance that liberates toxic gases on contact with acids.
+  ToxicGas,
+  /// Water or an aqueous solution together with a substance that reacts with water.
+  WaterReactive,
+  /// A corrosive acid together with a corrosive base.
+  Neutralization,
+  /// The provider lists a dangerous reaction between the substances.
+  Reported,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Incompatibility {
+  pub substances: [String; 2],
+  pub hazard: Hazard,
+  /// The localized warning, e.g. for the PDF.
+  pub message: String,
+}
+
+const FLAMMABLE: &[&str] = &[
+  "H220", "H221", "H222", "H223", "H224", "H225", "H226", "H228", "H230", "H231", "H232", "H242", "H250", "H251",
+  "H252",
+];
+const OXIDISING: &[&str] = &["H270", "H271", "H272"];
+const WATER_REACTIVE: &[&str] = &["H260", "H261", "EUH014", "EUH029"];
+const TOXIC_GAS_WITH_ACIDS: &[&str] = &["EUH031", "EUH032"];
+
+/// Words of the substance names, a trailing `*` matches every word starting with the rest.
+const ACID_NAMES: &[&str] = &["*säure", "acid"];
+const BASE_NAMES: &[&str] = &["*hydroxid", "*hydroxide", "*lauge", "ammoniak", "ammonia"];
+
+/// Words of the reactions listed by the provider that refer to a whole class of substances.
+const OXIDISER_WORDS: &[&str] = &["oxidationsmittel*", "oxidizing", "oxidising", "oxidants", "oxidizers"];
+const ACID_WORDS: &[&str] = &["säure*", "*säuren", "acid*"];
+const BASE_WORDS: &[&str] = &["lauge*", "laugen", "basen", "bases", "alkalihydroxid*"];
+const WATER_WORDS: &[&str] = &["wasser", "water", "feuchtigkeit", "moisture"];
+
+/// Compares a lowercase word with a pattern, see `ACID_NAMES`.
+fn matches_word(word: &str, pattern: &str) -> bool {
+  match (pattern.strip_prefix('*'), pattern.strip_suffix('*')) {
+    (Some(suffix), _) => word.ends_with(suffix),
+    (_, Some(prefix)) => word.starts_with(prefix),
+    _ => word == pattern,
+  }
+}
+
+fn mentions(text: &str, patterns: &[&str]) -> bool {
+  text
+    .to_lowercase()
+    .split(|c: char| !c.is_alphanumeric())
+    .any(|word| patterns.iter().any(|pattern| matches_word(word, pattern)))
+}
+
+/// Names have to start at a word boundary, `Methanol` doesn't mention `Ethanol`.
+fn mentions_name(text: &str, name: &str) -> bool {
+  let text = text.to_lowercase();
+  let name = name.trim().to_lowercase();
+  // short names and formulas like `O2` would match too often
+  if name.chars().count() < 4 {
+    return false;
+  }
+  text.match_indices(&name).any(|(i, _)| match text[..i].chars().last() {
+    Some(c) => !c.is_alphabetic(),
+    None => true,
+  })
+}
+
+/// What is known about a substance for the checks.
+struct Traits<'a> {
+  names: Vec<&'a str>,
+  reactions: &'a [String],
+  flammable: bool,
+  oxidising: bool,
+  water_reactive: bool,
+  toxic_gas_with_acids: bool,
+  acid: bool,
+  base: bool,
+  corrosive: bool,
+  water: bool,
+}
+
+impl<'a> Traits<'a> {
+  fn new(substance: &'a SubstanceData) -> Self {
+    let phrases: Vec<&str> = substance
+      .h_phrases
+      .current()
+      .iter()
+      .flat_map(|(code, _)| code.split('+').map(str::trim))
+      .collect();
+    let has_phrase = |codes: &[&str]| phrases.iter().any(|p| codes.contains(p));
+    let symbols = substance.symbols.current();
+    let has_symbol = |symbol: &str| symbols.iter().any(|s| s == symbol);
+
+    let mut names = vec![substance.name.current().as_str()];
+    names.extend(substance.alternative_names.iter().map(String::as_str));
+    let named = |patterns: &[&str]| names.iter().any(|name| mentions(name, patterns));
+    let formula = substance
+      .molecular_formula
+      .current()
+      .as_deref()
+      .unwrap_or_default()
+      .replace('₂', "2");
+    let solution = matches!(
+      substance.amount.as_ref().map(|a| &a.unit),
+      Some(Unit::SolutionRelative)
+        | Some(Unit::SolutionMol)
+        | Some(Unit::SolutionMillimol)
+        | Some(Unit::SolutionMicromol)
+        | Some(Unit::SolutionGram)
+        | Some(Unit::SolutionMilligram)
+    );
+
+    Traits {
+      flammable: has_symbol("ghs02") || has_phrase(FLAMMABLE),
+      oxidising: has_symbol("ghs03") || has_phrase(OXIDISING),
+      water_reactive: has_phrase(WATER_REACTIVE),
+      toxic_gas_with_acids: has_phrase(TOXIC_GAS_WITH_ACIDS),
+      acid: named(ACID_NAMES),
+      base: named(BASE_NAMES),
+      corrosive: has_phrase(&["H314"]),
+      // solutions are expected to be aqueous
+      water: solution || formula == "H2O" || named(&["wasser", "water"]),
+      reactions: substance.reactions.current().as_slice(),
+      names,
+    }
+  }
+
+  fn name(&self) -> &str {
+    self.names[0]
+  }
+
+  /// The first reaction listed for this substance that mentions the other one by name or by its class.
+  fn reported_reaction(&self, other: &Traits) -> Option<&str> {
+    self
+      .reactions
+      .iter()
+      .find(|line| {
+        other.names.iter().any(|name| mentions_name(line, name))
+          || (other.oxidising && mentions(line, OXIDISER_WORDS))
+          || (other.acid && mentions(line, ACID_WORDS))
+          || (other.base && mentions(line, BASE_WORDS))
+          || (other.water && mentions(line, WATER_WORDS))
+      })
+      .map(String::as_str)
+  }
+}
+
+/// Checks a substance against another one, the checks are not symmetric.
+fn check(a: &Traits, b: &Traits, language: Language) -> Vec<(Hazard, String)> {
+  let (first, second) = (a.name(), b.name());
+  let mut found = Vec::new();
+  let german = matches!(language, Language::German);
+
+  if a.oxidising && b.flammable {
+    found.push((
+      Hazard::FireOrExplosion,
+      match german {
+        true => format!(
+          "{} ist brandfördernd, mit {} besteht Brand- und Explosionsgefahr.",
+          first, second
+        ),
+        false => format!(
+          "{} is oxidising, there is a risk of fire and explosion with {}.",
+          first, second
+        ),
+      },
+    ));
+  }
+  if a.acid && b.toxic_gas_with_acids {
+    found.push((
+      Hazard::ToxicGas,
+      match german {
+        true => format!("{} setzt aus {} giftige Gase frei.", first, second),
+        false => format!("{} liberates toxic gases from {}.", first, second),
+      },
+    ));
+  }
+  if a.water && b.water_reactive {
+    found.push((
+      Hazard::WaterReactive,
+      match german {
+        true => format!(
+          "{} reagiert heftig mit {} oder setzt dabei entzündbare oder giftige Gase frei.",
+          second, first
+        ),
+        false => format!(
+          "{} reacts violently with {} or releases flammable or toxic gases.",
+          second, first
+        ),
+      },
+    ));
+  }
+  if a.acid && a.corrosive && b.base && b.corrosive {
+    found.push((
+      Hazard::Neutralization,
+      match german {
+        true => format!("{} und {} reagieren unter starker Wärmeentwicklung.", first, second),
+        false => format!("{} and {} react with strong heat generation.", first, second),
+      },
+    ));
+  }
+  if let Some(reaction) = a.reported_reaction(b) {
+    found.push((
+      Hazard::Reported,
+      match german {
+        true => format!("{} kann gefährlich mit {} reagieren: {}", first, second, reaction),
+        false => format!("{} may react dangerously with {}: {}", first, second, reaction),
+      },
+    ));
+  }
+
+  found
+}
+
+/// Looks for pairs of substances that must not come into contact, every hazard is reported once per pair.
+///
+/// The checks use the H statements, the names and the reactions listed by the provider, they can't replace
+/// a look into the safety data sheets.
+pub fn check_incompatibilities(substances: &[SubstanceData], language: Language) -> Vec<Incompatibility> {
+  let traits: Vec<Traits> = substances.iter().map(Traits::new).collect();
+
+  let mut incompatibilities: Vec<Incompatibility> = Vec::new();
+  for (i, a) in traits.iter().enumerate() {
+    for b in traits.iter().skip(i + 1) {
+      for (first, second) in [(a, b), (b, a)].iter() {
+        for (hazard, message) in check(first, second, language) {
+          let known = incompatibilities.iter().any(|known| {
+            known.hazard == hazard
+              && known.substances.iter().any(|s| s == first.name())
+              && known.substances.iter().any(|s| s == second.name())
+          });
+          if !known {
+            incompatibilities.push(Incompatibility {
+              substances: [first.name().into(), second.name().into()],
+              hazard,
+              message,
+            });
+          }
+        }
+      }
+    }
+  }
+
+  incompatibilities
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use cabr2_types::{Amount, Data};
+
+  fn substance(name: &str, h_phrases: &[&str], symbols: &[&str], reactions: &[&str]) -> SubstanceData {
+    SubstanceData {
+      name: Data::new(name.into()),
+      h_phrases: Data::new(h_phrases.iter().map(|c| (c.to_string(), String::new())).collect()),
+      symbols: Data::new(symbols.iter().map(|s| s.to_string()).collect()),
+      reactions: Data::new(reactions.iter().map(|r| r.to_string()).collect()),
+      ..SubstanceData::default()
+    }
+  }
+
+  #[test]
+  fn test_phrase_classes() {
+    let permanganate = substance("Kaliumpermanganat", &["H272", "H302", "H410"], &["ghs03"], &[]);
+    let ethanol = substance("Ethanol", &["H225", "H319"], &["ghs02", "ghs07"], &[]);
+    let cyanide = substance("Kaliumcyanid", &["H300+H310+H330", "EUH032"], &["ghs06"], &[]);
+    let acid = substance("Salzsäure", &["H290", "H314", "H335"], &["ghs05"], &[]);
+
+    let incompatibilities = check_incompatibilities(&[permanganate, ethanol, cyanide, acid], Language::English);
+    assert_eq!(incompatibilities.len(), 2);
+    assert_eq!(incompatibilities[0].hazard, Hazard::FireOrExplosion);
+    assert_eq!(incompatibilities[0].substances, ["Kaliumpermanganat", "Ethanol"]);
+    assert_eq!(incompatibilities[1].hazard, Hazard::ToxicGas);
+    assert_eq!(
+      incompatibilities[1].message,
+      "Salzsäure liberates toxic gases from Kaliumcyanid."
+    );
+  }
+
+  #[test]
+  fn test_water() {
+    let sodium = substance("Natrium", &["H260", "H314"], &["ghs02", "ghs05"], &[]);
+    let mut solution = substance("Natriumchlorid", &[], &[], &[]);
+    solution.amount = Some(Amount {
+      value: "10".into(),
+      unit: Unit::SolutionRelative,
+    });
+
+    let incompatibilities = check_incompatibilities(&[sodium, solution], Language::German);
+    assert_eq!(incompatibilities.len(), 1);
+    assert_eq!(incompatibilities[0].hazard, Hazard::WaterReactive);
+    assert_eq!(incompatibilities[0].substances, ["Natriumchlorid", "Natrium"]);
+  }
+
+  #[test]
+  fn test_reported_reactions() {
+    let calcium = substance(
+      "Calcium",
+      &["H261"],
+      &["ghs02"],
+      &[
+        "Explosionsgefahr bei Kontakt mit:",
+        "Alkalicarbonaten; Blei(II)-chlorid / Wärme; Chlor; Hydroxylamin",
+        "verdünnten Säuren --> Wasserstoffentwicklung",
+      ],
+    );
+    let hydroxylamine = substance("Hydroxylamin", &["H290", "H302"], &[], &[]);
+    let acetic_acid = substance("Essigsäure", &["H226", "H314"], &["ghs02", "ghs05"], &[]);
+    let methanol = substance("Methanol", &["H225"], &["ghs02"], &[]);
+
+    let incompatibilities = check_incompatibilities(&[calcium, hydroxylamine, acetic_acid, methanol], Language::German);
+    let reported: Vec<&str> = incompatibilities
+      .iter()
+      .filter(|i| i.hazard == Hazard::Reported)
+      .map(|i| i.message.as_str())
+      .collect();
+    assert_eq!(
+      reported,
+      vec![
+        "Calcium kann gefährlich mit Hydroxylamin reagieren: Alkalicarbonaten; Blei(II)-chlorid / Wärme; Chlor; \
+         Hydroxylamin",
+        "Calcium kann gefährlich mit Essigsäure reagieren: verdünnten Säuren --> Wasserstoffentwicklung",
+      ]
+    );
+  }
+}
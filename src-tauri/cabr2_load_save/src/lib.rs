@@ -4,14 +4,21 @@
 
 mod error;
 pub mod handler;
+mod incompatibilities;
+mod sections;
 mod types;
 
 #[cfg(feature = "tauri_plugin")]
 pub mod plugin;
 
+#[cfg(feature = "webserver")]
+pub mod webserver;
+
 #[cfg(feature = "beryllium")]
 mod beryllium;
 #[cfg(feature = "cabr2")]
 mod cabr2;
-#[cfg(feature = "pdf")]
+#[cfg(feature = "docx")]
+mod docx;
+#[cfg(any(feature = "pdf", feature = "native_pdf"))]
 mod pdf;
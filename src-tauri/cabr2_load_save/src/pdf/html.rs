@@ -0,0 +1,595 @@
+use std::{
+  io::Read,
+  path::PathBuf,
+  sync::{mpsc, Arc, Mutex},
+  thread,
+  time::SystemTime,
+};
+
+use cabr2_types::ProviderMapping;
+use handlebars::{to_json, JsonValue as Json};
+use lazy_static::lazy_static;
+use lopdf::Document;
+use serde::Serialize;
+use wkhtmltopdf::{PdfApplication, Size};
+
+use super::{
+  merge, prepare_document, print_incompatibilities_option,
+  templates::{Orientation, PageOptions, PageSetup, PageSize, Template, TemplateRegistry, DEFAULT_TEMPLATE, TEMPLATE},
+  types::PDFCaBr2Document,
+  PROVIDER_MAPPING,
+};
+use crate::{
+  error::{LoadSaveError, Result},
+  types::{CaBr2Document, OptionSchema, Options, Saver},
+};
+
+type PDFThreadChannels = Arc<Mutex<(mpsc::SyncSender<Page>, mpsc::Receiver<Result<Vec<u8>>>)>>;
+
+/// A rendered page of a template and how wkhtmltopdf should print it.
+struct Page {
+  html: String,
+  title: String,
+  setup: PageSetup,
+}
+
+pub struct PDF;
+
+impl PDF {
+  pub fn new(provider_mapping: ProviderMapping) -> PDF {
+    PROVIDER_MAPPING.lock().unwrap().extend(provider_mapping.into_iter());
+    PDF
+  }
+}
+
+impl Saver for PDF {
+  fn save_document(&self, document: CaBr2Document, options: &Options) -> Result<Vec<u8>> {
+    lazy_static! {
+      static ref PDF_THREAD_CHANNEL: PDFThreadChannels = Arc::new(Mutex::new(init_pdf_application()));
+    }
+
+    let registry = registry();
+    let id = options.get(TEMPLATE)?.unwrap_or_else(|| DEFAULT_TEMPLATE.to_string());
+    let template = registry.get(&id)?;
+    let setup = template.manifest.page_setup(&PageOptions::from_options(options)?);
+
+    let title = document.header.document_title.clone();
+    let document = prepare_document(document, options)?;
+    let channels = PDF_THREAD_CHANNEL.lock().unwrap();
+
+    let pages = render_doc(&registry, &id, template, &setup, &document, None)?;
+    let mut pdfs = convert(&channels, pages, &title, setup)?;
+
+    // a template page that overflows shifts the numbers of all following pages, so they are rendered again with the
+    // page counts of the PDFs
+    let page_counts: Vec<usize> = pdfs.iter().map(|pdf| pdf.get_pages().len()).collect();
+    if page_counts.iter().any(|count| *count != 1) {
+      let pages = render_doc(&registry, &id, template, &setup, &document, Some(&page_counts))?;
+      pdfs = convert(&channels, pages, &title, setup)?;
+    }
+
+    let mut merged_pdf = merge::merge_pdfs(pdfs)?;
+
+    let mut buf = Vec::new();
+    merged_pdf.save_to(&mut buf)?;
+
+    Ok(buf)
+  }
+
+  fn save_options(&self) -> Vec<OptionSchema> {
+    let mut options = registry().options();
+    options.push(print_incompatibilities_option());
+    options
+  }
+}
+
+/// Converts the html pages with the pdf thread, every page becomes a PDF with one or more pages.
+fn convert(channels: &PDFChannels, pages: Vec<String>, title: &str, setup: PageSetup) -> Result<Vec<Document>> {
+  let mut documents = Vec::with_capacity(pages.len());
+  for html in pages {
+    channels
+      .0
+      .send(Page {
+        html,
+        title: title.to_string(),
+        setup,
+      })
+      .expect("sending data to pdf thread failed");
+
+    let pdf: Vec<u8> = channels.1.recv().expect("receiving data from pdf thread failed")?;
+    match Document::load_mem(&pdf) {
+      Ok(doc) => documents.push(doc),
+      Err(_) => return Err(LoadSaveError::PdfMergeError("loading pdf failed".into())),
+    }
+  }
+
+  Ok(documents)
+}
+
+/// Renders every page of the template set `id` to html, the substance page is repeated until all substances are
+/// listed.
+///
+/// `page_counts` are the numbers of PDF pages of the rendered pages, without them every page is counted once.
+fn render_doc(
+  registry: &TemplateRegistry,
+  id: &str,
+  template: &Template,
+  setup: &PageSetup,
+  document: &PDFCaBr2Document,
+  page_counts: Option<&[usize]>,
+) -> Result<Vec<String>> {
+  #[derive(Debug, Serialize)]
+  struct Context<'a> {
+    stylesheet: &'a String,
+    document: &'a Json,
+    page: PageNumber,
+  }
+
+  #[derive(Debug, Serialize)]
+  struct PageNumber {
+    number: usize,
+    count: usize,
+    /// The substance page is continued from the previous page.
+    continued: bool,
+  }
+
+  let manifest = &template.manifest;
+  let substance_pages: Vec<Json> = document
+    .substance_data
+    .chunks(manifest.substances_per_page(setup))
+    .map(to_json)
+    .collect();
+  let document = to_json(document);
+
+  let mut pages: Vec<(String, Json, bool)> = Vec::new();
+  for (page, name) in manifest.pages.iter().zip(template.page_names(id)) {
+    if manifest.substance_page.as_ref() == Some(page) && substance_pages.len() > 1 {
+      for (i, substances) in substance_pages.iter().enumerate() {
+        // the helpers read the substances from the document, so phrases and sources only list the ones of the page
+        let mut document = document.clone();
+        document["substanceData"] = substances.clone();
+        pages.push((name.clone(), document, i > 0));
+      }
+    } else {
+      pages.push((name, document.clone(), false));
+    }
+  }
+
+  let page_counts = match page_counts {
+    Some(page_counts) if page_counts.len() == pages.len() => page_counts.to_vec(),
+    _ => vec![1; pages.len()],
+  };
+  let count = page_counts.iter().sum();
+
+  let mut number = 1;
+  let mut rendered = Vec::with_capacity(pages.len());
+  for ((name, document, continued), page_count) in pages.iter().zip(page_counts) {
+    let context = Context {
+      stylesheet: &template.stylesheet,
+      document,
+      page: PageNumber {
+        number,
+        count,
+        continued: *continued,
+      },
+    };
+    rendered.push(registry.handlebars.render(name, &context)?);
+    number += page_count;
+  }
+
+  Ok(rendered)
+}
+
+#[inline]
+/// The template sets are only read and compiled again if one of their files changed, so changes of the user are used
+/// without restart.
+fn registry() -> Arc<TemplateRegistry> {
+  type Cache = Mutex<Option<(Vec<(PathBuf, Option<SystemTime>)>, Arc<TemplateRegistry>)>>;
+  lazy_static! {
+    static ref CACHE: Cache = Mutex::new(None);
+  }
+
+  let state = TemplateRegistry::state();
+  let mut cache = CACHE.lock().unwrap();
+  match cache.as_ref() {
+    Some((cached, registry)) if *cached == state => registry.clone(),
+    _ => {
+      log::debug!("loading the pdf templates");
+      let registry = Arc::new(init_handlebars(TemplateRegistry::load()));
+      *cache = Some((state, registry.clone()));
+      registry
+    }
+  }
+}
+
+fn init_handlebars(mut registry: TemplateRegistry) -> TemplateRegistry {
+  let reg = &mut registry.handlebars;
+  reg.register_helper("ghs_symbols", Box::new(handlebar_helpers::ghs_symbols));
+  reg.register_helper("h_p_phrases_numbers", Box::new(handlebar_helpers::h_p_phrases_numbers));
+  reg.register_helper("h_p_phrases", Box::new(handlebar_helpers::h_p_phrases));
+  reg.register_helper("value_or_dash", Box::new(handlebar_helpers::value_or_dash));
+  reg.register_helper("providers", Box::new(handlebar_helpers::providers));
+
+  registry
+}
+
+type PDFChannels = (mpsc::SyncSender<Page>, mpsc::Receiver<Result<Vec<u8>>>);
+
+fn init_pdf_application() -> PDFChannels {
+  let (tauri_tx, pdf_rx) = mpsc::sync_channel(0);
+  let (pdf_tx, tauri_rx) = mpsc::sync_channel(0);
+
+  /* #region  pdf thread */
+
+  thread::spawn(move || {
+    log::debug!("[pdf_thread]: initializing pdf application");
+    let pdf_app = match PdfApplication::new() {
+      Ok(app) => app,
+      Err(e) => {
+        log::error!("[pdf_thread]: initialization of pdf application failed");
+        pdf_tx
+          .send(Err(LoadSaveError::PdfError(e)))
+          .expect("[pdf_thread]: pdf thread could not send data");
+        return;
+      }
+    };
+
+    loop {
+      log::trace!("[pdf_thread]: waiting for html to convert");
+      let page: Page = pdf_rx.recv().expect("[pdf_thread]: pdf thread could not receive data");
+      log::trace!("[pdf_thread]: got html");
+
+      let mut buf = Vec::new();
+
+      let margins = page.setup.margins;
+      let result = match pdf_app
+        .builder()
+        .page_size(match page.setup.size {
+          PageSize::A4 => wkhtmltopdf::PageSize::A4,
+          PageSize::A5 => wkhtmltopdf::PageSize::A5,
+          PageSize::Letter => wkhtmltopdf::PageSize::Letter,
+          PageSize::Legal => wkhtmltopdf::PageSize::Legal,
+        })
+        .orientation(match page.setup.orientation {
+          Orientation::Portrait => wkhtmltopdf::Orientation::Portrait,
+          Orientation::Landscape => wkhtmltopdf::Orientation::Landscape,
+        })
+        .margin((
+          Size::Millimeters(margins.top),
+          Size::Millimeters(margins.right),
+          Size::Millimeters(margins.bottom),
+          Size::Millimeters(margins.left),
+        ))
+        .title(&page.title)
+        .build_from_html(&page.html)
+      {
+        Ok(mut pdfout) => match pdfout.read_to_end(&mut buf) {
+          Ok(_) => Ok(buf),
+          Err(e) => Err(LoadSaveError::IOError(e)),
+        },
+        Err(e) => Err(LoadSaveError::PdfError(e)),
+      };
+
+      log::trace!("[pdf_thread]: sending result");
+      pdf_tx
+        .send(result)
+        .expect("[pdf_thread]: pdf thread could not send data");
+      log::trace!("[pdf_thread]: finished");
+    }
+  });
+
+  /* #endregion */
+
+  (tauri_tx, tauri_rx)
+}
+
+/// Custom helpers for handlebars
+mod handlebar_helpers {
+  use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+  };
+
+  use handlebars::{Handlebars, JsonRender, RenderError};
+  use lazy_static::lazy_static;
+
+  use cabr2_config::GHSSymbols;
+
+  use crate::pdf::{types::PDFSubstanceData, PROVIDER_MAPPING};
+
+  /// The symbols column of the default template fits three symbols side by side.
+  const SYMBOLS_PER_ROW: usize = 3;
+
+  lazy_static! {
+    static ref GHS_SYMBOLS: Arc<Mutex<GHSSymbols>> = Arc::new(Mutex::new(get_hazard_symbols().unwrap_or_default()));
+  }
+
+  fn get_hazard_symbols() -> Result<GHSSymbols, impl std::error::Error> {
+    tokio::runtime::Handle::current().block_on(cabr2_config::get_hazard_symbols())
+  }
+
+  /// Inlines the actual ghs-symbol-images from their keys as base64-encodes pngs
+  ///
+  /// The list of keys is written in rows that can't be wrapped.
+  pub fn ghs_symbols(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+  ) -> handlebars::HelperResult {
+    let keys = match h.param(0).unwrap().value() {
+      handlebars::JsonValue::Array(keys) => keys,
+      value => {
+        return Err(RenderError::new(format!(
+          "ghs_symbols expects a list of symbols: {}",
+          value
+        )))
+      }
+    };
+    for row in keys.chunks(SYMBOLS_PER_ROW) {
+      out.write("<div class='ghs-row'>")?;
+      for key in row {
+        write_symbol(key, out)?;
+      }
+      out.write("</div>")?;
+    }
+    Ok(())
+  }
+
+  fn write_symbol(key: &handlebars::JsonValue, out: &mut dyn handlebars::Output) -> handlebars::HelperResult {
+    out.write("<img class='ghs' src=\"")?;
+    out.write(
+      GHS_SYMBOLS
+        .lock()
+        .unwrap()
+        .get(key.as_str().unwrap_or_default())
+        .unwrap_or(&String::from(""))
+        .as_str(),
+    )?;
+    out.write("\" alt=\"")?;
+    out.write(&key.render())?; // alt content
+    out.write("\" />")?;
+    Ok(())
+  }
+
+  /// Writes numbers of h- or p-phrases
+  pub fn h_p_phrases_numbers(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+  ) -> handlebars::HelperResult {
+    let param = h.param(0).unwrap();
+    let phrases: Vec<(String, String)> = serde_json::from_value(param.value().clone())?;
+    out.write(&phrases.into_iter().map(|p| p.0).collect::<Vec<String>>().join("-"))?;
+    Ok(())
+  }
+
+  /// handlebar helper: write h- or p-phrases in the following style:
+  ///
+  /// - number1: text1
+  /// - number2: text2
+  /// - ...
+  pub fn h_p_phrases(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    ctx: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+  ) -> handlebars::HelperResult {
+    #[inline]
+    /// Returns a `Vec<String>` where the number and text are concatenated with a non-breaking space
+    fn map_phrases(phrases: Vec<(String, String)>) -> Vec<String> {
+      phrases.into_iter().map(|p| format!("{}:&nbsp;{}", p.0, p.1)).collect()
+    }
+
+    /// Helper enum for code below
+    enum PhraseType {
+      H,
+      P,
+    }
+
+    let param = h.param(0).unwrap();
+    let phrases_selector = match param.value().as_str().unwrap() {
+      "h" => PhraseType::H,
+      "p" => PhraseType::P,
+      _ => return Err(RenderError::new(format!("unknown phrase type: {}", param.value()))),
+    };
+
+    let substances: Vec<PDFSubstanceData> =
+      match serde_json::from_value(ctx.data()["document"]["substanceData"].clone()) {
+        Ok(substances) => substances,
+        Err(err) => return Err(RenderError::new(format!("json deserialize error: {:?}", err))),
+      };
+
+    let phrases: BTreeSet<String> = substances
+      .into_iter()
+      .map(|s| {
+        map_phrases(match phrases_selector {
+          PhraseType::H => s.h_phrases.data,
+          PhraseType::P => s.p_phrases.data,
+        })
+      })
+      .flatten()
+      .collect();
+
+    for p in phrases.iter() {
+      out.write(p)?;
+      out.write("<br/>")?;
+    }
+    Ok(())
+  }
+
+  /// Writes a `-` if the value is null, otherwise the value itself is written
+  pub fn value_or_dash(
+    h: &handlebars::Helper,
+    _: &Handlebars,
+    _: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+  ) -> handlebars::HelperResult {
+    let param = h.param(0).unwrap();
+    match param.value() {
+      handlebars::JsonValue::Null => out.write("-")?,
+      _ => out.write(&param.render())?,
+    };
+    Ok(())
+  }
+
+  /// Writes the `Set` of providers separated by `,`
+  pub fn providers(
+    _: &handlebars::Helper,
+    _: &Handlebars,
+    ctx: &handlebars::Context,
+    _: &mut handlebars::RenderContext,
+    out: &mut dyn handlebars::Output,
+  ) -> handlebars::HelperResult {
+    let substances: Vec<PDFSubstanceData> =
+      match serde_json::from_value(ctx.data()["document"]["substanceData"].clone()) {
+        Ok(substances) => substances,
+        Err(err) => return Err(RenderError::new(format!("json deserialize error: {:?}", err))),
+      };
+
+    let providers: BTreeSet<String> = substances.into_iter().map(|s| s.source.provider).collect();
+
+    let provider_mapping = PROVIDER_MAPPING.lock().unwrap();
+    // kill empty string from empty substance lines
+    for (i, provider) in providers
+      .iter()
+      .filter(|p| !(p.is_empty() || p.as_str() == "custom"))
+      .map(|p| provider_mapping.get(p).map(String::as_str).unwrap_or(p))
+      .enumerate()
+    {
+      if i > 0 {
+        out.write(", ")?;
+      }
+      out.write(provider)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::*;
+  use crate::{
+    pdf::types::{Data, PDFSubstanceData},
+    types::Header,
+  };
+
+  #[test]
+  fn test_pagination() {
+    let registry = init_handlebars(TemplateRegistry::from_folders(&[PathBuf::from("../../templates")]));
+    let template = registry.get(DEFAULT_TEMPLATE).unwrap();
+    let document = PDFCaBr2Document {
+      header: Header {
+        document_title: "Betriebsanweisungen nach EG Nr. 1272/2008".into(),
+        organisation: "für chemische Laboratorien".into(),
+        lab_course: "Praktikum".into(),
+        name: "Max Mustermann".into(),
+        place: "42".into(),
+        assistant: "Erika Mustermann".into(),
+        preparation: "Acetylsalicylsäure".into(),
+      },
+      substance_data: (0..20)
+        .map(|i| PDFSubstanceData {
+          name: Data::new(format!("Stoff {}", i)),
+          ..Default::default()
+        })
+        .collect(),
+      human_and_environment_danger: Vec::new(),
+      rules_of_conduct: Vec::new(),
+      in_case_of_danger: Vec::new(),
+      disposal: Vec::new(),
+      incompatibilities: Vec::new(),
+    };
+
+    let setup = template.manifest.page_setup(&PageOptions::default());
+    let pages = render_doc(&registry, DEFAULT_TEMPLATE, template, &setup, &document, None).unwrap();
+
+    // 8 substances per page and the second page
+    assert_eq!(pages.len(), 4);
+    assert!(pages[0].contains("Stoff 7<br/>") && !pages[0].contains("Stoff 8<br/>"));
+    assert!(!pages[0].contains("(Fortsetzung)"));
+    assert!(pages[1].contains("Stoff 8<br/>") && pages[1].contains("(Fortsetzung)"));
+    assert!(pages[2].contains("Stoff 19<br/>"));
+    // the header is repeated on every substance page
+    assert!(pages[2].contains("Acetylsalicylsäure"));
+    assert!(pages[2].contains("Seite 3 von 4"));
+    assert!(pages[3].contains("Seite 4 von 4"));
+
+    // the second substance page overflowed onto another page
+    let pages = render_doc(
+      &registry,
+      DEFAULT_TEMPLATE,
+      template,
+      &setup,
+      &document,
+      Some(&[1, 2, 1, 1]),
+    )
+    .unwrap();
+    assert!(pages[1].contains("Seite 2 von 5"));
+    assert!(pages[2].contains("Seite 4 von 5"));
+    assert!(pages[3].contains("Seite 5 von 5"));
+
+    // fewer substances fit on a landscape page
+    let setup = template.manifest.page_setup(&PageOptions {
+      orientation: Some(Orientation::Landscape),
+      ..Default::default()
+    });
+    let pages = render_doc(&registry, DEFAULT_TEMPLATE, template, &setup, &document, None).unwrap();
+    assert_eq!(pages.len(), 5);
+    assert!(pages[0].contains("Stoff 4<br/>") && !pages[0].contains("Stoff 5<br/>"));
+  }
+
+  #[test]
+  fn test_ghs_symbols() {
+    // the symbols are loaded from the config in the runtime
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (rows, single) = runtime
+      .block_on(runtime.spawn_blocking(|| {
+        let mut reg = handlebars::Handlebars::new();
+        reg.register_helper("ghs_symbols", Box::new(handlebar_helpers::ghs_symbols));
+        let data = serde_json::json!({ "symbols": ["ghs01", "ghs02", "ghs05", "ghs07"], "symbol": "ghs01" });
+        (
+          reg.render_template("{{ghs_symbols symbols}}", &data),
+          reg.render_template("{{ghs_symbols symbol}}", &data),
+        )
+      }))
+      .unwrap();
+
+    let rows = rows.unwrap();
+    assert_eq!(rows.matches("<div class='ghs-row'>").count(), 2);
+    assert_eq!(rows.matches("<img class='ghs'").count(), 4);
+    assert!(rows.contains("alt=\"ghs07\" /></div>"));
+    assert!(single.is_err());
+  }
+
+  #[test]
+  fn test_providers() {
+    PROVIDER_MAPPING
+      .lock()
+      .unwrap()
+      .insert("gestis".into(), "IFA GESTIS".into());
+    let substance = |provider: &str| {
+      let mut substance = PDFSubstanceData::default();
+      substance.source.provider = provider.into();
+      substance
+    };
+    let data = serde_json::json!({
+      "document": { "substanceData": [substance("gestis"), substance("custom"), substance("unknown")] }
+    });
+
+    let mut reg = handlebars::Handlebars::new();
+    reg.register_helper("providers", Box::new(handlebar_helpers::providers));
+    // providers that are not known anymore are written with their id
+    assert_eq!(
+      reg.render_template("{{providers}}", &data).unwrap(),
+      "IFA GESTIS, unknown"
+    );
+  }
+}
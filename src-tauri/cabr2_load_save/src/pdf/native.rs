@@ -0,0 +1,932 @@
+use std::{
+  collections::{BTreeSet, HashMap},
+  io::BufWriter,
+  mem,
+};
+
+use image::{DynamicImage, Rgb, RgbImage};
+use printpdf::{
+  BuiltinFont, CustomPdfConformance, Image, IndirectFontRef, Line, Mm, PdfConformance, PdfDocument,
+  PdfDocumentReference, PdfLayerReference, Point,
+};
+
+use cabr2_config::DATA_DIR;
+use cabr2_types::ProviderMapping;
+
+use super::{
+  prepare_document, print_incompatibilities_option,
+  types::{Data, PDFCaBr2Document, PDFSubstanceData},
+  PROVIDER_MAPPING,
+};
+use crate::{
+  error::Result,
+  types::{CaBr2Document, Header, OptionSchema, Options, Saver},
+};
+
+/* #region layout */
+
+// all lengths are in mm, y is measured from the top of the page
+const PAGE_WIDTH: f64 = 210.0;
+const PAGE_HEIGHT: f64 = 297.0;
+const MARGIN: f64 = 15.0;
+const CONTENT_WIDTH: f64 = PAGE_WIDTH - 2.0 * MARGIN;
+const PADDING: f64 = 1.2;
+const MM_PER_PT: f64 = 25.4 / 72.0;
+const LINE_SPACING: f64 = 1.2;
+const GHS_SIZE: f64 = 8.5;
+const SIGNATURE_HEIGHT: f64 = 37.0;
+const SUBSTANCE_HEIGHT: f64 = 12.0;
+/// Space that is kept below the substances of every page for the phrases.
+const MIN_PHRASES_HEIGHT: f64 = 40.0;
+const MIN_FONT_SIZE: f64 = 5.0;
+/// The first column contains name and formula, the html template spans it over two columns.
+const SUBSTANCE_COLUMNS: [f64; 7] = [45.0, 17.0, 19.0, 30.0, 27.0, 24.0, 18.0];
+
+/* #endregion */
+
+/// Lays out the safety sheet without wkhtmltopdf, using the builtin Helvetica fonts.
+pub struct NativePDF;
+
+impl NativePDF {
+  pub fn new(provider_mapping: ProviderMapping) -> NativePDF {
+    PROVIDER_MAPPING.lock().unwrap().extend(provider_mapping);
+    NativePDF
+  }
+}
+
+impl Saver for NativePDF {
+  fn save_document(&self, document: CaBr2Document, options: &Options) -> Result<Vec<u8>> {
+    render_document(prepare_document(document, options)?)
+  }
+
+  /// The columns are laid out for A4 portrait, so there are no template or page options.
+  fn save_options(&self) -> Vec<OptionSchema> {
+    vec![print_incompatibilities_option()]
+  }
+}
+
+fn render_document(document: PDFCaBr2Document) -> Result<Vec<u8>> {
+  let (doc, page, layer) = PdfDocument::new(
+    document.header.document_title.as_str(),
+    Mm(PAGE_WIDTH),
+    Mm(PAGE_HEIGHT),
+    "content",
+  );
+  // PDF/A would embed an icc profile and forbids the builtin fonts
+  let doc = doc.with_conformance(PdfConformance::Custom(CustomPdfConformance {
+    requires_icc_profile: false,
+    requires_xmp_metadata: false,
+    allows_default_fonts: true,
+    ..Default::default()
+  }));
+
+  let fonts = Fonts::new(&doc)?;
+  let symbols = load_symbols(&document.substance_data);
+  let substance_pages = paginate(&document, &symbols);
+  let count = substance_pages.len() + 1;
+
+  let mut first = Some((page, layer));
+  for (i, substances) in substance_pages.iter().enumerate() {
+    let (page, layer) = first
+      .take()
+      .unwrap_or_else(|| doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content"));
+    let canvas = Canvas::new(doc.get_page(page).get_layer(layer), &fonts);
+    render_substance_page(&canvas, &document, substances, &symbols, i > 0);
+    render_page_number(&canvas, i + 1, count);
+  }
+
+  let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "content");
+  let second = Canvas::new(doc.get_page(page).get_layer(layer), &fonts);
+  render_second_page(&second, &document);
+  render_page_number(&second, count, count);
+
+  let mut pdf = Vec::new();
+  doc.save(&mut BufWriter::new(&mut pdf))?;
+
+  Ok(pdf)
+}
+
+/* #region pages */
+
+/// Splits the substances into the pages of the table, the header is repeated on every page.
+fn paginate<'a>(document: &'a PDFCaBr2Document, symbols: &HashMap<String, RgbImage>) -> Vec<&'a [PDFSubstanceData]> {
+  let substances = &document.substance_data;
+  // the sources of all substances are at least as high as the ones of a single page
+  let bottom = PAGE_HEIGHT - MARGIN - sources(substances).height() - phrases_heading().height() - MIN_PHRASES_HEIGHT;
+
+  let mut pages = Vec::new();
+  let mut start = 0;
+  while start < substances.len() || pages.is_empty() {
+    let header = header_rows(&document.header, !pages.is_empty());
+    let mut y = MARGIN + header.iter().map(|row| row_height(row, 0.0)).sum::<f64>();
+
+    let mut end = start;
+    while end < substances.len() {
+      let height = row_height(&substance_cells(&substances[end], symbols), SUBSTANCE_HEIGHT);
+      // a substance that doesn't fit on an empty page gets a page of its own
+      if end > start && y + height > bottom {
+        break;
+      }
+      y += height;
+      end += 1;
+    }
+
+    pages.push(&substances[start..end]);
+    start = end;
+  }
+
+  pages
+}
+
+/// The titles, the fields of the header and the head of the substance table.
+fn header_rows(header: &Header, continued: bool) -> Vec<Vec<Cell<'static>>> {
+  let mut rows: Vec<Vec<Cell>> = [&header.document_title, &header.organisation, &header.lab_course]
+    .iter()
+    .map(|title| vec![Cell::new(CONTENT_WIDTH, vec![Text::new(title.as_str(), 11.0, Style::Bold)]).centered()])
+    .collect();
+
+  let eighth = CONTENT_WIDTH / 8.0;
+  rows.push(vec![
+    labeled(3.0 * eighth, "Name:", &header.name),
+    labeled(2.0 * eighth, "Platz:", &header.place),
+    labeled(3.0 * eighth, "Assistent/in:", &header.assistant),
+  ]);
+
+  rows.push(vec![Cell::new(
+    CONTENT_WIDTH,
+    vec![
+      Text::new("Herzustellendes Präparat:", 8.0, Style::Regular),
+      Text::new(header.preparation.as_str(), 11.0, Style::Bold),
+    ],
+  )
+  .centered()]);
+
+  let substances_title = if continued {
+    "eingesetzte Stoffe und Produkte (Fortsetzung)"
+  } else {
+    "eingesetzte Stoffe und Produkte"
+  };
+  rows.push(
+    [
+      substances_title,
+      "MG [g/mol]\nDichte",
+      "Sdp.\nSmp.\nFlp.\nZdt.\nEx.-Grenzen",
+      "GHS-Symbole",
+      "H/P-Sätze (Nummern)",
+      "MAK\nLD50\nWGK",
+      "für Ansatz benötigt",
+    ]
+    .iter()
+    .zip(SUBSTANCE_COLUMNS.iter())
+    .map(|(title, width)| Cell::new(*width, vec![Text::new(*title, 8.0, Style::Bold)]).centered())
+    .collect(),
+  );
+
+  rows
+}
+
+fn render_substance_page(
+  canvas: &Canvas,
+  document: &PDFCaBr2Document,
+  substances: &[PDFSubstanceData],
+  symbols: &HashMap<String, RgbImage>,
+  continued: bool,
+) {
+  let mut y = MARGIN;
+  for row in header_rows(&document.header, continued) {
+    y += canvas.row(MARGIN, y, &row, 0.0);
+  }
+
+  for substance in substances {
+    y += canvas.row(MARGIN, y, &substance_cells(substance, symbols), SUBSTANCE_HEIGHT);
+  }
+
+  // like the html template, the phrases and sources only list the substances of the page
+  let sources = sources(substances);
+  let sources_height = sources.height();
+  y += canvas.row(MARGIN, y, &[phrases_heading()], 0.0);
+
+  // the phrases fill the rest of the page, the font shrinks until they fit
+  let available = (PAGE_HEIGHT - MARGIN - sources_height - y).max(0.0);
+  let h_phrases = phrases(substances, |s| &s.h_phrases);
+  let p_phrases = phrases(substances, |s| &s.p_phrases);
+  let phrase_cells = |size: f64| {
+    vec![
+      Cell::new(CONTENT_WIDTH / 2.0, texts(&h_phrases, size, Style::Regular)),
+      Cell::new(CONTENT_WIDTH / 2.0, texts(&p_phrases, size, Style::Regular)),
+    ]
+  };
+  let cells = shrink_to_fit(8.0, available, phrase_cells);
+  y += canvas.row(MARGIN, y, &cells, available);
+
+  canvas.row(MARGIN, y, &[sources], 0.0);
+}
+
+fn render_second_page(canvas: &Canvas, document: &PDFCaBr2Document) {
+  let mut sections = vec![
+    (
+      "Gefahren für Mensch und Umwelt, die von den Ausgangsmaterialien bzw. dem(n) Produkt ausgehen, soweit sie nicht \
+       durch genannte Angaben abgedeckt sind (z.B. krebserregend, fruchtschädigend, hautresorptiv):",
+      &document.human_and_environment_danger,
+    ),
+    ("Schutzmaßnahmen und Verhaltensregeln:", &document.rules_of_conduct),
+    (
+      "Verhalten im Gefahrenfall, Erste-Hilfe-Maßnahmen (gegebenfalls Kopie der entsprechenden Literaturstelle \
+       beiheften):",
+      &document.in_case_of_danger,
+    ),
+    ("Entsorgung:", &document.disposal),
+  ];
+  if !document.incompatibilities.is_empty() {
+    sections.push((
+      "Unverträglichkeiten der Ausgangsmaterialien:",
+      &document.incompatibilities,
+    ));
+  }
+
+  // the sections share the space above the signatures
+  let available = PAGE_HEIGHT - 2.0 * MARGIN - SIGNATURE_HEIGHT;
+  let section_cells = |size: f64| -> Vec<Cell> {
+    sections
+      .iter()
+      .map(|(heading, items)| {
+        let mut texts = vec![Text::new(*heading, size, Style::Bold)];
+        texts.extend(
+          items
+            .iter()
+            .map(|i| Text::new(format!("• {}", i), size, Style::Regular)),
+        );
+        Cell::new(CONTENT_WIDTH, texts)
+      })
+      .collect()
+  };
+  let mut size = 9.0;
+  while size > MIN_FONT_SIZE && section_cells(size).iter().map(|c| c.height()).sum::<f64>() > available {
+    size -= 0.5;
+  }
+
+  let cells = section_cells(size);
+  let extra = ((available - cells.iter().map(|c| c.height()).sum::<f64>()) / cells.len() as f64).max(0.0);
+  let mut y = MARGIN;
+  for cell in cells {
+    let height = cell.height() + extra;
+    y += canvas.row(MARGIN, y, &[cell], height);
+  }
+
+  let signatures = [
+    "Hiermit verpflichte ich mich, den Versuch gemäß den in dieser Betriebsanweisung aufgeführten \
+     Sicherheitsvorschriften durchzuführen.",
+    "Präparat zur Synthese mit den auf der Vorderseite berechneten Chemikalienmengen freigegeben.",
+  ];
+  let width = CONTENT_WIDTH / 2.0;
+  let cells: Vec<Cell> = signatures
+    .iter()
+    .map(|s| Cell::new(width, vec![Text::new(*s, 8.0, Style::Regular)]))
+    .collect();
+  canvas.row(MARGIN, y, &cells, SIGNATURE_HEIGHT);
+
+  for i in 0..signatures.len() {
+    let x = MARGIN + i as f64 * width;
+    let line_y = y + SIGNATURE_HEIGHT - 8.0;
+    canvas.line(x + 5.0, line_y, x + width - 5.0, line_y);
+    canvas.text_centered("Unterschrift", 7.0, Style::Regular, x, width, line_y + 1.0);
+  }
+}
+
+fn render_page_number(canvas: &Canvas, number: usize, count: usize) {
+  let text = format!("Seite {} von {}", number, count);
+  canvas.text_centered(
+    &text,
+    8.0,
+    Style::Regular,
+    MARGIN,
+    CONTENT_WIDTH,
+    PAGE_HEIGHT - MARGIN + 4.0,
+  );
+}
+
+/* #endregion */
+
+/* #region content */
+
+fn sources(substances: &[PDFSubstanceData]) -> Cell<'static> {
+  Cell::new(
+    CONTENT_WIDTH,
+    vec![Text::new(
+      format!("Quellen: {}", providers(substances).join(", ")),
+      8.0,
+      Style::Regular,
+    )],
+  )
+}
+
+fn phrases_heading() -> Cell<'static> {
+  Cell::new(
+    CONTENT_WIDTH,
+    vec![Text::new(
+      "Wortlaut der wesentlichen oben genannten H- und P-Sätze:",
+      9.0,
+      Style::Bold,
+    )],
+  )
+}
+
+fn labeled(width: f64, label: &str, value: &str) -> Cell<'static> {
+  Cell::new(
+    width,
+    vec![
+      Text::new(label, 7.0, Style::Regular),
+      Text::new(value, 10.0, Style::Regular),
+    ],
+  )
+}
+
+fn style(modified: bool) -> Style {
+  if modified {
+    Style::Italic
+  } else {
+    Style::Regular
+  }
+}
+
+/// Writes a `-` if the value is missing, like the `value_or_dash` helper of the html templates.
+fn value_or_dash(data: &Data<Option<String>>, size: f64) -> Text {
+  Text::new(data.data.as_deref().unwrap_or("-"), size, style(data.modified))
+}
+
+fn phrase_numbers(phrases: &Data<Vec<(String, String)>>, size: f64) -> Text {
+  let numbers: Vec<&str> = phrases.data.iter().map(|p| p.0.as_str()).collect();
+  Text::new(numbers.join("-"), size, style(phrases.modified))
+}
+
+fn substance_cells<'a>(substance: &PDFSubstanceData, symbols: &'a HashMap<String, RgbImage>) -> Vec<Cell<'a>> {
+  let columns = SUBSTANCE_COLUMNS;
+
+  let mut name = vec![Text::new(
+    substance.name.data.as_str(),
+    9.0,
+    style(substance.name.modified),
+  )];
+  if let Some(formula) = &substance.molecular_formula.data {
+    name.push(Text::new(
+      formula.as_str(),
+      8.0,
+      style(substance.molecular_formula.modified),
+    ));
+  }
+
+  let mut ghs = Vec::new();
+  if let Some(signal_word) = &substance.signal_word.data {
+    ghs.push(Text::new(
+      signal_word.as_str(),
+      7.0,
+      style(substance.signal_word.modified),
+    ));
+  }
+  let pictograms = substance.symbols.data.iter().filter_map(|s| symbols.get(s)).collect();
+
+  let amount = match &substance.amount {
+    Some(amount) => format!("{} {}", amount.value, amount.unit),
+    None => String::new(),
+  };
+
+  vec![
+    Cell::new(columns[0], name),
+    Cell::new(
+      columns[1],
+      vec![
+        value_or_dash(&substance.molar_mass, 8.0),
+        value_or_dash(&substance.density, 8.0),
+      ],
+    )
+    .centered(),
+    Cell::new(
+      columns[2],
+      vec![
+        value_or_dash(&substance.boiling_point, 8.0),
+        value_or_dash(&substance.melting_point, 8.0),
+        value_or_dash(&substance.flash_point, 7.0),
+        value_or_dash(&substance.ignition_temperature, 7.0),
+        value_or_dash(&substance.explosion_limits, 7.0),
+      ],
+    )
+    .centered(),
+    Cell::new(columns[3], ghs).with_symbols(pictograms).centered(),
+    Cell::new(
+      columns[4],
+      vec![
+        phrase_numbers(&substance.h_phrases, 7.0),
+        phrase_numbers(&substance.p_phrases, 7.0),
+      ],
+    ),
+    Cell::new(
+      columns[5],
+      vec![
+        value_or_dash(&substance.mak, 8.0),
+        value_or_dash(&substance.lethal_dose, 8.0),
+        value_or_dash(&substance.water_hazard_class, 8.0),
+      ],
+    )
+    .centered(),
+    Cell::new(columns[6], vec![Text::new(amount, 8.0, Style::Regular)]).centered(),
+  ]
+}
+
+/// Returns the sorted and deduplicated phrases of all substances as `number: text`.
+fn phrases<F>(substances: &[PDFSubstanceData], select: F) -> BTreeSet<String>
+where
+  F: Fn(&PDFSubstanceData) -> &Data<Vec<(String, String)>>,
+{
+  substances
+    .iter()
+    .flat_map(|s| select(s).data.iter())
+    .map(|(number, text)| {
+      if text.is_empty() {
+        number.clone()
+      } else {
+        format!("{}: {}", number, text)
+      }
+    })
+    .collect()
+}
+
+fn texts(lines: &BTreeSet<String>, size: f64, style: Style) -> Vec<Text> {
+  lines.iter().map(|l| Text::new(l.as_str(), size, style)).collect()
+}
+
+/// Names of the used providers, empty lines and custom substances are skipped.
+fn providers(substances: &[PDFSubstanceData]) -> Vec<String> {
+  let providers: BTreeSet<&str> = substances.iter().map(|s| s.source.provider.as_str()).collect();
+  let provider_mapping = PROVIDER_MAPPING.lock().unwrap();
+
+  providers
+    .into_iter()
+    .filter(|p| !(p.is_empty() || *p == "custom"))
+    .map(|p| provider_mapping.get(p).cloned().unwrap_or_else(|| p.into()))
+    .collect()
+}
+
+/// Reduces the font size until the highest cell fits into `height`.
+fn shrink_to_fit<'a, F>(size: f64, height: f64, cells: F) -> Vec<Cell<'a>>
+where
+  F: Fn(f64) -> Vec<Cell<'a>>,
+{
+  let mut size = size;
+  loop {
+    let current = cells(size);
+    if size <= MIN_FONT_SIZE || current.iter().all(|c| c.height() <= height) {
+      return current;
+    }
+    size -= 0.5;
+  }
+}
+
+/* #endregion */
+
+/* #region ghs symbols */
+
+fn load_symbols(substances: &[PDFSubstanceData]) -> HashMap<String, RgbImage> {
+  let keys: BTreeSet<&String> = substances.iter().flat_map(|s| s.symbols.data.iter()).collect();
+
+  keys
+    .into_iter()
+    .filter_map(|key| load_symbol(key).map(|image| (key.clone(), image)))
+    .collect()
+}
+
+/// Missing pictograms are logged and left out instead of failing the whole document.
+fn load_symbol(key: &str) -> Option<RgbImage> {
+  if !key.chars().all(|c| c.is_ascii_alphanumeric()) {
+    log::warn!("invalid ghs symbol: '{}'", key);
+    return None;
+  }
+
+  let mut path = DATA_DIR.clone();
+  path.push("ghs_symbols");
+  path.push(format!("{}.png", key));
+
+  match image::open(&path) {
+    Ok(image) => Some(flatten(image)),
+    Err(err) => {
+      log::warn!("failed to load ghs symbol {:?}: {}", path, err);
+      None
+    }
+  }
+}
+
+/// printpdf can't embed the alpha channel, so the pictograms are put on a white background.
+fn flatten(image: DynamicImage) -> RgbImage {
+  let rgba = image.to_rgba8();
+
+  RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+    let pixel = rgba.get_pixel(x, y);
+    let alpha = pixel[3] as u32;
+    let blend = |c: u8| ((c as u32 * alpha + 255 * (255 - alpha)) / 255) as u8;
+    Rgb([blend(pixel[0]), blend(pixel[1]), blend(pixel[2])])
+  })
+}
+
+/* #endregion */
+
+/* #region drawing */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Style {
+  Regular,
+  Bold,
+  Italic,
+}
+
+struct Fonts {
+  regular: IndirectFontRef,
+  bold: IndirectFontRef,
+  italic: IndirectFontRef,
+}
+
+impl Fonts {
+  fn new(doc: &PdfDocumentReference) -> Result<Fonts> {
+    Ok(Fonts {
+      regular: doc.add_builtin_font(BuiltinFont::Helvetica)?,
+      bold: doc.add_builtin_font(BuiltinFont::HelveticaBold)?,
+      italic: doc.add_builtin_font(BuiltinFont::HelveticaOblique)?,
+    })
+  }
+
+  fn get(&self, style: Style) -> &IndirectFontRef {
+    match style {
+      Style::Regular => &self.regular,
+      Style::Bold => &self.bold,
+      Style::Italic => &self.italic,
+    }
+  }
+}
+
+#[derive(Debug)]
+struct Text {
+  text: String,
+  size: f64,
+  style: Style,
+}
+
+impl Text {
+  fn new<S: Into<String>>(text: S, size: f64, style: Style) -> Text {
+    Text {
+      text: text.into(),
+      size,
+      style,
+    }
+  }
+}
+
+/// A bordered table cell, the pictograms are placed above the text, three per line.
+struct Cell<'a> {
+  width: f64,
+  texts: Vec<Text>,
+  symbols: Vec<&'a RgbImage>,
+  centered: bool,
+}
+
+impl<'a> Cell<'a> {
+  fn new(width: f64, texts: Vec<Text>) -> Cell<'a> {
+    Cell {
+      width,
+      texts,
+      symbols: Vec::new(),
+      centered: false,
+    }
+  }
+
+  fn with_symbols(mut self, symbols: Vec<&'a RgbImage>) -> Cell<'a> {
+    self.symbols = symbols;
+    self
+  }
+
+  fn centered(mut self) -> Cell<'a> {
+    self.centered = true;
+    self
+  }
+
+  fn lines(&self) -> Vec<(String, f64, Style)> {
+    self
+      .texts
+      .iter()
+      .flat_map(|t| {
+        wrap(&t.text, t.size, t.style, self.width - 2.0 * PADDING)
+          .into_iter()
+          .map(move |line| (line, t.size, t.style))
+      })
+      .collect()
+  }
+
+  fn symbols_height(&self) -> f64 {
+    (self.symbols.len() as f64 / 3.0).ceil() * GHS_SIZE
+  }
+
+  fn height(&self) -> f64 {
+    let text_height: f64 = self.lines().iter().map(|(_, size, _)| line_height(*size)).sum();
+    self.symbols_height() + text_height + 2.0 * PADDING
+  }
+}
+
+struct Canvas<'a> {
+  layer: PdfLayerReference,
+  fonts: &'a Fonts,
+}
+
+impl<'a> Canvas<'a> {
+  fn new(layer: PdfLayerReference, fonts: &'a Fonts) -> Canvas<'a> {
+    layer.set_outline_thickness(0.5);
+    Canvas { layer, fonts }
+  }
+
+  /// `y` is the top of the line.
+  fn text(&self, text: &str, size: f64, style: Style, x: f64, y: f64) {
+    let baseline = y + size * MM_PER_PT * 0.95;
+    self
+      .layer
+      .use_text(text, size, Mm(x), Mm(PAGE_HEIGHT - baseline), self.fonts.get(style));
+  }
+
+  fn text_centered(&self, text: &str, size: f64, style: Style, x: f64, width: f64, y: f64) {
+    let offset = ((width - text_width(text, size, style)) / 2.0).max(0.0);
+    self.text(text, size, style, x + offset, y);
+  }
+
+  fn line(&self, x1: f64, y1: f64, x2: f64, y2: f64) {
+    self.layer.add_shape(Line {
+      points: vec![(point(x1, y1), false), (point(x2, y2), false)],
+      is_closed: false,
+      has_fill: false,
+      has_stroke: true,
+      is_clipping_path: false,
+    });
+  }
+
+  fn rect(&self, x: f64, y: f64, width: f64, height: f64) {
+    self.layer.add_shape(Line {
+      points: vec![
+        (point(x, y), false),
+        (point(x + width, y), false),
+        (point(x + width, y + height), false),
+        (point(x, y + height), false),
+      ],
+      is_closed: true,
+      has_fill: false,
+      has_stroke: true,
+      is_clipping_path: false,
+    });
+  }
+
+  fn symbol(&self, image: &RgbImage, x: f64, y: f64) {
+    let dpi = image.width() as f64 * 25.4 / GHS_SIZE;
+    Image::from_dynamic_image(&DynamicImage::ImageRgb8(image.clone())).add_to_layer(
+      self.layer.clone(),
+      Some(Mm(x)),
+      Some(Mm(PAGE_HEIGHT - y - GHS_SIZE)),
+      None,
+      None,
+      None,
+      Some(dpi),
+    );
+  }
+
+  /// Draws the cells next to each other and returns the height of the row.
+  fn row(&self, x: f64, y: f64, cells: &[Cell], min_height: f64) -> f64 {
+    let height = row_height(cells, min_height);
+
+    let mut x = x;
+    for cell in cells {
+      self.rect(x, y, cell.width, height);
+
+      let symbols_width = cell.symbols.len().min(3) as f64 * GHS_SIZE;
+      for (i, symbol) in cell.symbols.iter().enumerate() {
+        let offset = if cell.centered {
+          (cell.width - symbols_width) / 2.0
+        } else {
+          PADDING
+        };
+        self.symbol(
+          symbol,
+          x + offset + (i % 3) as f64 * GHS_SIZE,
+          y + PADDING + (i / 3) as f64 * GHS_SIZE,
+        );
+      }
+
+      let mut line_y = y + PADDING + cell.symbols_height();
+      for (line, size, style) in cell.lines() {
+        if cell.centered {
+          self.text_centered(&line, size, style, x, cell.width, line_y);
+        } else {
+          self.text(&line, size, style, x + PADDING, line_y);
+        }
+        line_y += line_height(size);
+      }
+
+      x += cell.width;
+    }
+
+    height
+  }
+}
+
+fn row_height(cells: &[Cell], min_height: f64) -> f64 {
+  cells.iter().map(|c| c.height()).fold(min_height, f64::max)
+}
+
+fn point(x: f64, y: f64) -> Point {
+  Point::new(Mm(x), Mm(PAGE_HEIGHT - y))
+}
+
+fn line_height(size: f64) -> f64 {
+  size * MM_PER_PT * LINE_SPACING
+}
+
+/* #endregion */
+
+/* #region text metrics */
+
+/// Advance widths of the printable ascii characters (32..=126) in 1/1000 em, taken from the Adobe font metrics.
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+  278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+  556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+  1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+  667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+  333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+  556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+  278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+  556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+  975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+  667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+  333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+  611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+fn char_width(c: char, widths: &[u16; 95]) -> u16 {
+  // accented characters have the width of their base character
+  let c = match c {
+    'ä' | 'à' | 'á' | 'â' => 'a',
+    'Ä' => 'A',
+    'ö' | 'ó' | 'ô' => 'o',
+    'Ö' => 'O',
+    'ü' | 'ú' | 'û' => 'u',
+    'Ü' => 'U',
+    'é' | 'è' | 'ê' => 'e',
+    'ß' => 'B',
+    '\u{a0}' => ' ',
+    c => c,
+  };
+
+  match c {
+    ' '..='~' => widths[c as usize - 32],
+    '°' => 400,
+    '²' | '³' => 333,
+    '•' => 350,
+    _ => 556,
+  }
+}
+
+fn text_width(text: &str, size: f64, style: Style) -> f64 {
+  let widths = match style {
+    Style::Bold => &HELVETICA_BOLD_WIDTHS,
+    Style::Regular | Style::Italic => &HELVETICA_WIDTHS,
+  };
+  let width: u32 = text.chars().map(|c| char_width(c, widths) as u32).sum();
+
+  width as f64 / 1000.0 * size * MM_PER_PT
+}
+
+/// Greedy word wrapping, words that are longer than a line are split between characters.
+fn wrap(text: &str, size: f64, style: Style, width: f64) -> Vec<String> {
+  let mut lines = Vec::new();
+
+  for paragraph in text.split('\n') {
+    let mut line = String::new();
+
+    for word in paragraph.split_whitespace() {
+      let candidate = if line.is_empty() {
+        word.to_string()
+      } else {
+        format!("{} {}", line, word)
+      };
+      if text_width(&candidate, size, style) <= width {
+        line = candidate;
+        continue;
+      }
+
+      if !line.is_empty() {
+        lines.push(mem::take(&mut line));
+      }
+      for c in word.chars() {
+        line.push(c);
+        if line.chars().count() > 1 && text_width(&line, size, style) > width {
+          line.pop();
+          lines.push(mem::take(&mut line));
+          line.push(c);
+        }
+      }
+    }
+
+    lines.push(line);
+  }
+
+  lines
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::types::Header;
+
+  #[test]
+  fn test_text_width() {
+    // "Hello" is 2278/1000 em wide in Helvetica
+    assert!((text_width("Hello", 10.0, Style::Regular) - 2.278 * 10.0 * MM_PER_PT).abs() < 1e-9);
+    assert!(text_width("Hello", 10.0, Style::Bold) > text_width("Hello", 10.0, Style::Regular));
+  }
+
+  #[test]
+  fn test_wrap() {
+    let width = text_width("Handschuhe tragen", 10.0, Style::Regular);
+
+    assert_eq!(
+      wrap("Schutzbrille und Handschuhe tragen", 10.0, Style::Regular, width),
+      vec!["Schutzbrille und", "Handschuhe tragen"]
+    );
+    assert_eq!(
+      wrap("eins\n\nzwei", 10.0, Style::Regular, width),
+      vec!["eins", "", "zwei"]
+    );
+
+    let lines = wrap("H225-H302-H314-H319-H335-H336", 8.0, Style::Regular, 15.0);
+    assert!(lines.len() > 1);
+    assert_eq!(lines.concat(), "H225-H302-H314-H319-H335-H336");
+    assert!(lines.iter().all(|l| text_width(l, 8.0, Style::Regular) <= 15.0));
+  }
+
+  fn document() -> CaBr2Document {
+    CaBr2Document {
+      header: Header {
+        document_title: "Betriebsanweisungen nach EG Nr. 1272/2008".into(),
+        organisation: "für chemische Laboratorien".into(),
+        lab_course: "Praktikum".into(),
+        name: "Max Mustermann".into(),
+        place: "42".into(),
+        assistant: "Erika Mustermann".into(),
+        preparation: "Acetylsalicylsäure".into(),
+      },
+      substance_data: Vec::new(),
+      human_and_environment_danger: vec!["keine".into()],
+      rules_of_conduct: vec!["Schutzbrille tragen".into(), "Handschuhe tragen".into()],
+      in_case_of_danger: Vec::new(),
+      disposal: vec!["Sammelbehälter für organische Abfälle".into()],
+    }
+  }
+
+  #[test]
+  fn test_paginate() {
+    let mut document: PDFCaBr2Document = document().into();
+    let symbols = HashMap::new();
+    // the five empty lines of a new document
+    let pages = paginate(&document, &symbols);
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].len(), 5);
+
+    document.substance_data = (0..40)
+      .map(|i| PDFSubstanceData {
+        name: Data::new(format!("Stoff {}", i)),
+        ..Default::default()
+      })
+      .collect();
+    let pages = paginate(&document, &symbols);
+
+    assert!(pages.len() > 1);
+    assert_eq!(pages.iter().map(|page| page.len()).sum::<usize>(), 40);
+    assert_eq!(pages[1][0].name.data, format!("Stoff {}", pages[0].len()));
+    // every page keeps the space for the phrases
+    for page in pages.iter() {
+      let rows: f64 = header_rows(&document.header, true)
+        .iter()
+        .map(|row| row_height(row, 0.0))
+        .sum::<f64>()
+        + page.len() as f64 * SUBSTANCE_HEIGHT;
+      assert!(MARGIN + rows <= PAGE_HEIGHT - MARGIN - MIN_PHRASES_HEIGHT);
+    }
+
+    let pdf = render_document(document).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+  }
+
+  #[test]
+  fn test_render_document() {
+    let pdf = render_document(document().into()).unwrap();
+
+    assert!(pdf.starts_with(b"%PDF"));
+  }
+}
@@ -0,0 +1,556 @@
+use std::{
+  collections::BTreeMap,
+  fs,
+  path::{Path, PathBuf},
+  time::SystemTime,
+};
+
+use handlebars::Handlebars;
+use serde::{Deserialize, Serialize};
+
+use cabr2_config::{DATA_DIR, PROJECT_DIRS};
+
+use crate::{
+  error::{LoadSaveError, Result},
+  types::{OptionKind, OptionSchema, Options},
+};
+
+/// The template set used if the user doesn't choose one.
+pub const DEFAULT_TEMPLATE: &str = "default";
+pub const TEMPLATE: &str = "template";
+const PAGE_SIZE: &str = "pageSize";
+const ORIENTATION: &str = "orientation";
+/// The same margin in mm for all sides.
+const MARGIN: &str = "margin";
+const MAX_MARGIN: i64 = 50;
+const MANIFEST: &str = "manifest.json";
+
+/// Used if the template has a substance page, but doesn't say how many substances fit on it with its own layout.
+const DEFAULT_SUBSTANCES_PER_PAGE: usize = 8;
+/// Used if neither the template nor the user sets margins.
+const DEFAULT_MARGINS: Margins = Margins {
+  top: 15,
+  right: 15,
+  bottom: 15,
+  left: 15,
+};
+
+/// The page layout of the PDF export, values that are not set are taken from the template.
+#[derive(Debug, Default)]
+pub struct PageOptions {
+  pub size: Option<PageSize>,
+  pub orientation: Option<Orientation>,
+  pub margins: Option<Margins>,
+}
+
+impl PageOptions {
+  pub fn from_options(options: &Options) -> Result<PageOptions> {
+    Ok(PageOptions {
+      size: options.get(PAGE_SIZE)?,
+      orientation: options.get(ORIENTATION)?,
+      margins: options.get_integer(MARGIN, 0, MAX_MARGIN)?.map(|margin| {
+        let margin = margin as u32;
+        Margins {
+          top: margin,
+          right: margin,
+          bottom: margin,
+          left: margin,
+        }
+      }),
+    })
+  }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum PageSize {
+  A4,
+  A5,
+  Letter,
+  Legal,
+}
+
+impl PageSize {
+  /// Width and height in portrait orientation in millimeters.
+  fn dimensions(self) -> (f64, f64) {
+    match self {
+      PageSize::A4 => (210.0, 297.0),
+      PageSize::A5 => (148.0, 210.0),
+      PageSize::Letter => (215.9, 279.4),
+      PageSize::Legal => (215.9, 355.6),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Orientation {
+  Portrait,
+  Landscape,
+}
+
+/// How many substances fit on the substance page with the layout of the template, either for both orientations or
+/// for each of them.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum SubstancesPerPage {
+  Fixed(usize),
+  Orientation { portrait: usize, landscape: usize },
+}
+
+/// The margins of the pages in millimeters.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Margins {
+  pub top: u32,
+  pub right: u32,
+  pub bottom: u32,
+  pub left: u32,
+}
+
+/// The `manifest.json` of a template set, the paths are relative to its folder.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+  pub name: String,
+  #[serde(default)]
+  pub description: String,
+  /// Every page is rendered on its own and the resulting PDFs are merged in this order.
+  pub pages: Vec<String>,
+  /// Available in the pages as `{{ stylesheet }}`.
+  #[serde(default)]
+  pub stylesheet: Option<String>,
+  /// The page with the substance table, it is rendered again with the substances that don't fit on it.
+  #[serde(default)]
+  pub substance_page: Option<String>,
+  #[serde(default)]
+  pub substances_per_page: Option<SubstancesPerPage>,
+  pub page_size: PageSize,
+  pub orientation: Orientation,
+  #[serde(default)]
+  pub margins: Option<Margins>,
+}
+
+/// The page layout of a PDF export.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageSetup {
+  pub size: PageSize,
+  pub orientation: Orientation,
+  pub margins: Margins,
+}
+
+impl PageSetup {
+  /// The height between the top and bottom margin in millimeters.
+  fn printable_height(&self) -> f64 {
+    let (width, height) = self.size.dimensions();
+    let height = match self.orientation {
+      Orientation::Portrait => height,
+      Orientation::Landscape => width,
+    };
+    height - (self.margins.top + self.margins.bottom) as f64
+  }
+}
+
+impl Manifest {
+  /// The number of substances of the template is scaled to the printable height of `setup`, so a smaller page or
+  /// larger margins move substances to the next page.
+  pub fn substances_per_page(&self, setup: &PageSetup) -> usize {
+    let mut layout = self.page_setup(&PageOptions::default());
+    let substances = match self.substances_per_page {
+      Some(SubstancesPerPage::Fixed(substances)) => substances,
+      Some(SubstancesPerPage::Orientation { portrait, landscape }) => {
+        // each value belongs to the layout of the template in that orientation
+        layout.orientation = setup.orientation;
+        match setup.orientation {
+          Orientation::Portrait => portrait,
+          Orientation::Landscape => landscape,
+        }
+      }
+      None => DEFAULT_SUBSTANCES_PER_PAGE,
+    };
+
+    let scaled = substances as f64 * setup.printable_height() / layout.printable_height();
+    // avoids that 7.999... substances are cut to 7
+    ((scaled + 1e-9).floor() as usize).max(1)
+  }
+
+  /// The layout of the template with the values the user has set for this export.
+  pub fn page_setup(&self, options: &PageOptions) -> PageSetup {
+    PageSetup {
+      size: options.size.unwrap_or(self.page_size),
+      orientation: options.orientation.unwrap_or(self.orientation),
+      margins: options.margins.or(self.margins).unwrap_or(DEFAULT_MARGINS),
+    }
+  }
+}
+
+/// A validated template set, its pages are registered as `<id>/<page>`.
+#[derive(Debug)]
+pub struct Template {
+  pub manifest: Manifest,
+  pub stylesheet: String,
+}
+
+impl Template {
+  pub fn page_names<'a>(&'a self, id: &'a str) -> impl Iterator<Item = String> + 'a {
+    self.manifest.pages.iter().map(move |page| page_name(id, page))
+  }
+}
+
+/// All template sets found in the `templates` folders of the program data and the user config, sets of the user
+/// replace sets with the same id.
+pub struct TemplateRegistry {
+  pub handlebars: Handlebars<'static>,
+  templates: BTreeMap<String, Template>,
+  /// Invalid sets are kept to report why they can't be used.
+  errors: BTreeMap<String, String>,
+}
+
+impl TemplateRegistry {
+  fn folders() -> [PathBuf; 2] {
+    [DATA_DIR.join("templates"), PROJECT_DIRS.config_dir().join("templates")]
+  }
+
+  pub fn load() -> TemplateRegistry {
+    TemplateRegistry::from_folders(&TemplateRegistry::folders())
+  }
+
+  /// The files of all template sets with their modification time, a different state means the sets changed.
+  pub fn state() -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut state = Vec::new();
+    for folder in TemplateRegistry::folders().iter() {
+      let sets = match fs::read_dir(folder) {
+        Ok(sets) => sets,
+        Err(_) => continue,
+      };
+      for set in sets.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+        let files = match fs::read_dir(&set) {
+          Ok(files) => files,
+          Err(_) => continue,
+        };
+        for file in files.filter_map(|entry| entry.ok()) {
+          let modified = file.metadata().and_then(|metadata| metadata.modified()).ok();
+          state.push((file.path(), modified));
+        }
+      }
+    }
+    state.sort();
+    state
+  }
+
+  pub fn from_folders(folders: &[PathBuf]) -> TemplateRegistry {
+    let mut registry = TemplateRegistry {
+      handlebars: Handlebars::new(),
+      templates: BTreeMap::new(),
+      errors: BTreeMap::new(),
+    };
+
+    for folder in folders {
+      let entries = match fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(err) => {
+          log::debug!("no templates in {:?}: {}", folder, err);
+          continue;
+        }
+      };
+
+      for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+        if !path.join(MANIFEST).is_file() {
+          continue;
+        }
+        let id = match path.file_name().and_then(|name| name.to_str()) {
+          Some(id) => id.to_string(),
+          None => continue,
+        };
+
+        if registry.templates.remove(&id).is_some() || registry.errors.remove(&id).is_some() {
+          log::debug!("template '{}' replaced by {:?}", id, path);
+        }
+        match registry.register(&id, &path) {
+          Ok(template) => {
+            log::trace!("loaded template '{}' from {:?}", id, path);
+            registry.templates.insert(id, template);
+          }
+          Err(err) => {
+            log::warn!("invalid template '{}' in {:?}: {}", id, path, err);
+            registry.errors.insert(id, err);
+          }
+        }
+      }
+    }
+
+    registry
+  }
+
+  /// Reads and compiles a template set, the error describes what is wrong with it.
+  fn register(&mut self, id: &str, path: &Path) -> std::result::Result<Template, String> {
+    let manifest =
+      fs::read_to_string(path.join(MANIFEST)).map_err(|err| format!("reading {} failed: {}", MANIFEST, err))?;
+    let manifest: Manifest = serde_json::from_str(&manifest).map_err(|err| format!("invalid {}: {}", MANIFEST, err))?;
+
+    if manifest.pages.is_empty() {
+      return Err("no pages listed".into());
+    }
+    if let Some(page) = &manifest.substance_page {
+      if !manifest.pages.contains(page) {
+        return Err(format!("substance page '{}' is not listed in pages", page));
+      }
+    }
+    if matches!(
+      manifest.substances_per_page,
+      Some(SubstancesPerPage::Fixed(0))
+        | Some(SubstancesPerPage::Orientation { portrait: 0, .. })
+        | Some(SubstancesPerPage::Orientation { landscape: 0, .. })
+    ) {
+      return Err("at least one substance has to fit on a page".into());
+    }
+
+    let stylesheet = match &manifest.stylesheet {
+      Some(stylesheet) => {
+        fs::read_to_string(path.join(stylesheet)).map_err(|err| format!("reading '{}' failed: {}", stylesheet, err))?
+      }
+      None => String::new(),
+    };
+
+    let mut registered: Vec<String> = Vec::new();
+    for page in &manifest.pages {
+      let name = page_name(id, page);
+      let result = match fs::read_to_string(path.join(page)) {
+        Ok(source) => self
+          .handlebars
+          .register_template_string(&name, source)
+          .map_err(|err| format!("page '{}' is invalid: {}", page, err)),
+        Err(err) => Err(format!("reading page '{}' failed: {}", page, err)),
+      };
+      if let Err(err) = result {
+        // a set is only usable as a whole
+        for name in registered {
+          self.handlebars.unregister_template(&name);
+        }
+        return Err(err);
+      }
+      registered.push(name);
+    }
+
+    Ok(Template { manifest, stylesheet })
+  }
+
+  pub fn get(&self, id: &str) -> Result<&Template> {
+    if let Some(template) = self.templates.get(id) {
+      return Ok(template);
+    }
+    match self.errors.get(id) {
+      Some(err) => Err(LoadSaveError::InvalidTemplate(id.into(), err.clone())),
+      None => Err(LoadSaveError::UnknownTemplate(id.into())),
+    }
+  }
+
+  /// The template and page layout options of the PDF export.
+  pub fn options(&self) -> Vec<OptionSchema> {
+    vec![
+      OptionSchema {
+        key: TEMPLATE,
+        kind: OptionKind::Choice {
+          choices: self.templates.keys().cloned().collect(),
+        },
+        default: Some(DEFAULT_TEMPLATE.into()),
+      },
+      OptionSchema {
+        key: PAGE_SIZE,
+        kind: OptionKind::Choice {
+          choices: vec!["A4".into(), "A5".into(), "Letter".into(), "Legal".into()],
+        },
+        default: None,
+      },
+      OptionSchema {
+        key: ORIENTATION,
+        kind: OptionKind::Choice {
+          choices: vec!["portrait".into(), "landscape".into()],
+        },
+        default: None,
+      },
+      OptionSchema {
+        key: MARGIN,
+        kind: OptionKind::Integer {
+          min: 0,
+          max: MAX_MARGIN,
+        },
+        default: None,
+      },
+    ]
+  }
+}
+
+fn page_name(id: &str, page: &str) -> String {
+  format!("{}/{}", id, page)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write_set(folder: &Path, id: &str, manifest: &str, pages: &[(&str, &str)]) {
+    let path = folder.join(id);
+    fs::create_dir_all(&path).unwrap();
+    fs::write(path.join(MANIFEST), manifest).unwrap();
+    for (name, content) in pages {
+      fs::write(path.join(name), content).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_default_template() {
+    let registry = TemplateRegistry::from_folders(&[PathBuf::from("../../templates")]);
+    let template = registry.get(DEFAULT_TEMPLATE).unwrap();
+
+    assert_eq!(template.manifest.pages, vec!["first.html", "second.html"]);
+    assert_eq!(template.manifest.page_size, PageSize::A4);
+    assert!(!template.stylesheet.is_empty());
+    assert!(registry.handlebars.has_template("default/first.html"));
+  }
+
+  #[test]
+  fn test_page_setup() {
+    let manifest: Manifest = serde_json::from_str(
+      r#"{
+        "name": "Praktikum",
+        "pages": ["first.html"],
+        "pageSize": "A4",
+        "orientation": "landscape",
+        "margins": { "top": 10, "right": 10, "bottom": 20, "left": 10 }
+      }"#,
+    )
+    .unwrap();
+
+    let setup = manifest.page_setup(&PageOptions::default());
+    assert_eq!(setup.size, PageSize::A4);
+    assert_eq!(setup.orientation, Orientation::Landscape);
+    assert_eq!(setup.margins.bottom, 20);
+
+    // values of the user replace the ones of the template
+    let options: Options = serde_json::from_str(r#"{ "pageSize": "Letter", "orientation": "portrait" }"#).unwrap();
+    let options = PageOptions::from_options(&options).unwrap();
+    let setup = manifest.page_setup(&options);
+    assert_eq!(setup.size, PageSize::Letter);
+    assert_eq!(setup.orientation, Orientation::Portrait);
+    assert_eq!(setup.margins.top, 10);
+
+    let manifest = Manifest {
+      margins: None,
+      ..manifest
+    };
+    assert_eq!(manifest.page_setup(&options).margins, DEFAULT_MARGINS);
+
+    let options: Options = serde_json::from_str(r#"{ "margin": 20 }"#).unwrap();
+    assert_eq!(PageOptions::from_options(&options).unwrap().margins.unwrap().left, 20);
+    for margin in ["60", "-5", "12.5", "\"wide\""].iter() {
+      let options: Options = serde_json::from_str(&format!(r#"{{ "margin": {} }}"#, margin)).unwrap();
+      assert!(matches!(
+        PageOptions::from_options(&options),
+        Err(LoadSaveError::InvalidOption(key, _)) if key == MARGIN
+      ));
+    }
+  }
+
+  #[test]
+  fn test_substances_per_page() {
+    let manifest: Manifest = serde_json::from_str(
+      r#"{ "name": "Praktikum", "pages": ["first.html"], "pageSize": "A4", "orientation": "portrait" }"#,
+    )
+    .unwrap();
+    let substances = |manifest: &Manifest, options: &str| {
+      let options = PageOptions::from_options(&serde_json::from_str(options).unwrap()).unwrap();
+      manifest.substances_per_page(&manifest.page_setup(&options))
+    };
+
+    assert_eq!(substances(&manifest, "{}"), DEFAULT_SUBSTANCES_PER_PAGE);
+    // 180 instead of 267 mm
+    assert_eq!(substances(&manifest, r#"{ "orientation": "landscape" }"#), 5);
+    assert_eq!(substances(&manifest, r#"{ "pageSize": "Legal" }"#), 9);
+    assert_eq!(substances(&manifest, r#"{ "margin": 50 }"#), 5);
+
+    let manifest = Manifest {
+      substances_per_page: Some(SubstancesPerPage::Orientation {
+        portrait: 8,
+        landscape: 6,
+      }),
+      ..manifest
+    };
+    assert_eq!(substances(&manifest, "{}"), 8);
+    assert_eq!(substances(&manifest, r#"{ "orientation": "landscape" }"#), 6);
+    assert_eq!(
+      substances(&manifest, r#"{ "orientation": "landscape", "pageSize": "A5" }"#),
+      3
+    );
+  }
+
+  #[test]
+  fn test_invalid_templates() {
+    let folder = std::env::temp_dir().join(format!("cabr2_templates_{}", std::process::id()));
+    let user_folder = folder.join("user");
+    write_set(
+      &folder,
+      "course",
+      r#"{ "name": "Praktikum", "pages": ["first.html"], "pageSize": "A5", "orientation": "landscape" }"#,
+      &[("first.html", "<p>{{ document.header.labCourse }}</p>")],
+    );
+    write_set(
+      &folder,
+      "missing",
+      r#"{ "name": "Missing", "pages": ["first.html", "second.html"], "pageSize": "A4", "orientation": "portrait" }"#,
+      &[("first.html", "<p></p>")],
+    );
+    write_set(
+      &folder,
+      "broken",
+      r#"{ "name": "Broken", "pages": ["first.html"], "pageSize": "A4", "orientation": "portrait" }"#,
+      &[("first.html", "<p>{{#if}}</p>")],
+    );
+    write_set(
+      &folder,
+      "incomplete",
+      r#"{ "name": "Incomplete", "pages": ["first.html"] }"#,
+      &[("first.html", "<p></p>")],
+    );
+    write_set(
+      &folder,
+      "table",
+      r#"{
+        "name": "Table",
+        "pages": ["first.html"],
+        "substancePage": "table.html",
+        "pageSize": "A4",
+        "orientation": "portrait"
+      }"#,
+      &[("first.html", "<p></p>")],
+    );
+    // fixes the broken set of the program data
+    write_set(
+      &user_folder,
+      "broken",
+      r#"{ "name": "Fixed", "pages": ["first.html"], "pageSize": "A4", "orientation": "portrait" }"#,
+      &[("first.html", "<p></p>")],
+    );
+
+    let registry = TemplateRegistry::from_folders(&[folder.clone(), user_folder]);
+    fs::remove_dir_all(&folder).unwrap();
+
+    assert_eq!(
+      registry.get("course").unwrap().manifest.orientation,
+      Orientation::Landscape
+    );
+    assert!(matches!(
+      registry.get("missing"),
+      Err(LoadSaveError::InvalidTemplate(id, err)) if id == "missing" && err.contains("second.html")
+    ));
+    assert!(!registry.handlebars.has_template("missing/first.html"));
+    assert!(matches!(
+      registry.get("incomplete"),
+      Err(LoadSaveError::InvalidTemplate(_, err)) if err.contains("pageSize")
+    ));
+    assert!(matches!(
+      registry.get("table"),
+      Err(LoadSaveError::InvalidTemplate(_, err)) if err.contains("table.html")
+    ));
+    assert_eq!(registry.get("broken").unwrap().manifest.name, "Fixed");
+    assert!(matches!(registry.get("other"), Err(LoadSaveError::UnknownTemplate(_))));
+  }
+}
@@ -4,39 +4,44 @@ use chrono::TimeZone;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 
-use cabr2_types::{Source, SubstanceData};
+use cabr2_types::{Quantity, Source, SubstanceData};
 
 use crate::types::{CaBr2Document, Header};
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PDFCaBr2Document {
-  header: Header,
-  substance_data: Vec<PDFSubstanceData>,
-  human_and_environment_danger: Vec<String>,
-  rules_of_conduct: Vec<String>,
-  in_case_of_danger: Vec<String>,
-  disposal: Vec<String>,
+  pub header: Header,
+  pub substance_data: Vec<PDFSubstanceData>,
+  pub human_and_environment_danger: Vec<String>,
+  pub rules_of_conduct: Vec<String>,
+  pub in_case_of_danger: Vec<String>,
+  pub disposal: Vec<String>,
+  pub incompatibilities: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PDFSubstanceData {
-  name: Data<String>,
-  alternative_names: Vec<String>,
-  cas: Data<Option<String>>,
-  molecular_formula: Data<Option<String>>,
-  molar_mass: Data<Option<String>>,
-  melting_point: Data<Option<String>>,
-  boiling_point: Data<Option<String>>,
-  water_hazard_class: Data<Option<String>>,
+  pub name: Data<String>,
+  pub alternative_names: Vec<String>,
+  pub cas: Data<Option<String>>,
+  pub molecular_formula: Data<Option<String>>,
+  pub molar_mass: Data<Option<String>>,
+  pub melting_point: Data<Option<String>>,
+  pub boiling_point: Data<Option<String>>,
+  pub density: Data<Option<String>>,
+  pub flash_point: Data<Option<String>>,
+  pub ignition_temperature: Data<Option<String>>,
+  pub explosion_limits: Data<Option<String>>,
+  pub water_hazard_class: Data<Option<String>>,
   pub h_phrases: Data<Vec<(String, String)>>,
   pub p_phrases: Data<Vec<(String, String)>>,
-  signal_word: Data<Option<String>>,
-  symbols: Data<Vec<String>>,
-  lethal_dose: Data<Option<String>>,
-  mak: Data<Option<String>>,
-  amount: Option<Amount>,
+  pub signal_word: Data<Option<String>>,
+  pub symbols: Data<Vec<String>>,
+  pub lethal_dose: Data<Option<String>>,
+  pub mak: Data<Option<String>>,
+  pub amount: Option<Amount>,
   pub source: Source,
 }
 
@@ -44,7 +49,7 @@ pub struct PDFSubstanceData {
 #[serde(rename_all = "camelCase")]
 pub struct Data<T> {
   pub data: T,
-  modified: bool,
+  pub modified: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -62,6 +67,10 @@ impl PDFSubstanceData {
       && self.molar_mass.data.is_none()
       && self.melting_point.data.is_none()
       && self.boiling_point.data.is_none()
+      && self.density.data.is_none()
+      && self.flash_point.data.is_none()
+      && self.ignition_temperature.data.is_none()
+      && self.explosion_limits.data.is_none()
       && self.water_hazard_class.data.is_none()
       && self.h_phrases.data.is_empty()
       && self.p_phrases.data.is_empty()
@@ -87,6 +96,10 @@ impl std::default::Default for PDFSubstanceData {
       molar_mass: Data::new(Some("".into())),
       melting_point: Data::new(Some("".into())),
       boiling_point: Data::new(Some("".into())),
+      density: Data::new(Some("".into())),
+      flash_point: Data::new(Some("".into())),
+      ignition_temperature: Data::new(Some("".into())),
+      explosion_limits: Data::new(Some("".into())),
       water_hazard_class: Data::new(Some("".into())),
       h_phrases: Data::default(),
       p_phrases: Data::default(),
@@ -130,6 +143,7 @@ impl std::convert::From<CaBr2Document> for PDFCaBr2Document {
       rules_of_conduct: doc.rules_of_conduct,
       in_case_of_danger: doc.in_case_of_danger,
       disposal: doc.disposal,
+      incompatibilities: Vec::new(),
     }
   }
 }
@@ -144,6 +158,10 @@ impl std::convert::From<SubstanceData> for PDFSubstanceData {
       molar_mass: data.molar_mass.into(),
       melting_point: data.melting_point.into(),
       boiling_point: data.boiling_point.into(),
+      density: data.density.into(),
+      flash_point: data.flash_point.into(),
+      ignition_temperature: data.ignition_temperature.into(),
+      explosion_limits: data.explosion_limits.into(),
       water_hazard_class: data.water_hazard_class.into(),
       h_phrases: data.h_phrases.into(),
       p_phrases: data.p_phrases.into(),
@@ -183,3 +201,14 @@ impl<T> std::convert::From<cabr2_types::Data<T>> for Data<T> {
     }
   }
 }
+
+/// The templates show quantities the way they were entered or fetched.
+impl std::convert::From<cabr2_types::Data<Option<Quantity>>> for Data<Option<String>> {
+  fn from(data: cabr2_types::Data<Option<Quantity>>) -> Self {
+    let data: Data<Option<Quantity>> = data.into();
+    Data {
+      data: data.data.map(|quantity| quantity.to_string()),
+      modified: data.modified,
+    }
+  }
+}
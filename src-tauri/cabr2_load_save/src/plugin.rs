@@ -2,17 +2,27 @@ use std::path::PathBuf;
 
 use tauri::{async_runtime, plugin::Plugin, Invoke, Params, Window};
 
-use cabr2_types::ProviderMapping;
+use cabr2_types::{
+  ghs::{Classification, MixtureComponent, ValidationWarning},
+  Amount, ProviderMapping, Quantity, SubstanceData, Unit,
+};
 use tokio::fs;
 
 use crate::{
   error::{LoadSaveError, Result},
   handler::{self, init_handlers},
-  types::{CaBr2Document, DocumentTypes},
+  incompatibilities::Incompatibility,
+  sections::Sections,
+  types::{CaBr2Document, DocumentTypes, Options},
 };
 
 #[tauri::command]
-pub async fn save_document(file_type: String, filename: PathBuf, document: CaBr2Document) -> Result<()> {
+pub async fn save_document(
+  file_type: String,
+  filename: PathBuf,
+  document: CaBr2Document,
+  options: Option<Options>,
+) -> Result<()> {
   log::debug!("type: {}", file_type);
   log::debug!("filename: {:?}", filename);
   log::trace!("doc: {:#?}", document);
@@ -39,7 +49,7 @@ pub async fn save_document(file_type: String, filename: PathBuf, document: CaBr2
     }
   }
 
-  let res = handler::save_document(file_type.as_str(), document).await?;
+  let res = handler::save_document(file_type.as_str(), document, options.unwrap_or_default()).await?;
 
   let res = fs::write(&filename, res).await;
 
@@ -51,7 +61,7 @@ pub async fn save_document(file_type: String, filename: PathBuf, document: CaBr2
 }
 
 #[tauri::command]
-pub async fn load_document(filename: PathBuf) -> Result<CaBr2Document> {
+pub async fn load_document(filename: PathBuf, options: Option<Options>) -> Result<CaBr2Document> {
   log::debug!("filename: {:?}", filename);
 
   let contents = fs::read(&filename).await;
@@ -63,6 +73,7 @@ pub async fn load_document(filename: PathBuf) -> Result<CaBr2Document> {
   handler::load_document(
     filename.extension().unwrap_or_default().to_str().unwrap_or_default(),
     contents?,
+    options.unwrap_or_default(),
   )
   .await
 }
@@ -72,6 +83,41 @@ pub async fn get_available_document_types() -> Result<DocumentTypes> {
   handler::get_available_document_types().await
 }
 
+#[tauri::command]
+pub async fn generate_sections(substance_data: Vec<SubstanceData>) -> Result<Sections> {
+  handler::generate_sections(substance_data).await
+}
+
+#[tauri::command]
+pub async fn check_incompatibilities(substance_data: Vec<SubstanceData>) -> Result<Vec<Incompatibility>> {
+  handler::check_incompatibilities(substance_data).await
+}
+
+#[tauri::command]
+pub async fn validate_substance_data(substance_data: SubstanceData) -> Result<Vec<ValidationWarning>> {
+  handler::validate_substance_data(substance_data).await
+}
+
+#[tauri::command]
+pub async fn classify_solution(substance_data: SubstanceData) -> Result<SubstanceData> {
+  handler::classify_solution(substance_data).await
+}
+
+#[tauri::command]
+pub async fn classify_mixture(components: Vec<MixtureComponent>) -> Result<Classification> {
+  handler::classify_mixture(components).await
+}
+
+#[tauri::command]
+pub async fn convert_amount(
+  amount: Amount,
+  unit: Unit,
+  molar_mass: Option<Quantity>,
+  density: Option<Quantity>,
+) -> Result<Amount> {
+  handler::convert_amount(amount, unit, molar_mass, density).await
+}
+
 pub struct LoadSave<M: Params> {
   invoke_handler: Box<dyn Fn(Invoke<M>) + Send + Sync>,
 }
@@ -84,6 +130,12 @@ impl<M: Params> LoadSave<M> {
         save_document,
         load_document,
         get_available_document_types,
+        generate_sections,
+        check_incompatibilities,
+        validate_substance_data,
+        classify_solution,
+        classify_mixture,
+        convert_amount,
       ]),
     }
   }
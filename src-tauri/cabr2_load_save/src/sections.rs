@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use cabr2_types::{Language, SubstanceData};
+
+/// Proposed contents of the free text sections of a document.
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sections {
+  pub human_and_environment_danger: Vec<String>,
+  pub rules_of_conduct: Vec<String>,
+  pub in_case_of_danger: Vec<String>,
+  pub disposal: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+enum Section {
+  Danger,
+  Conduct,
+  Emergency,
+  Disposal,
+}
+
+enum Trigger {
+  Always,
+  /// One of the H or P statements, parts of combined statements count on their own.
+  /// Variants with letters match their base statement, e.g. `H360FD` matches `H360`.
+  Phrase(&'static [&'static str]),
+  Symbol(&'static str),
+  /// A substance containing one of the elements.
+  Element(&'static [&'static str]),
+  /// An organic compound containing a halogen.
+  Halogenated,
+  /// An organic compound without halogens.
+  NonHalogenated,
+}
+
+const HALOGENS: [&str; 4] = ["F", "Cl", "Br", "I"];
+
+/// The rules in the order the sentences are proposed, every sentence is proposed once.
+const RULES: &[(Trigger, Section, &str, &str)] = &[
+  /* #region human and environment danger */
+  (
+    Trigger::Symbol("ghs01"),
+    Section::Danger,
+    "Explosionsgefährlich, Schlag, Reibung und Erwärmung vermeiden.",
+    "Explosive, avoid shock, friction and heat.",
+  ),
+  (
+    Trigger::Symbol("ghs02"),
+    Section::Danger,
+    "Entzündbar, Dämpfe können mit Luft explosionsfähige Gemische bilden.",
+    "Flammable, vapours may form explosive mixtures with air.",
+  ),
+  (
+    Trigger::Symbol("ghs03"),
+    Section::Danger,
+    "Brandfördernd, Kontakt mit brennbaren Stoffen kann einen Brand verursachen.",
+    "Oxidising, contact with combustible material may cause fire.",
+  ),
+  (
+    Trigger::Symbol("ghs04"),
+    Section::Danger,
+    "Enthält Gas unter Druck, kann bei Erwärmung explodieren.",
+    "Contains gas under pressure, may explode if heated.",
+  ),
+  (
+    Trigger::Symbol("ghs05"),
+    Section::Danger,
+    "Ätzend, verursacht schwere Verätzungen der Haut und schwere Augenschäden.",
+    "Corrosive, causes severe skin burns and eye damage.",
+  ),
+  (
+    Trigger::Symbol("ghs06"),
+    Section::Danger,
+    "Giftig bei Verschlucken, Hautkontakt oder Einatmen.",
+    "Toxic if swallowed, in contact with skin or if inhaled.",
+  ),
+  (
+    Trigger::Symbol("ghs07"),
+    Section::Danger,
+    "Gesundheitsschädlich, reizt Haut, Augen oder Atemwege.",
+    "Harmful, irritates skin, eyes or respiratory tract.",
+  ),
+  (
+    Trigger::Symbol("ghs08"),
+    Section::Danger,
+    "Kann Organe schädigen, krebserzeugend, erbgutverändernd oder fortpflanzungsgefährdend sein.",
+    "May damage organs, cause cancer, genetic defects or damage fertility.",
+  ),
+  (
+    Trigger::Symbol("ghs09"),
+    Section::Danger,
+    "Giftig für Wasserorganismen.",
+    "Toxic to aquatic life.",
+  ),
+  /* #endregion */
+
+  /* #region rules of conduct */
+  (
+    Trigger::Always,
+    Section::Conduct,
+    "Schutzbrille und Laborkittel tragen.",
+    "Wear safety goggles and a lab coat.",
+  ),
+  (
+    Trigger::Phrase(&["P280"]),
+    Section::Conduct,
+    "Schutzhandschuhe tragen.",
+    "Wear protective gloves.",
+  ),
+  (
+    Trigger::Phrase(&["P260", "P261", "P271", "H330", "H331", "H332", "H335", "H336"]),
+    Section::Conduct,
+    "Nur im Abzug arbeiten, Dämpfe und Stäube nicht einatmen.",
+    "Only work in the fume hood, do not breathe vapours or dust.",
+  ),
+  (
+    Trigger::Phrase(&["P210"]),
+    Section::Conduct,
+    "Von Zündquellen fernhalten, nicht rauchen.",
+    "Keep away from sources of ignition, no smoking.",
+  ),
+  (
+    Trigger::Phrase(&["P233"]),
+    Section::Conduct,
+    "Behälter dicht verschlossen halten.",
+    "Keep the container tightly closed.",
+  ),
+  (
+    Trigger::Phrase(&["P201", "P202", "H340", "H350", "H360", "H361"]),
+    Section::Conduct,
+    "Vor Gebrauch besondere Anweisungen einholen, jede Exposition vermeiden.",
+    "Obtain special instructions before use, avoid any exposure.",
+  ),
+  (
+    Trigger::Phrase(&["P264", "P270"]),
+    Section::Conduct,
+    "Am Arbeitsplatz nicht essen und trinken, nach der Arbeit Hände waschen.",
+    "Do not eat or drink at the workplace, wash hands after work.",
+  ),
+  /* #endregion */
+
+  /* #region in case of danger */
+  (
+    Trigger::Always,
+    Section::Emergency,
+    "Bei Unfällen sofort die Assistenz informieren.",
+    "Inform the assistant immediately in case of an accident.",
+  ),
+  (
+    Trigger::Phrase(&["P305"]),
+    Section::Emergency,
+    "Nach Augenkontakt: Einige Minuten lang behutsam mit Wasser spülen und einen Augenarzt aufsuchen.",
+    "After eye contact: Rinse cautiously with water for several minutes and consult an ophthalmologist.",
+  ),
+  (
+    Trigger::Phrase(&["P302", "P303"]),
+    Section::Emergency,
+    "Nach Hautkontakt: Kontaminierte Kleidung ausziehen und die Haut mit viel Wasser spülen.",
+    "After skin contact: Take off contaminated clothing and rinse the skin with plenty of water.",
+  ),
+  (
+    Trigger::Phrase(&["P304"]),
+    Section::Emergency,
+    "Nach Einatmen: An die frische Luft bringen.",
+    "After inhalation: Remove the person to fresh air.",
+  ),
+  (
+    Trigger::Phrase(&["P301"]),
+    Section::Emergency,
+    "Nach Verschlucken: Mund ausspülen und einen Arzt anrufen.",
+    "After swallowing: Rinse mouth and call a doctor.",
+  ),
+  (
+    Trigger::Symbol("ghs02"),
+    Section::Emergency,
+    "Im Brandfall mit Sand, Kohlendioxid oder Pulver löschen.",
+    "In case of fire use sand, carbon dioxide or powder to extinguish.",
+  ),
+  /* #endregion */
+
+  /* #region disposal */
+  (
+    Trigger::Halogenated,
+    Section::Disposal,
+    "In den Behälter für halogenhaltige organische Abfälle geben.",
+    "Collect in the container for halogenated organic waste.",
+  ),
+  (
+    Trigger::NonHalogenated,
+    Section::Disposal,
+    "In den Behälter für halogenfreie organische Abfälle geben.",
+    "Collect in the container for non-halogenated organic waste.",
+  ),
+  (
+    Trigger::Element(&["Ag", "Ba", "Cd", "Co", "Cr", "Cu", "Hg", "Ni", "Pb", "Tl"]),
+    Section::Disposal,
+    "In den Behälter für schwermetallhaltige Abfälle geben.",
+    "Collect in the container for waste containing heavy metals.",
+  ),
+  (
+    Trigger::Phrase(&["H290"]),
+    Section::Disposal,
+    "Säuren und Laugen vor der Entsorgung neutralisieren.",
+    "Neutralise acids and bases before disposal.",
+  ),
+  (
+    Trigger::Phrase(&["H400", "H410", "H411", "H412", "H413"]),
+    Section::Disposal,
+    "Nicht in den Ausguss geben, Freisetzung in die Umwelt vermeiden.",
+    "Do not pour down the drain, avoid release to the environment.",
+  ),
+  /* #endregion */
+];
+
+/// `C₂H₅Cl` -> `["C", "H", "Cl"]`, numbers and charges are ignored.
+fn elements(formula: &str) -> Vec<&str> {
+  formula
+    .char_indices()
+    .filter(|(_, c)| c.is_ascii_uppercase())
+    .map(|(start, c)| {
+      let end = formula[start + c.len_utf8()..]
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_lowercase())
+        .map_or(formula.len(), |(i, _)| start + c.len_utf8() + i);
+      &formula[start..end]
+    })
+    .collect()
+}
+
+/// Whether a phrase is the code or one of its variants with letters, e.g. `H360Fd` for `H360`.
+fn matches_phrase(phrase: &str, code: &str) -> bool {
+  matches!(phrase.strip_prefix(code), Some(rest) if rest.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// Carbon compounds with hydrogen are organic, unless all carbon atoms are part of carbonates or cyanides
+/// (`NaHCO3`, `HCN`, `NH4SCN`). Carbides, CO and CO₂ contain no hydrogen.
+fn is_organic(formula: &str, elements: &[&str]) -> bool {
+  let carbon = elements.iter().filter(|e| **e == "C").count();
+  let inorganic = formula.matches("CO3").count() + formula.matches("CN").count();
+  elements.contains(&"H") && carbon > inorganic
+}
+
+/// Proposes sentences for the free text sections from the statements, pictograms and formulas of all substances.
+pub fn generate_sections(substances: &[SubstanceData], language: Language) -> Sections {
+  let phrases: HashSet<&str> = substances
+    .iter()
+    .flat_map(|s| s.h_phrases.current().iter().chain(s.p_phrases.current().iter()))
+    .flat_map(|(code, _)| code.split('+').map(str::trim))
+    .collect();
+  let symbols: HashSet<&str> = substances
+    .iter()
+    .flat_map(|s| s.symbols.current().iter().map(String::as_str))
+    .collect();
+  let formulas: Vec<(&str, Vec<&str>)> = substances
+    .iter()
+    .filter_map(|s| s.molecular_formula.current().as_deref())
+    .map(|formula| (formula, elements(formula)))
+    .collect();
+  let organic = |halogenated: bool| {
+    formulas
+      .iter()
+      .filter(|(formula, elements)| is_organic(formula, elements))
+      .any(|(_, elements)| HALOGENS.iter().any(|h| elements.contains(h)) == halogenated)
+  };
+
+  let mut sections = Sections::default();
+  for (trigger, section, german, english) in RULES.iter() {
+    let applies = match trigger {
+      Trigger::Always => true,
+      Trigger::Phrase(codes) => codes
+        .iter()
+        .any(|code| phrases.iter().any(|phrase| matches_phrase(phrase, code))),
+      Trigger::Symbol(symbol) => symbols.contains(symbol),
+      Trigger::Element(elements) => formulas.iter().any(|(_, f)| elements.iter().any(|e| f.contains(e))),
+      Trigger::Halogenated => organic(true),
+      Trigger::NonHalogenated => organic(false),
+    };
+    if !applies {
+      continue;
+    }
+
+    let sentences = match section {
+      Section::Danger => &mut sections.human_and_environment_danger,
+      Section::Conduct => &mut sections.rules_of_conduct,
+      Section::Emergency => &mut sections.in_case_of_danger,
+      Section::Disposal => &mut sections.disposal,
+    };
+    let sentence = match language {
+      Language::German => german,
+      Language::English => english,
+    };
+    sentences.push(sentence.to_string());
+  }
+
+  sections
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use cabr2_types::Data;
+
+  fn substance(formula: &str, h_phrases: &[&str], p_phrases: &[&str], symbols: &[&str]) -> SubstanceData {
+    let phrases = |codes: &[&str]| codes.iter().map(|c| (c.to_string(), String::new())).collect();
+    SubstanceData {
+      name: Data::new(formula.into()),
+      molecular_formula: Data::new(Some(formula.into())),
+      h_phrases: Data::new(phrases(h_phrases)),
+      p_phrases: Data::new(phrases(p_phrases)),
+      symbols: Data::new(symbols.iter().map(|s| s.to_string()).collect()),
+      ..SubstanceData::default()
+    }
+  }
+
+  #[test]
+  fn test_elements() {
+    assert_eq!(elements("CH2Cl2"), vec!["C", "H", "Cl"]);
+    assert_eq!(elements("C₂H₅OH"), vec!["C", "H", "O", "H"]);
+    assert_eq!(elements("[Cu(NH3)4]SO4"), vec!["Cu", "N", "H", "S", "O"]);
+  }
+
+  #[test]
+  fn test_organic() {
+    for formula in ["C2H6O", "CH3CN", "CH2Cl2", "CH4N2O"].iter() {
+      assert!(is_organic(formula, &elements(formula)), "{}", formula);
+    }
+    for formula in ["NaHCO3", "(NH4)2CO3", "HCN", "NH4SCN", "CO2", "CaC2", "CuSO4"].iter() {
+      assert!(!is_organic(formula, &elements(formula)), "{}", formula);
+    }
+  }
+
+  #[test]
+  fn test_reproductive_toxicity() {
+    let exposure = "Obtain special instructions before use, avoid any exposure.";
+    for phrase in ["H360FD", "H360Df", "H361d", "H350i"].iter() {
+      let sections = generate_sections(&[substance("PbO", &[phrase], &[], &[])], Language::English);
+      assert!(sections.rules_of_conduct.iter().any(|s| s == exposure), "{}", phrase);
+    }
+
+    let sections = generate_sections(&[substance("PbO", &["H36"], &["P2010"], &[])], Language::English);
+    assert!(!sections.rules_of_conduct.iter().any(|s| s == exposure));
+  }
+
+  #[test]
+  fn test_generate_sections() {
+    let dichloromethane = substance("CH2Cl2", &["H351"], &["P261", "P280"], &["ghs08"]);
+    let sections = generate_sections(&[dichloromethane], Language::English);
+
+    assert_eq!(
+      sections.human_and_environment_danger,
+      vec!["May damage organs, cause cancer, genetic defects or damage fertility."]
+    );
+    assert_eq!(
+      sections.rules_of_conduct,
+      vec![
+        "Wear safety goggles and a lab coat.",
+        "Wear protective gloves.",
+        "Only work in the fume hood, do not breathe vapours or dust.",
+      ]
+    );
+    assert_eq!(
+      sections.disposal,
+      vec!["Collect in the container for halogenated organic waste."]
+    );
+  }
+
+  #[test]
+  fn test_combined_phrases() {
+    let ethanol = substance(
+      "C2H6O",
+      &["H225", "H319"],
+      &["P210", "P305+P351+P338"],
+      &["ghs02", "ghs07"],
+    );
+    let copper_sulfate = substance("CuSO4", &["H302", "H410"], &["P273"], &["ghs07", "ghs09"]);
+    let sections = generate_sections(&[ethanol, copper_sulfate], Language::German);
+
+    assert_eq!(sections.in_case_of_danger.len(), 3);
+    assert!(sections.in_case_of_danger[1].starts_with("Nach Augenkontakt"));
+    assert_eq!(
+      sections.disposal,
+      vec![
+        "In den Behälter für halogenfreie organische Abfälle geben.",
+        "In den Behälter für schwermetallhaltige Abfälle geben.",
+        "Nicht in den Ausguss geben, Freisetzung in die Umwelt vermeiden.",
+      ]
+    );
+  }
+}
@@ -1,15 +1,95 @@
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-use cabr2_types::SubstanceData;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-use super::error::Result;
+use cabr2_types::{Language, SubstanceData};
+
+use super::error::{LoadSaveError, Result};
 
 pub trait Loader {
-  fn load_document(&self, contents: Vec<u8>) -> Result<CaBr2Document>;
+  fn load_document(&self, contents: Vec<u8>, options: &Options) -> Result<CaBr2Document>;
+
+  /// The options the user can set when loading, they are offered with the file type.
+  fn load_options(&self) -> Vec<OptionSchema> {
+    Vec::new()
+  }
 }
 
 pub trait Saver {
-  fn save_document(&self, document: CaBr2Document) -> Result<Vec<u8>>;
+  fn save_document(&self, document: CaBr2Document, options: &Options) -> Result<Vec<u8>>;
+
+  /// The options the user can set when saving, they are offered with the file type.
+  fn save_options(&self) -> Vec<OptionSchema> {
+    Vec::new()
+  }
+}
+
+/// Format specific choices of the user, the keys are the ones advertised by the `OptionSchema`s of the format.
+///
+/// The settings of the config are filled in by the handler, the loaders and savers run blocking and can't read it.
+#[derive(Debug, Default, Deserialize)]
+pub struct Options {
+  #[serde(flatten)]
+  values: BTreeMap<String, Value>,
+  /// The language of the ui.
+  #[serde(skip)]
+  pub language: Language,
+  /// Whether the PDF lists dangerous combinations of the substances if the user didn't choose.
+  #[serde(skip)]
+  pub print_incompatibilities: bool,
+}
+
+impl Options {
+  /// Missing and `null` values are `None`, the loader or saver chooses the default then.
+  pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>> {
+    match self.values.get(key) {
+      None | Some(Value::Null) => Ok(None),
+      Some(value) => match serde_json::from_value(value.clone()) {
+        Ok(value) => Ok(Some(value)),
+        Err(err) => Err(LoadSaveError::InvalidOption(key.into(), err.to_string())),
+      },
+    }
+  }
+
+  /// Like `get`, values outside of the bounds advertised by `OptionKind::Integer` are rejected.
+  pub fn get_integer(&self, key: &str, min: i64, max: i64) -> Result<Option<i64>> {
+    match self.get::<i64>(key)? {
+      Some(value) if value < min || value > max => Err(LoadSaveError::InvalidOption(
+        key.into(),
+        format!("{} is not between {} and {}", value, min, max),
+      )),
+      value => Ok(value),
+    }
+  }
+}
+
+impl From<BTreeMap<String, Value>> for Options {
+  fn from(values: BTreeMap<String, Value>) -> Self {
+    Options {
+      values,
+      ..Options::default()
+    }
+  }
+}
+
+/// Describes an option, so the frontend can offer it.
+#[derive(Debug, Serialize)]
+pub struct OptionSchema {
+  pub key: &'static str,
+  #[serde(flatten)]
+  pub kind: OptionKind,
+  /// `None` if the default depends on other things, e.g. the template or the config.
+  pub default: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OptionKind {
+  Boolean,
+  Integer { min: i64, max: i64 },
+  Text,
+  Choice { choices: Vec<String> },
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -45,4 +125,5 @@ pub struct DocumentTypes {
 pub struct DialogFilter {
   pub name: String,
   pub extensions: Vec<String>,
+  pub options: Vec<OptionSchema>,
 }
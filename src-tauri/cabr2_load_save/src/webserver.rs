@@ -0,0 +1,173 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+use serde_json::Value;
+use warp::{hyper::body::Bytes, reply, Filter, Rejection, Reply};
+
+use cabr2_types::{ghs::MixtureComponent, webserver::into_reply, Amount, Quantity, SubstanceData, Unit};
+
+use crate::{
+  handler,
+  types::{CaBr2Document, Options},
+};
+
+/// Uploaded documents larger than this are rejected.
+const MAX_DOCUMENT_SIZE: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SaveDocumentArguments {
+  file_type: String,
+  document: CaBr2Document,
+  #[serde(default)]
+  options: Options,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateSectionsArguments {
+  substance_data: Vec<SubstanceData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckIncompatibilitiesArguments {
+  substance_data: Vec<SubstanceData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidateArguments {
+  substance_data: SubstanceData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClassifyMixtureArguments {
+  components: Vec<MixtureComponent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertArguments {
+  amount: Amount,
+  unit: Unit,
+  molar_mass: Option<Quantity>,
+  density: Option<Quantity>,
+}
+
+/// Returns all routes of this crate, they are available under `/load_save/<command>`.
+///
+/// In contrast to the tauri plugin no files are touched on the server:
+/// `save_document` returns the contents of the generated file and
+/// `load_document` expects the contents of the file as request body and its options as query parameters.
+pub fn get_routes() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+  let save_document = warp::path!("save_document")
+    .and(warp::post())
+    .and(warp::body::content_length_limit(MAX_DOCUMENT_SIZE))
+    .and(warp::body::json())
+    .and_then(|args: SaveDocumentArguments| async move {
+      log::debug!("type: {}", args.file_type);
+      log::trace!("doc: {:#?}", args.document);
+
+      let reply: Box<dyn Reply> = match handler::save_document(&args.file_type, args.document, args.options).await {
+        Ok(contents) => Box::new(reply::with_header(
+          contents,
+          "Content-Disposition",
+          format!("attachment; filename=\"document.{}\"", args.file_type),
+        )),
+        Err(err) => Box::new(into_reply::<(), _>(Err(err))),
+      };
+
+      Ok::<_, Rejection>(reply)
+    });
+
+  let load_document = warp::path!("load_document" / String)
+    .and(warp::post())
+    .and(warp::body::content_length_limit(MAX_DOCUMENT_SIZE))
+    .and(warp::body::bytes())
+    .and(warp::query::<HashMap<String, String>>())
+    .and_then(
+      |file_type: String, contents: Bytes, query: HashMap<String, String>| async move {
+        log::debug!("type: {}", file_type);
+
+        Ok::<_, Rejection>(into_reply(
+          handler::load_document(&file_type, contents.to_vec(), query_options(query)).await,
+        ))
+      },
+    );
+
+  let get_available_document_types = warp::path!("get_available_document_types")
+    .and(warp::get())
+    .and_then(|| async { Ok::<_, Rejection>(into_reply(handler::get_available_document_types().await)) });
+
+  let generate_sections = warp::path!("generate_sections")
+    .and(warp::post())
+    .and(warp::body::content_length_limit(MAX_DOCUMENT_SIZE))
+    .and(warp::body::json())
+    .and_then(|args: GenerateSectionsArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::generate_sections(args.substance_data).await))
+    });
+
+  let check_incompatibilities = warp::path!("check_incompatibilities")
+    .and(warp::post())
+    .and(warp::body::content_length_limit(MAX_DOCUMENT_SIZE))
+    .and(warp::body::json())
+    .and_then(|args: CheckIncompatibilitiesArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::check_incompatibilities(args.substance_data).await))
+    });
+
+  let validate_substance_data = warp::path!("validate_substance_data")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: ValidateArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::validate_substance_data(args.substance_data).await))
+    });
+
+  let classify_solution = warp::path!("classify_solution")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: ValidateArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::classify_solution(args.substance_data).await))
+    });
+
+  let classify_mixture = warp::path!("classify_mixture")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: ClassifyMixtureArguments| async move {
+      Ok::<_, Rejection>(into_reply(handler::classify_mixture(args.components).await))
+    });
+
+  let convert_amount = warp::path!("convert_amount")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: ConvertArguments| async move {
+      Ok::<_, Rejection>(into_reply(
+        handler::convert_amount(args.amount, args.unit, args.molar_mass, args.density).await,
+      ))
+    });
+
+  warp::path("load_save").and(
+    save_document
+      .or(load_document)
+      .or(get_available_document_types)
+      .or(generate_sections)
+      .or(check_incompatibilities)
+      .or(validate_substance_data)
+      .or(classify_solution)
+      .or(classify_mixture)
+      .or(convert_amount),
+  )
+}
+
+/// Query parameters are strings, values that are valid JSON (e.g. `true` or `10`) are passed as such.
+fn query_options(query: HashMap<String, String>) -> Options {
+  query
+    .into_iter()
+    .map(|(key, value)| {
+      let value = serde_json::from_str(&value).unwrap_or(Value::String(value));
+      (key, value)
+    })
+    .collect::<BTreeMap<String, Value>>()
+    .into()
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::new_without_default)]
 
+#[cfg(feature = "tauri_plugin")]
 pub mod plugin;
 
 use std::fs;
@@ -10,7 +11,7 @@ use log::LevelFilter;
 use cabr2_config::{read_config, BackendConfig, TMP_DIR};
 use cabr2_types::logging::LogLevel;
 
-async fn setup_logger() -> Result<(), fern::InitError> {
+pub async fn setup_logger() -> Result<(), fern::InitError> {
   let mut log_file = TMP_DIR.clone();
   log_file.push(format!("cabr2_{}.log", chrono::Local::now().format("%F_%H.%M.%S")));
 
@@ -39,7 +40,7 @@ async fn setup_logger() -> Result<(), fern::InitError> {
     .level_for("cabr2_load_save", convert_level(config.cabr2.clone()))
     .level_for("cabr2_logger", convert_level(config.cabr2.clone()))
     .level_for("cabr2_search", convert_level(config.cabr2))
-    .level_for("ureq", convert_level(config.ureq))
+    .level_for("reqwest", convert_level(config.reqwest))
     .level_for("rustls", convert_level(config.rustls))
     .chain(std::io::stdout())
     .chain(fs::OpenOptions::new().create(true).write(true).open(&log_file)?)
@@ -6,7 +6,10 @@ use std::{
 
 use structopt::StructOpt;
 
-use cabr2_search::gestis::{self, types::GestisResponse};
+use cabr2_search::{
+  gestis::{self, types::GestisResponse},
+  types::{Language, Provider},
+};
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "gestis_helper")]
@@ -14,14 +17,30 @@ struct Arguments {
   /// Extract xmls from gestis id
   #[structopt(short, long)]
   pub extract: Option<String>,
+
+  /// Request the english article
+  #[structopt(long)]
+  pub english: bool,
 }
 
 fn main() {
   let args = Arguments::from_args();
 
   if args.extract.is_some() {
-    let gestis = gestis::Gestis::new(ureq::agent());
-    let (res, _) = gestis.get_article(args.extract.unwrap()).unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .unwrap();
+    let gestis = gestis::Gestis::new(reqwest::Client::new());
+    let language = if args.english {
+      Language::English
+    } else {
+      Language::German
+    };
+    let raw = runtime
+      .block_on(gestis.get_raw_substance_data(language, args.extract.unwrap()))
+      .unwrap();
+    let res: GestisResponse = serde_json::from_value(raw.data).unwrap();
 
     extract_xmls(&res).unwrap();
   }
@@ -0,0 +1,387 @@
+use std::{future::Future, path::PathBuf, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::fs;
+
+use crate::{
+  error::Result,
+  types::{Language, ParsedSubstanceData, Provider, RawSubstanceData, SearchArguments, SearchResponse, SearchType},
+};
+use cabr2_config::{Cache, PROJECT_DIRS};
+
+const SUGGESTIONS: &str = "suggestions";
+const SEARCH: &str = "search";
+const SUBSTANCES: &str = "substances";
+
+/// Longer ttls are clamped, `chrono::Duration` panics for values out of its range.
+const MAX_TTL_HOURS: u64 = 10 * 365 * 24;
+/// Entries per kind, if there are more the least recently written ones are deleted.
+const MAX_ENTRIES: usize = 1000;
+
+/// Wraps the provider into a `CachedProvider` if caching is enabled in the config.
+pub fn wrap<P>(config: &Cache, identifier: &str, provider: P) -> Arc<dyn Provider + Send + Sync>
+where
+  P: Provider + Send + Sync + 'static,
+{
+  if !config.enabled {
+    return Arc::new(provider);
+  }
+
+  let mut folder = PROJECT_DIRS.cache_dir().to_path_buf();
+  folder.push(identifier);
+  log::trace!("cache path for '{}': {:?}", identifier, folder);
+
+  Arc::new(CachedProvider {
+    provider: Box::new(provider),
+    cache: DiskCache::new(folder, Duration::hours(config.ttl.min(MAX_TTL_HOURS) as i64)),
+  })
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CacheEntry<T> {
+  key: String,
+  fetched: DateTime<Utc>,
+  data: T,
+}
+
+/// Stores json files in a folder, one file per entry.
+struct DiskCache {
+  folder: PathBuf,
+  ttl: Duration,
+  max_entries: usize,
+}
+
+impl DiskCache {
+  fn new(folder: PathBuf, ttl: Duration) -> Self {
+    DiskCache {
+      folder,
+      ttl,
+      max_entries: MAX_ENTRIES,
+    }
+  }
+
+  /// Returns the cached data if it is still valid.
+  /// If it is outdated the data is requested again and only used if the request fails.
+  async fn get_or_fetch<T, F>(&self, kind: &str, key: String, fetch: F) -> Result<T>
+  where
+    T: Serialize + DeserializeOwned,
+    F: Future<Output = Result<T>>,
+  {
+    let cached = self.read::<T>(kind, &key).await;
+
+    if let Some(entry) = &cached {
+      let fresh = match entry.fetched.checked_add_signed(self.ttl) {
+        Some(expiry) => expiry > Utc::now(),
+        // the entry expires after the last representable date
+        None => true,
+      };
+      if fresh {
+        log::trace!("[{}] cache hit: {}", kind, key);
+        return Ok(cached.unwrap().data);
+      }
+    }
+
+    match fetch.await {
+      Ok(data) => {
+        self.write(kind, &key, &data).await;
+        Ok(data)
+      }
+      Err(err) => match cached {
+        Some(entry) => {
+          log::warn!(
+            "[{}] request failed, using entry from {}: {:?}",
+            kind,
+            entry.fetched,
+            err
+          );
+          Ok(entry.data)
+        }
+        None => Err(err),
+      },
+    }
+  }
+
+  async fn read<T: DeserializeOwned>(&self, kind: &str, key: &str) -> Option<CacheEntry<T>> {
+    let path = self.path(kind, key);
+    let contents = fs::read(&path).await.ok()?;
+
+    match serde_json::from_slice::<CacheEntry<T>>(&contents) {
+      // the filename is only a hash, so the key must be compared too
+      Ok(entry) if entry.key == key => Some(entry),
+      Ok(_) => None,
+      Err(err) => {
+        log::warn!("invalid cache entry {:?}: {:?}", path, err);
+        None
+      }
+    }
+  }
+
+  async fn write<T: Serialize>(&self, kind: &str, key: &str, data: &T) {
+    let path = self.path(kind, key);
+    let entry = CacheEntry {
+      key: key.to_string(),
+      fetched: Utc::now(),
+      data,
+    };
+
+    let result = match serde_json::to_vec(&entry) {
+      Ok(contents) => match fs::create_dir_all(path.parent().unwrap()).await {
+        Ok(_) => fs::write(&path, contents).await,
+        Err(err) => Err(err),
+      },
+      Err(err) => Err(err.into()),
+    };
+
+    if let Err(err) = result {
+      log::warn!("writing cache entry {:?} failed: {:?}", path, err);
+      return;
+    }
+
+    if let Err(err) = self.prune(kind).await {
+      log::warn!("[{}] pruning cache failed: {:?}", kind, err);
+    }
+  }
+
+  /// Deletes the least recently written entries of `kind` until at most `max_entries` are left.
+  ///
+  /// Outdated entries are kept as long as there is space, they are still used if the source is not reachable.
+  async fn prune(&self, kind: &str) -> std::io::Result<()> {
+    let mut folder = self.folder.clone();
+    folder.push(kind);
+
+    let mut entries = Vec::new();
+    let mut dir = fs::read_dir(&folder).await?;
+    while let Some(entry) = dir.next_entry().await? {
+      entries.push((entry.metadata().await?.modified()?, entry.path()));
+    }
+    if entries.len() <= self.max_entries {
+      return Ok(());
+    }
+
+    entries.sort();
+    let count = entries.len() - self.max_entries;
+    for (_, path) in entries.into_iter().take(count) {
+      log::trace!("[{}] removing cache entry {:?}", kind, path);
+      fs::remove_file(path).await?;
+    }
+    Ok(())
+  }
+
+  fn path(&self, kind: &str, key: &str) -> PathBuf {
+    let mut path = self.folder.clone();
+    path.push(kind);
+    path.push(format!("{:016x}", fnv1a(key.as_bytes())));
+    path.with_extension("json")
+  }
+}
+
+/// Stable hash for the filenames, the hasher of the standard library may change between releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+    (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+  })
+}
+
+/// Caches the results of another provider on disk, so they are available without network access.
+pub struct CachedProvider {
+  provider: Box<dyn Provider + Send + Sync>,
+  cache: DiskCache,
+}
+
+#[async_trait]
+impl Provider for CachedProvider {
+  fn get_name(&self) -> String {
+    self.provider.get_name()
+  }
+
+  async fn get_quick_search_suggestions(
+    &self,
+    language: Language,
+    search_type: SearchType,
+    pattern: String,
+  ) -> Result<Vec<String>> {
+    let key = serde_json::to_string(&(language, &search_type, &pattern))?;
+    self
+      .cache
+      .get_or_fetch(
+        SUGGESTIONS,
+        key,
+        self
+          .provider
+          .get_quick_search_suggestions(language, search_type, pattern),
+      )
+      .await
+  }
+
+  async fn get_search_results(&self, language: Language, arguments: SearchArguments) -> Result<Vec<SearchResponse>> {
+    let key = serde_json::to_string(&(language, &arguments))?;
+    self
+      .cache
+      .get_or_fetch(SEARCH, key, self.provider.get_search_results(language, arguments))
+      .await
+  }
+
+  async fn get_raw_substance_data(&self, language: Language, identifier: String) -> Result<RawSubstanceData> {
+    let key = serde_json::to_string(&(language, &identifier))?;
+    self
+      .cache
+      .get_or_fetch(
+        SUBSTANCES,
+        key,
+        self.provider.get_raw_substance_data(language, identifier),
+      )
+      .await
+  }
+
+  fn parse_substance_data(&self, language: Language, raw: RawSubstanceData) -> Result<ParsedSubstanceData> {
+    self.provider.parse_substance_data(language, raw)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+  use crate::error::SearchError;
+
+  use super::*;
+
+  struct Counting {
+    calls: Arc<AtomicUsize>,
+    offline: Arc<AtomicBool>,
+  }
+
+  #[async_trait]
+  impl Provider for Counting {
+    fn get_name(&self) -> String {
+      "Counting".into()
+    }
+
+    async fn get_quick_search_suggestions(&self, _: Language, _: SearchType, pattern: String) -> Result<Vec<String>> {
+      if self.offline.load(Ordering::SeqCst) {
+        return Err(SearchError::Logged);
+      }
+      self.calls.fetch_add(1, Ordering::SeqCst);
+      Ok(vec![pattern])
+    }
+
+    async fn get_search_results(&self, _: Language, _: SearchArguments) -> Result<Vec<SearchResponse>> {
+      Ok(vec![])
+    }
+
+    async fn get_raw_substance_data(&self, _: Language, identifier: String) -> Result<RawSubstanceData> {
+      if self.offline.load(Ordering::SeqCst) {
+        return Err(SearchError::Logged);
+      }
+      Ok(RawSubstanceData {
+        url: identifier,
+        fetched: "2021-07-01T12:00:00Z".parse().unwrap(),
+        data: serde_json::Value::Null,
+      })
+    }
+
+    fn parse_substance_data(&self, _: Language, _: RawSubstanceData) -> Result<ParsedSubstanceData> {
+      Err(SearchError::Empty)
+    }
+  }
+
+  fn cached_provider(name: &str, ttl: Duration) -> (CachedProvider, Arc<AtomicUsize>, Arc<AtomicBool>) {
+    cached_provider_with_capacity(name, ttl, MAX_ENTRIES)
+  }
+
+  fn cached_provider_with_capacity(
+    name: &str,
+    ttl: Duration,
+    max_entries: usize,
+  ) -> (CachedProvider, Arc<AtomicUsize>, Arc<AtomicBool>) {
+    let mut folder = std::env::temp_dir();
+    folder.push(format!("cabr2_cache_test_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&folder);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let offline = Arc::new(AtomicBool::new(false));
+    let provider = CachedProvider {
+      provider: Box::new(Counting {
+        calls: calls.clone(),
+        offline: offline.clone(),
+      }),
+      cache: DiskCache {
+        max_entries,
+        ..DiskCache::new(folder, ttl)
+      },
+    };
+
+    (provider, calls, offline)
+  }
+
+  #[tokio::test]
+  async fn test_fresh_entries_are_not_fetched_again() {
+    let (provider, calls, _) = cached_provider("fresh", Duration::hours(1));
+
+    for _ in 0..2 {
+      let suggestions = provider
+        .get_quick_search_suggestions(Language::German, SearchType::ChemicalName, "ethanol".into())
+        .await
+        .unwrap();
+      assert_eq!(suggestions, vec!["ethanol"]);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_stale_entries_are_used_when_offline() {
+    let (provider, calls, offline) = cached_provider("stale", Duration::zero());
+
+    let raw = provider
+      .get_raw_substance_data(Language::German, "010420".into())
+      .await
+      .unwrap();
+    offline.store(true, Ordering::SeqCst);
+    let cached = provider
+      .get_raw_substance_data(Language::German, "010420".into())
+      .await
+      .unwrap();
+    assert_eq!(cached.url, raw.url);
+    // the time of the original request is kept
+    assert_eq!(cached.fetched, raw.fetched);
+
+    assert!(provider
+      .get_quick_search_suggestions(Language::German, SearchType::ChemicalName, "ethanol".into())
+      .await
+      .is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+  }
+
+  #[tokio::test]
+  async fn test_entries_never_expire_with_maximum_ttl() {
+    let (provider, calls, _) = cached_provider("maximum_ttl", Duration::milliseconds(i64::MAX));
+
+    for _ in 0..2 {
+      provider
+        .get_quick_search_suggestions(Language::German, SearchType::ChemicalName, "ethanol".into())
+        .await
+        .unwrap();
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn test_oldest_entries_are_removed() {
+    let (provider, calls, _) = cached_provider_with_capacity("prune", Duration::hours(1), 2);
+
+    for pattern in ["ethanol", "methanol", "propanol", "ethanol"].iter() {
+      provider
+        .get_quick_search_suggestions(Language::German, SearchType::ChemicalName, pattern.to_string())
+        .await
+        .unwrap();
+    }
+    // ethanol was removed when propanol was written
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    let mut folder = provider.cache.folder.clone();
+    folder.push(SUGGESTIONS);
+    assert_eq!(std::fs::read_dir(folder).unwrap().count(), 2);
+  }
+}
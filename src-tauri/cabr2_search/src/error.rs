@@ -10,6 +10,9 @@ pub enum SearchError {
   #[error("unknown provider: {0}")]
   UnknownProvider(String),
 
+  #[error("unknown substance: {0}")]
+  UnknownSubstance(String),
+
   #[error("rate limited by server")]
   RateLimit,
 
@@ -31,6 +34,9 @@ pub enum SearchError {
 
   #[error("parsing json failed")]
   JsonError(#[from] serde_json::Error),
+  #[cfg(any(feature = "gestis", feature = "pubchem"))]
+  #[error("request failed: {0}")]
+  ReqwestError(#[from] reqwest::Error),
   #[cfg(feature = "gestis")]
   #[error("parsing xml failed")]
   XmlError(roxmltree::Error),
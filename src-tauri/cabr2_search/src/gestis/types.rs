@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use cabr2_types::Toxicity;
+
+use crate::types::ParseReport;
+
 #[derive(Debug)]
 pub struct ParsedData {
   pub cas: Option<String>,
@@ -7,13 +11,20 @@ pub struct ParsedData {
   pub molar_mass: Option<String>,
   pub melting_point: Option<String>,
   pub boiling_point: Option<String>,
+  pub density: Option<String>,
+  pub flash_point: Option<String>,
+  pub ignition_temperature: Option<String>,
+  pub explosion_limits: Option<String>,
   pub water_hazard_class: Option<String>,
   pub h_phrases: Option<Vec<(String, String)>>,
   pub p_phrases: Option<Vec<(String, String)>>,
   pub signal_word: Option<String>,
   pub symbols: Option<Vec<String>>,
   pub lethal_dose: Option<String>,
+  pub toxicity: Option<Vec<Toxicity>>,
   pub mak: Option<String>,
+  pub reactions: Option<Vec<String>>,
+  pub report: ParseReport,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
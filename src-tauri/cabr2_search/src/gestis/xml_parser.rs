@@ -3,19 +3,29 @@ use std::collections::BTreeMap;
 use lazy_static::lazy_static;
 use roxmltree::{Document, Node, NodeId};
 
+use cabr2_types::{ghs, Quantity, Toxicity};
+
 use super::types::{GestisResponse, ParsedData};
-use crate::error::{Result, SearchError};
+use crate::{
+  error::{Result, SearchError},
+  types::{FieldStatus, Language, ParseReport},
+};
 
 lazy_static! {
   pub static ref CHAPTER_MAPPING: BTreeMap<&'static str, (&'static str, &'static str)> = [
     ("boiling_point", ("0600", "0603")),
     ("cas_number", ("0100", "0100")),
+    ("density", ("0600", "0604")),
+    ("explosion_limits", ("0600", "0609")),
+    ("flash_point", ("0600", "0607")),
     ("h_p_signal_symbols", ("1100", "1303")),
-    ("lethal_dose", ("0500", "0501")),
+    ("ignition_temperature", ("0600", "0608")),
     ("mak1", ("1100", "1201")),
     ("mak2", ("1100", "1203")),
     ("melting_point", ("0600", "0602")),
     ("molecular_formula", ("0400", "0400")),
+    ("reactions", ("0600", "0619")),
+    ("toxicity", ("0500", "0501")),
     ("water_hazard_class", ("1100", "1106")),
   ]
   .iter()
@@ -23,161 +33,266 @@ lazy_static! {
   .collect();
 }
 
-pub fn parse_response(json: &GestisResponse) -> Result<ParsedData> {
+/// Labels of the values in the xml, they differ between the german and the english articles.
+struct Labels {
+  melting_point: &'static str,
+  boiling_point: &'static str,
+  flash_point: &'static [&'static str],
+  ignition_temperature: &'static [&'static str],
+  lower_explosion_limit: &'static [&'static str],
+  upper_explosion_limit: &'static [&'static str],
+  h_phrases: &'static str,
+  p_phrases: &'static str,
+  signal_word: &'static str,
+  value: &'static str,
+  not_water_hazardous: &'static str,
+  workplace_limits: &'static [&'static str],
+  workplace_limit_separator: &'static str,
+  sources: &'static [&'static str],
+  reactions: &'static [&'static str],
+  /// Parts of the text of substances that decompose instead of melting or boiling, and the value used then.
+  decomposition: (&'static [&'static str], &'static str),
+}
+
+const GERMAN_LABELS: Labels = Labels {
+  melting_point: "Schmelzpunkt:",
+  boiling_point: "Siedepunkt:",
+  flash_point: &["Flammpunkt:"],
+  ignition_temperature: &["Zündtemperatur:"],
+  lower_explosion_limit: &["Untere Explosionsgrenze (UEG):", "Untere Explosionsgrenze:"],
+  upper_explosion_limit: &["Obere Explosionsgrenze (OEG):", "Obere Explosionsgrenze:"],
+  h_phrases: "Gefahrenhinweise - H-Sätze:",
+  p_phrases: "Sicherheitshinweise - P-Sätze:",
+  signal_word: "Signalwort:",
+  value: "Wert:",
+  not_water_hazardous: "Nicht wassergefährdender Stoff",
+  workplace_limits: &["Arbeitsplatzgrenzwert:", "MAK-Wert:"],
+  workplace_limit_separator: "bzw.",
+  sources: &["Quelle:", "Bemerkung:", "Bemerkungen:"],
+  reactions: &["Gefährliche chemische Reaktionen:"],
+  decomposition: (&["zersetzt sich", "zersetzung"], "Zersetzung"),
+};
+
+const ENGLISH_LABELS: Labels = Labels {
+  melting_point: "Melting point:",
+  boiling_point: "Boiling point:",
+  flash_point: &["Flash point:"],
+  ignition_temperature: &["Ignition temperature:"],
+  lower_explosion_limit: &["Lower explosion limit (LEL):", "Lower explosion limit:"],
+  upper_explosion_limit: &["Upper explosion limit (UEL):", "Upper explosion limit:"],
+  h_phrases: "Hazard statements - H-phrases:",
+  p_phrases: "Precautionary statements - P-phrases:",
+  signal_word: "Signal word:",
+  value: "Value:",
+  not_water_hazardous: "Non-hazardous to water",
+  workplace_limits: &[
+    "Occupational exposure limit value:",
+    "Workplace exposure limit:",
+    "MAK value:",
+  ],
+  workplace_limit_separator: " or ",
+  sources: &["Source:", "Remark:", "Remarks:"],
+  reactions: &["Hazardous chemical reactions:"],
+  decomposition: (&["decomposes", "decomposition"], "decomposes"),
+};
+
+impl Labels {
+  fn of(language: Language) -> &'static Labels {
+    match language {
+      Language::German => &GERMAN_LABELS,
+      Language::English => &ENGLISH_LABELS,
+    }
+  }
+}
+
+/// The capitalization of the labels is not consistent between the articles.
+#[inline]
+fn is_label(text: Option<&str>, label: &str) -> bool {
+  matches!(text, Some(t) if t.trim().to_lowercase() == label.to_lowercase())
+}
+
+/// What an extractor found, errors are only returned if the article can't be read as expected.
+#[derive(Debug, PartialEq)]
+enum Extracted<T> {
+  Found(T),
+  /// The source lists several values, this is the first one.
+  Multiple(T),
+  /// The source states that there is no value, e.g. for substances that are not hazardous to water.
+  Empty,
+  /// The article has no such subchapter or field.
+  Missing,
+}
+
+impl<T> Extracted<T> {
+  fn status(&self) -> FieldStatus {
+    match self {
+      Extracted::Found(_) => FieldStatus::Found,
+      Extracted::Multiple(_) => FieldStatus::Multiple,
+      Extracted::Empty => FieldStatus::Empty,
+      Extracted::Missing => FieldStatus::Missing,
+    }
+  }
+
+  fn map<U>(self, f: impl FnOnce(T) -> U) -> Extracted<U> {
+    match self {
+      Extracted::Found(value) => Extracted::Found(f(value)),
+      Extracted::Multiple(value) => Extracted::Multiple(f(value)),
+      Extracted::Empty => Extracted::Empty,
+      Extracted::Missing => Extracted::Missing,
+    }
+  }
+}
+
+/// Records how the fields were extracted.
+#[derive(Default)]
+struct Report(ParseReport);
+
+impl Report {
+  fn extracted<T>(&mut self, field: &'static str, mapping: &str, result: Result<Extracted<T>>) -> Option<T> {
+    match result {
+      Ok(extracted) => {
+        self.0.insert(field, extracted.status());
+        match extracted {
+          Extracted::Found(value) => Some(value),
+          Extracted::Multiple(value) => {
+            log::debug!("[{}] multiple values, using the first one", field);
+            Some(value)
+          }
+          Extracted::Empty | Extracted::Missing => None,
+        }
+      }
+      Err(e) => {
+        self.error(&[field], mapping, e);
+        None
+      }
+    }
+  }
+
+  fn field<T>(&mut self, field: &'static str, mapping: &str, result: Result<T>) -> Option<T> {
+    self.extracted(field, mapping, result.map(Extracted::Found))
+  }
+
+  /// Sets the status of all fields that are extracted together.
+  fn set(&mut self, fields: &[&'static str], status: FieldStatus) {
+    for field in fields {
+      self.0.insert(field, status.clone());
+    }
+  }
+
+  /// The subchapter exists, but the values could not be found in it. `mapping` is the key of the fields in
+  /// `CHAPTER_MAPPING`.
+  fn error(&mut self, fields: &[&'static str], mapping: &str, error: SearchError) {
+    log::debug!("[{}] error: {:#?}", fields.join(", "), error);
+    let location = CHAPTER_MAPPING.get(mapping);
+    let status = FieldStatus::ParseError {
+      message: error.to_string(),
+      chapter: location.map(|(chapter, _)| chapter.to_string()),
+      subchapter: location.map(|(_, subchapter)| subchapter.to_string()),
+    };
+    self.set(fields, status);
+  }
+}
+
+pub fn parse_response(json: &GestisResponse, language: Language) -> Result<ParsedData> {
   log::info!("extracting data for: {} [{}] ...", json.name, json.zvg_number);
+  let labels = Labels::of(language);
+  let mut report = Report::default();
 
-  let h_p_signal_symbols_error;
-  let (h_phrases, p_phrases, signal_word, symbols) = match get_h_p_signal_symbols(&json) {
-    Ok(inner) => {
-      h_p_signal_symbols_error = None;
-      inner
+  let fields = ["hPhrases", "pPhrases", "signalWord", "symbols"];
+  let (h_phrases, p_phrases, signal_word, symbols) = match get_h_p_signal_symbols(json, labels) {
+    Ok(Extracted::Found((h_phrases, p_phrases, signal_word, symbols))) => (
+      report.field("hPhrases", "h_p_signal_symbols", h_phrases),
+      report.field("pPhrases", "h_p_signal_symbols", p_phrases),
+      report.field("signalWord", "h_p_signal_symbols", signal_word),
+      report.field("symbols", "h_p_signal_symbols", symbols),
+    ),
+    Ok(extracted) => {
+      report.set(&fields, extracted.status());
+      (None, None, None, None)
     }
     Err(e) => {
-      h_p_signal_symbols_error = Some(e);
-      (
-        // this error type should not be used like this but here it is
-        // only a sentinel value that is ignored further down anyways
-        Err(SearchError::Empty),
-        Err(SearchError::Empty),
-        Err(SearchError::Empty),
-        Err(SearchError::Empty),
-      )
+      report.error(&fields, "h_p_signal_symbols", e);
+      (None, None, None, None)
     }
   };
 
-  let molecular_formula_molar_mass_error;
+  let fields = ["molecularFormula", "molarMass"];
   let (molecular_formula, molar_mass) = match get_molecular_formula_molar_mass(json) {
-    Ok(inner) => {
-      molecular_formula_molar_mass_error = None;
-      inner
+    Ok(Extracted::Found((molecular_formula, molar_mass))) => (
+      report.field("molecularFormula", "molecular_formula", molecular_formula),
+      report.field("molarMass", "molecular_formula", molar_mass),
+    ),
+    Ok(extracted) => {
+      report.set(&fields, extracted.status());
+      (None, None)
     }
     Err(e) => {
-      molecular_formula_molar_mass_error = Some(e);
-      (
-        // this error type should not be used like this but here it is
-        // only a sentinel value that is ignored further down anyways
-        Err(SearchError::Empty),
-        Err(SearchError::Empty),
-      )
+      report.error(&fields, "molecular_formula", e);
+      (None, None)
+    }
+  };
+
+  let toxicity = report.extracted("toxicity", "toxicity", get_toxicity(json, labels));
+  let lethal_dose = match toxicity
+    .as_deref()
+    .map(|t| (Toxicity::preferred(t), Toxicity::is_ambiguous(t)))
+  {
+    Some((Some(preferred), ambiguous)) => {
+      let status = if ambiguous {
+        log::debug!("[lethalDose] multiple values, using: {}", preferred.value);
+        FieldStatus::Multiple
+      } else {
+        FieldStatus::Found
+      };
+      report.0.insert("lethalDose", status);
+      Some(preferred.value.to_string())
+    }
+    // only other values than LD50
+    Some((None, _)) => {
+      report.0.insert("lethalDose", FieldStatus::Missing);
+      None
+    }
+    None => {
+      report.0.insert("lethalDose", report.0["toxicity"].clone());
+      None
     }
   };
 
   Ok(ParsedData {
-    cas: match get_cas(json) {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &molecular_formula_molar_mass_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[cas_number] error: {:#?}", e);
-        None
-      }
-    },
-    molecular_formula: match molecular_formula {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &molecular_formula_molar_mass_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[molecular_formula] error: {:#?}", e);
-        None
-      }
-    },
-    molar_mass: match molar_mass {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &molecular_formula_molar_mass_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[molar_mass] error: {:#?}", e);
-        None
-      }
-    },
-    melting_point: match get_melting_point(json) {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        log::debug!("[melting_point] error: {:#?}", e);
-        None
-      }
-    },
-    boiling_point: match get_boiling_point(json) {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        log::debug!("[boiling_point] error: {:#?}", e);
-        None
-      }
-    },
-    water_hazard_class: match get_whc(json) {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        log::debug!("[water_hazard_class] error: {:#?}", e);
-        None
-      }
-    },
-    h_phrases: match h_phrases {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &h_p_signal_symbols_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[h_phrases] error: {:#?}", e);
-        None
-      }
-    },
-    p_phrases: match p_phrases {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &h_p_signal_symbols_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[p_phrases] error: {:#?}", e);
-        None
-      }
-    },
-    signal_word: match signal_word {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &h_p_signal_symbols_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[signal_word] error: {:#?}", e);
-        None
-      }
-    },
-    symbols: match symbols {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        let e = match &h_p_signal_symbols_error {
-          Some(e_new) => e_new,
-          None => &e,
-        };
-        log::debug!("[symbols] error: {:#?}", e);
-        None
-      }
-    },
-    lethal_dose: match get_lethal_dose(json) {
-      Ok(inner) => inner,
-      Err(e) => {
-        log::debug!("[lethal_dose] error: {:#?}", e);
-        match e {
-          SearchError::Multiple(inner) => Some(inner),
-          _ => None,
-        }
-      }
-    },
-    mak: match get_mak(json) {
-      Ok(inner) => Some(inner),
-      Err(e) => {
-        log::debug!("[mak] error: {:#?}", e);
-        None
-      }
+    cas: report.extracted("cas", "cas_number", get_cas(json)),
+    molecular_formula,
+    molar_mass,
+    melting_point: report.extracted("meltingPoint", "melting_point", get_melting_point(json, labels)),
+    boiling_point: report.extracted("boilingPoint", "boiling_point", get_boiling_point(json, labels)),
+    density: report.extracted("density", "density", get_density(json, labels)),
+    flash_point: report.extracted(
+      "flashPoint",
+      "flash_point",
+      get_first_value(json, "flash_point", labels.flash_point),
+    ),
+    ignition_temperature: report.extracted(
+      "ignitionTemperature",
+      "ignition_temperature",
+      get_first_value(json, "ignition_temperature", labels.ignition_temperature),
+    ),
+    explosion_limits: report.extracted(
+      "explosionLimits",
+      "explosion_limits",
+      get_explosion_limits(json, labels),
+    ),
+    water_hazard_class: report.extracted("waterHazardClass", "water_hazard_class", get_whc(json, labels)),
+    h_phrases,
+    p_phrases,
+    signal_word,
+    symbols,
+    lethal_dose,
+    toxicity,
+    mak: {
+      let (mapping, mak) = get_mak(json, labels);
+      report.extracted("mak", mapping, mak)
     },
+    reactions: report.extracted("reactions", "reactions", get_reactions(json, labels)),
+    report: report.0,
   })
 }
 
@@ -192,6 +307,12 @@ pub fn get_xml(json: &GestisResponse, chapter: &str, subchapter: &str) -> Result
   Err(SearchError::NoXML)
 }
 
+/// The xml of the subchapter of `name` in `CHAPTER_MAPPING`, `None` if the article has no such subchapter.
+fn mapped_xml(json: &GestisResponse, name: &str) -> Option<String> {
+  let (chapter, subchapter) = CHAPTER_MAPPING.get(name).unwrap();
+  get_xml(json, chapter, subchapter).ok()
+}
+
 #[inline]
 fn tables(node: &Node, class: &str) -> Vec<Vec<Vec<NodeId>>> {
   node
@@ -214,9 +335,11 @@ fn tables(node: &Node, class: &str) -> Vec<Vec<Vec<NodeId>>> {
 
 /* #region  extractors */
 
-fn get_cas(json: &GestisResponse) -> Result<String> {
-  let (chapter, subchapter) = CHAPTER_MAPPING.get("cas_number").unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+fn get_cas(json: &GestisResponse) -> Result<Extracted<String>> {
+  let xml = match mapped_xml(json, "cas_number") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
   for table in tables(&doc.root().first_child().unwrap(), "block")
@@ -230,7 +353,7 @@ fn get_cas(json: &GestisResponse) -> Result<String> {
     let data = doc.get_node(table[1]).unwrap();
     if let Some(cas) = data.first_element_child() {
       if cas.has_tag_name("casnr") {
-        return Ok(cas.text().unwrap().into());
+        return Ok(Extracted::Found(cas.text().unwrap().into()));
       }
     }
   }
@@ -238,9 +361,13 @@ fn get_cas(json: &GestisResponse) -> Result<String> {
   Err(SearchError::MissingInfo("cas number".into()))
 }
 
-fn get_molecular_formula_molar_mass(json: &GestisResponse) -> Result<(Result<String>, Result<String>)> {
-  let (chapter, subchapter) = CHAPTER_MAPPING.get("molecular_formula").unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+type MolecularFormulaMolarMassResult = Result<Extracted<(Result<String>, Result<String>)>>;
+
+fn get_molecular_formula_molar_mass(json: &GestisResponse) -> MolecularFormulaMolarMassResult {
+  let xml = match mapped_xml(json, "molecular_formula") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
   let mut id_tables = tables(&doc.root().first_child().unwrap(), "block")
@@ -278,21 +405,23 @@ fn get_molecular_formula_molar_mass(json: &GestisResponse) -> Result<(Result<Str
     }
   }
 
-  Ok((molecular_formula, molar_mass))
+  Ok(Extracted::Found((molecular_formula, molar_mass)))
 }
 
-fn get_melting_point(json: &GestisResponse) -> Result<String> {
-  get_mp_bp(json, "melting_point", "Schmelzpunkt:")
+fn get_melting_point(json: &GestisResponse, labels: &Labels) -> Result<Extracted<String>> {
+  get_mp_bp(json, "melting_point", labels.melting_point, labels)
 }
 
-fn get_boiling_point(json: &GestisResponse) -> Result<String> {
-  get_mp_bp(json, "boiling_point", "Siedepunkt:")
+fn get_boiling_point(json: &GestisResponse, labels: &Labels) -> Result<Extracted<String>> {
+  get_mp_bp(json, "boiling_point", labels.boiling_point, labels)
 }
 
-/// returns melting point or boiling point
-fn get_mp_bp(json: &GestisResponse, name: &str, xml_check: &str) -> Result<String> {
-  let (chapter, subchapter) = CHAPTER_MAPPING.get(name).unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+/// returns melting point or boiling point, substances that decompose instead get the decomposition as value
+fn get_mp_bp(json: &GestisResponse, name: &str, xml_check: &str, labels: &Labels) -> Result<Extracted<String>> {
+  let xml = match mapped_xml(json, name) {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
   let mut mp_bp_point = None;
@@ -319,25 +448,132 @@ fn get_mp_bp(json: &GestisResponse, name: &str, xml_check: &str) -> Result<Strin
       .flatten();
 
     let mp_bp_text_node = doc.get_node(inner_data_ids.next().unwrap()).unwrap();
-    if let Some(mp_bp_text) = mp_bp_text_node.text() {
-      if mp_bp_text == xml_check {
-        let mp_bp_node = doc.get_node(inner_data_ids.next().unwrap()).unwrap();
-        mp_bp_point = mp_bp_node.text();
+    if is_label(mp_bp_text_node.text(), xml_check) {
+      let mp_bp_node = doc.get_node(inner_data_ids.next().unwrap()).unwrap();
+      mp_bp_point = mp_bp_node.text();
+    }
+  }
+
+  if let Some(mp) = mp_bp_point {
+    return Ok(Extracted::Found(mp.trim().into()));
+  }
+
+  // e.g. "Der Stoff zersetzt sich beim Erhitzen (s. Zersetzungstemperatur)."
+  let (texts, decomposition) = labels.decomposition;
+  let decomposes = doc
+    .descendants()
+    .filter_map(|n| n.text())
+    .map(str::to_lowercase)
+    .any(|text| texts.iter().any(|t| text.contains(t)));
+  match decomposes {
+    true => Ok(Extracted::Found(decomposition.into())),
+    false => Err(SearchError::MissingInfo(name.into())),
+  }
+}
+
+/// Returns the values of all fields with one of the labels, e.g. `Wert:` -> `1,54 g/cm³`.
+///
+/// The numbering of the physical-chemical subchapters is not the same in every article, with `search_chapter`
+/// the other subchapters of the chapter are searched if the mapped one contains no such field.
+fn get_labeled_values(
+  json: &GestisResponse,
+  name: &str,
+  labels: &[&str],
+  search_chapter: bool,
+) -> Result<Extracted<Vec<String>>> {
+  let (chapter, subchapter) = CHAPTER_MAPPING.get(name).unwrap();
+  let mapped = get_xml(json, chapter, subchapter);
+  // if the mapped subchapter exists, a missing value is no longer caused by a missing subchapter
+  let exists = mapped.is_ok();
+  let mut xmls = vec![mapped];
+  if search_chapter {
+    if let Some(chapter) = json.chapters.iter().find(|c| c.number == *chapter) {
+      xmls.extend(
+        chapter
+          .subchapters
+          .iter()
+          .filter(|s| s.number != *subchapter)
+          .map(|s| get_xml(json, &chapter.number, &s.number)),
+      );
+    }
+  }
+
+  for xml in xmls.into_iter().filter_map(|xml| xml.ok()) {
+    let doc = Document::parse(&xml)?;
+    let mut values = Vec::new();
+    for data_id in tables(&doc.root().first_child().unwrap(), "block")
+      .into_iter()
+      .flatten()
+      .flatten()
+    {
+      let data = doc.get_node(data_id).unwrap();
+      for row in tables(&data, "feldmitlabel").into_iter().flatten() {
+        if row.len() < 2 {
+          continue;
+        }
+        let label = doc.get_node(row[0]).unwrap().text();
+        if !labels.iter().any(|l| is_label(label, l)) {
+          continue;
+        }
+        let value = doc
+          .get_node(row[1])
+          .unwrap()
+          .descendants()
+          .filter_map(|n| n.text())
+          .map(str::trim)
+          .find(|text| !text.is_empty());
+        if let Some(value) = value {
+          values.push(value.into());
+        }
       }
     }
+    if !values.is_empty() {
+      return Ok(Extracted::Found(values));
+    }
   }
 
-  match mp_bp_point {
-    Some(mp) => Ok(mp.trim().into()),
-    None => Err(SearchError::MissingInfo(name.into())),
+  match exists {
+    true => Err(SearchError::MissingInfo(name.into())),
+    false => Ok(Extracted::Missing),
   }
 }
 
-fn get_whc(json: &GestisResponse) -> Result<String> {
-  static KEYWORD: &str = "Nicht wassergefährdender Stoff";
+/// The labels of flash point and ignition temperature are unique in the chapter.
+fn get_first_value(json: &GestisResponse, name: &str, labels: &[&str]) -> Result<Extracted<String>> {
+  Ok(get_labeled_values(json, name, labels, true)?.map(|mut values| values.remove(0)))
+}
 
-  let (chapter, subchapter) = CHAPTER_MAPPING.get("water_hazard_class").unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+/// Some substances list several densities (e.g. anhydrous and hydrate), the first one is used then.
+fn get_density(json: &GestisResponse, labels: &Labels) -> Result<Extracted<String>> {
+  // `Wert:` is no unique label, so only the density subchapter is searched
+  Ok(match get_labeled_values(json, "density", &[labels.value], false)? {
+    Extracted::Found(mut values) if values.len() > 1 => Extracted::Multiple(values.remove(0)),
+    extracted => extracted.map(|mut values| values.remove(0)),
+  })
+}
+
+/// Returns the lower and upper explosion limit as range, e.g. `3,1 ... 27,7 Vol%`.
+fn get_explosion_limits(json: &GestisResponse, labels: &Labels) -> Result<Extracted<String>> {
+  let lower = get_labeled_values(json, "explosion_limits", labels.lower_explosion_limit, true)?;
+  let upper = get_labeled_values(json, "explosion_limits", labels.upper_explosion_limit, true)?;
+  let (lower, upper) = match (lower, upper) {
+    (Extracted::Found(mut lower), Extracted::Found(mut upper)) => (lower.remove(0), upper.remove(0)),
+    _ => return Ok(Extracted::Missing),
+  };
+
+  // both limits are usually given in Vol%, the unit is only written once
+  let lower = match upper.split_once(' ') {
+    Some((_, unit)) if lower.ends_with(unit) => lower.trim_end_matches(unit).trim().to_string(),
+    _ => lower,
+  };
+  Ok(Extracted::Found(format!("{} ... {}", lower, upper)))
+}
+
+fn get_whc(json: &GestisResponse, labels: &Labels) -> Result<Extracted<String>> {
+  let xml = match mapped_xml(json, "water_hazard_class") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
   let mut tables = tables(&doc.root().first_child().unwrap(), "block")
@@ -362,24 +598,26 @@ fn get_whc(json: &GestisResponse) -> Result<String> {
   if let Some(node_id) = tables.next() {
     data = doc.get_node(node_id).unwrap();
     let text = data.text().unwrap();
-    if text == KEYWORD {
-      return Err(SearchError::Empty);
+    if is_label(Some(text), labels.not_water_hazardous) {
+      return Ok(Extracted::Empty);
     } else {
-      return Ok(text.split('-').next().unwrap().trim().into());
+      return Ok(Extracted::Found(text.split('-').next().unwrap().trim().into()));
     }
   }
 
   Err(SearchError::MissingInfo("water hazard class".into()))
 }
 
-type HPSignalSymbolsResult = Result<(
-  Result<Vec<(std::string::String, std::string::String)>>,
-  Result<Vec<(std::string::String, std::string::String)>>,
-  Result<std::string::String>,
-  Result<Vec<String>>,
-)>;
+type HPSignalSymbolsResult = Result<
+  Extracted<(
+    Result<Vec<(std::string::String, std::string::String)>>,
+    Result<Vec<(std::string::String, std::string::String)>>,
+    Result<std::string::String>,
+    Result<Vec<String>>,
+  )>,
+>;
 
-fn get_h_p_signal_symbols(json: &GestisResponse) -> HPSignalSymbolsResult {
+fn get_h_p_signal_symbols(json: &GestisResponse, labels: &Labels) -> HPSignalSymbolsResult {
   #[inline]
   fn extract_h_p(id: NodeId, doc: &Document) -> Vec<(String, String)> {
     let data = doc.get_node(id).unwrap();
@@ -388,14 +626,16 @@ fn get_h_p_signal_symbols(json: &GestisResponse) -> HPSignalSymbolsResult {
       .filter(|n| n.is_text())
       .map(|n| n.text().unwrap())
       .map(|s| s.splitn(2, ':').map(|s| s.trim()).collect::<Vec<&str>>())
-      // TODO remove lines that are no h/p-phrases
-      .filter(|v| v.len() > 1) // TODO quickfix, remove
+      // the cell also contains notes and footnotes
+      .filter(|v| v.len() > 1 && ghs::is_code(v[0]))
       .map(|v| (v[0].into(), v[1].into()))
       .collect()
   }
 
-  let (chapter, subchapter) = CHAPTER_MAPPING.get("h_p_signal_symbols").unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+  let xml = match mapped_xml(json, "h_p_signal_symbols") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
   let mut h_phrases = Err(SearchError::MissingInfo("h phrases".into()));
@@ -412,11 +652,11 @@ fn get_h_p_signal_symbols(json: &GestisResponse) -> HPSignalSymbolsResult {
       if let Some(data) = data_iter.peek() {
         if let Some(inner) = data.first_element_child() {
           if inner.has_tag_name("b") {
-            if inner.text() == Some("Gefahrenhinweise - H-Sätze:") {
+            if is_label(inner.text(), labels.h_phrases) {
               if let Some(row) = row_iter.next() {
                 h_phrases = Ok(extract_h_p(row[0], &doc));
               }
-            } else if inner.text() == Some("Sicherheitshinweise - P-Sätze:") {
+            } else if is_label(inner.text(), labels.p_phrases) {
               if let Some(row) = row_iter.next() {
                 p_phrases = Ok(extract_h_p(row[0], &doc));
               }
@@ -441,7 +681,7 @@ fn get_h_p_signal_symbols(json: &GestisResponse) -> HPSignalSymbolsResult {
               .map(|id| doc.get_node(id).unwrap());
             if let Some(data) = table_iter.next() {
               if let Some(inner) = data.first_element_child() {
-                if inner.has_tag_name("b") && inner.text() == Some("Signalwort:") {
+                if inner.has_tag_name("b") && is_label(inner.text(), labels.signal_word) {
                   signal_word = Ok(table_iter.next().unwrap().text().unwrap().trim_matches('"').to_string());
                 }
               }
@@ -452,62 +692,237 @@ fn get_h_p_signal_symbols(json: &GestisResponse) -> HPSignalSymbolsResult {
     }
   }
 
-  Ok((h_phrases, p_phrases, signal_word, symbols))
+  Ok(Extracted::Found((h_phrases, p_phrases, signal_word, symbols)))
 }
 
-fn get_lethal_dose(json: &GestisResponse) -> Result<Option<String>> {
-  let (chapter, subchapter) = CHAPTER_MAPPING.get("lethal_dose").unwrap();
-  let xml = get_xml(json, chapter, subchapter)?;
+/// All acute toxicity values, each one is a heading (e.g. `LD50 oral Ratte`) followed by a table with the value.
+fn get_toxicity(json: &GestisResponse, labels: &Labels) -> Result<Extracted<Vec<Toxicity>>> {
+  let xml = match mapped_xml(json, "toxicity") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
   let doc = Document::parse(&xml)?;
 
-  let mut ld50: Option<&str> = None;
+  let mut toxicity = Vec::new();
+  let mut heading: Option<&str> = None;
 
-  let mut table_iter = tables(&doc.root().first_child().unwrap(), "block").into_iter();
+  for table in tables(&doc.root().first_child().unwrap(), "block") {
+    let mut data_iter = match table.into_iter().next() {
+      Some(row) => row.into_iter().map(|id| doc.get_node(id).unwrap()),
+      None => continue,
+    };
 
-  while let Some(table) = table_iter.next() {
-    let mut row_iter = table.into_iter();
+    if let Some(data) = data_iter.next() {
+      if let Some(inner) = data.first_element_child().filter(|inner| inner.has_tag_name("b")) {
+        heading = inner.text();
+      } else if let Some(current) = heading.take() {
+        if is_label(data.first_child().and_then(|text| text.text()), labels.value) {
+          if let Some(value) = data_iter.next().and_then(|value| value.text()) {
+            match Toxicity::parse(current.trim(), Quantity::parse(value.trim())) {
+              Some(entry) => toxicity.push(entry),
+              None => log::debug!("skipping toxicity value: {}", current),
+            }
+          }
+        }
+      }
+    }
+  }
 
-    if let Some(row) = row_iter.next() {
-      let mut data_iter = row.into_iter().map(|id| doc.get_node(id).unwrap()).peekable();
+  if toxicity.is_empty() {
+    Ok(Extracted::Empty)
+  } else {
+    Ok(Extracted::Found(toxicity))
+  }
+}
 
-      if let Some(data) = data_iter.peek() {
-        if let Some(inner) = data.first_element_child() {
-          if inner.has_tag_name("b") && inner.text() == Some("LD50 oral Ratte") {
-            if let Some(inner) = ld50 {
-              return Err(SearchError::Multiple(inner.into()));
-            } else if let Some(table) = table_iter.next() {
-              let mut row_iter = table.into_iter();
+/// Workplace limit values of one subchapter
+#[derive(Debug, Default)]
+struct WorkplaceLimit {
+  ppm: Option<String>,
+  mg_per_m3: Option<String>,
+  sources: Vec<String>,
+}
 
-              if let Some(row) = row_iter.next() {
-                let mut data_iter = row.into_iter().map(|id| doc.get_node(id).unwrap());
-
-                if let Some(data) = data_iter.next() {
-                  if let Some(text) = data.first_child() {
-                    if text.text() == Some("Wert:") {
-                      if let Some(value) = data_iter.next() {
-                        ld50 = value.text();
-                      }
-                    }
-                  }
-                }
-              }
-            }
-          }
+impl WorkplaceLimit {
+  /// Fills the units that are missing with the values of `other`, its sources are only added if one of its values
+  /// is used.
+  fn merge(&mut self, other: WorkplaceLimit) {
+    let mut used = false;
+    if self.ppm.is_none() && other.ppm.is_some() {
+      self.ppm = other.ppm;
+      used = true;
+    }
+    if self.mg_per_m3.is_none() && other.mg_per_m3.is_some() {
+      self.mg_per_m3 = other.mg_per_m3;
+      used = true;
+    }
+
+    if used {
+      for source in other.sources {
+        if !self.sources.contains(&source) {
+          self.sources.push(source);
         }
       }
     }
   }
+}
 
-  match ld50 {
-    Some(inner) => Ok(Some(inner.into())),
-    None => Err(SearchError::MissingInfo("lethal dose".into())),
+/// Returns the workplace limit values, the legally binding values (TRGS 900) are preferred over the recommendations
+/// of the MAK commission (DFG), which only complete missing units.
+/// Returns the key of the subchapter in `CHAPTER_MAPPING` that the value (or the error) comes from together with the
+/// value, the values of the TRGS 900 are completed by the ones of the DFG.
+fn get_mak(json: &GestisResponse, labels: &Labels) -> (&'static str, Result<Extracted<String>>) {
+  let mut limit: Option<WorkplaceLimit> = None;
+  let mut mapping = None;
+  let mut listed = false;
+
+  for (name, default_source) in [("mak1", "TRGS 900"), ("mak2", "DFG")].iter() {
+    match get_workplace_limit(json, labels, name, default_source) {
+      Ok(Extracted::Found(new)) | Ok(Extracted::Multiple(new)) => {
+        mapping.get_or_insert(*name);
+        match limit.as_mut() {
+          Some(limit) => limit.merge(new),
+          None => limit = Some(new),
+        }
+      }
+      Ok(Extracted::Empty) => listed = true,
+      Ok(Extracted::Missing) => log::trace!("[{}] no subchapter", name),
+      Err(e) => return (name, Err(e)),
+    }
   }
+
+  let mak = match limit {
+    Some(limit) => {
+      let values: Vec<String> = vec![
+        limit.ppm.map(|ppm| format!("{} ppm", ppm)),
+        limit.mg_per_m3.map(|mg| format!("{} mg/m³", mg)),
+      ]
+      .into_iter()
+      .flatten()
+      .collect();
+
+      Extracted::Found(format!("{} ({})", values.join(", "), limit.sources.join(", ")))
+    }
+    // the substance is listed, but without limit values
+    None if listed => Extracted::Empty,
+    None => Extracted::Missing,
+  };
+  (mapping.unwrap_or("mak1"), Ok(mak))
 }
 
-fn get_mak(_json: &GestisResponse) -> Result<String> {
-  // TODO implement function
-  log::error!("not implemented: 'get_mak()'");
-  Err(SearchError::MissingInfo("mak".into()))
+fn get_workplace_limit(
+  json: &GestisResponse,
+  labels: &Labels,
+  name: &str,
+  default_source: &str,
+) -> Result<Extracted<WorkplaceLimit>> {
+  const KNOWN_SOURCES: [&str; 5] = ["TRGS 900", "TRGS 910", "DFG", "AGS", "EU"];
+
+  #[inline]
+  fn text_of(node: &Node) -> String {
+    node
+      .descendants()
+      .filter(|n| n.is_text())
+      .map(|n| n.text().unwrap())
+      .collect::<String>()
+      .trim()
+      .to_string()
+  }
+
+  let xml = match mapped_xml(json, name) {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
+  let doc = Document::parse(&xml)?;
+
+  let mut limit = WorkplaceLimit::default();
+  let mut sources = vec![default_source.to_string()];
+
+  for row in tables(&doc.root().first_child().unwrap(), "block")
+    .into_iter()
+    .flatten()
+  {
+    if row.len() < 2 {
+      continue;
+    }
+    let label = text_of(&doc.get_node(row[0]).unwrap());
+    let value = text_of(&doc.get_node(row[1]).unwrap());
+
+    if labels.workplace_limits.iter().any(|l| is_label(Some(&label), l)) {
+      // e.g. "200 ml/m³ (ppm) bzw. 380 mg/m³"
+      for part in value.split(labels.workplace_limit_separator).map(|p| p.trim()) {
+        if let Some(index) = part.find("mg/m³") {
+          limit.mg_per_m3 = Some(part[..index].trim().into());
+        } else if let Some(index) = part.find("ml/m³").or_else(|| part.find("ppm")) {
+          limit.ppm = Some(part[..index].trim().into());
+        }
+      }
+    } else if labels.sources.iter().any(|l| is_label(Some(&label), l)) {
+      sources.extend(
+        value
+          .split(&[',', ';', '('][..])
+          .map(|s| s.trim())
+          .filter(|s| KNOWN_SOURCES.contains(s))
+          .map(|s| s.to_string()),
+      );
+    }
+  }
+
+  if limit.ppm.is_none() && limit.mg_per_m3.is_none() {
+    return Ok(Extracted::Empty);
+  }
+
+  for source in sources {
+    if !limit.sources.contains(&source) {
+      limit.sources.push(source);
+    }
+  }
+
+  Ok(Extracted::Found(limit))
+}
+
+/// Returns the lines of the dangerous chemical reactions, the label is in its own row above the lines.
+fn get_reactions(json: &GestisResponse, labels: &Labels) -> Result<Extracted<Vec<String>>> {
+  let xml = match mapped_xml(json, "reactions") {
+    Some(xml) => xml,
+    None => return Ok(Extracted::Missing),
+  };
+  let doc = Document::parse(&xml)?;
+
+  for rows in tables(&doc.root().first_child().unwrap(), "block").into_iter() {
+    for row in rows.windows(2) {
+      let label = match row[0].first() {
+        Some(id) => doc.get_node(*id).unwrap().first_element_child(),
+        None => continue,
+      };
+      if !labels
+        .reactions
+        .iter()
+        .any(|l| is_label(label.and_then(|l| l.text()), l))
+      {
+        continue;
+      }
+
+      let lines: Vec<String> = match row[1].first() {
+        Some(id) => doc
+          .get_node(*id)
+          .unwrap()
+          .children()
+          .filter_map(|n| n.text())
+          .map(|line| line.trim())
+          .filter(|line| !line.is_empty())
+          .map(String::from)
+          .collect(),
+        None => Vec::new(),
+      };
+      if lines.is_empty() {
+        return Ok(Extracted::Empty);
+      }
+      return Ok(Extracted::Found(lines));
+    }
+  }
+
+  Err(SearchError::MissingInfo("reactions".into()))
 }
 
 /* #endregion */
@@ -516,9 +931,14 @@ fn get_mak(_json: &GestisResponse) -> Result<String> {
 mod tests {
   use std::{fs::File, io::BufReader};
 
-  use crate::gestis::types::GestisResponse;
+  use cabr2_types::ToxicityType;
 
-  use super::parse_response;
+  use crate::{
+    gestis::types::{Chapter, GestisResponse, Subchapter},
+    types::{FieldStatus, Language},
+  };
+
+  use super::{get_mak, get_workplace_limit, parse_response, Extracted, Labels};
 
   fn load_substance_json(path: &str) -> GestisResponse {
     let file = File::open(path).unwrap();
@@ -528,11 +948,264 @@ mod tests {
 
   #[test]
   fn test_parsing_cobaltdinitrat() {
-    parse_response(&load_substance_json("tests/assets/cobaltdinitrat.json")).unwrap();
+    parse_response(
+      &load_substance_json("tests/assets/cobaltdinitrat.json"),
+      Language::German,
+    )
+    .unwrap();
+  }
+
+  /// Checks the english labels against a recorded article, the article is recorded with
+  /// `contrib/gestis_helper.sh --extract 010420 --english` and its `_response.json` copied to the assets.
+  #[test]
+  #[ignore = "needs the recorded english article of ethanol in tests/assets/english/ethanol.json"]
+  fn test_parsing_english_ethanol() {
+    let data = parse_response(
+      &load_substance_json("tests/assets/english/ethanol.json"),
+      Language::English,
+    )
+    .unwrap();
+
+    for field in [
+      "meltingPoint",
+      "boilingPoint",
+      "flashPoint",
+      "ignitionTemperature",
+      "explosionLimits",
+      "hPhrases",
+      "signalWord",
+      "mak",
+    ]
+    .iter()
+    {
+      assert_eq!(data.report[field], FieldStatus::Found, "{}", field);
+    }
+    assert!(data.melting_point.unwrap().contains("°C"));
+    assert!(data.boiling_point.unwrap().contains("°C"));
+    assert!(data.mak.unwrap().contains(" ppm"));
   }
 
   #[test]
   fn test_parsing_calcium() {
-    parse_response(&load_substance_json("tests/assets/calcium.json")).unwrap();
+    parse_response(&load_substance_json("tests/assets/calcium.json"), Language::German).unwrap();
+  }
+
+  #[test]
+  fn test_reactions() {
+    let data = parse_response(&load_substance_json("tests/assets/calcium.json"), Language::German).unwrap();
+    let reactions = data.reactions.unwrap();
+    assert_eq!(
+      reactions[0],
+      "Feuchtes Calcium, in Gegenwart von Sauerstoff --> Selbstentzündung"
+    );
+    assert!(reactions.contains(&"Feuchte Luft oder Wasser --> Wasserstoffentwicklung".to_string()));
+  }
+
+  #[test]
+  fn test_density() {
+    let calcium = parse_response(&load_substance_json("tests/assets/calcium.json"), Language::German).unwrap();
+    assert_eq!(calcium.density, Some("1,54 g/cm³".into()));
+    assert_eq!(calcium.flash_point, None);
+
+    // the anhydrous salt is listed first
+    let cobalt = parse_response(
+      &load_substance_json("tests/assets/cobaltdinitrat.json"),
+      Language::German,
+    )
+    .unwrap();
+    assert_eq!(cobalt.density, Some("2,49 g/cm³".into()));
+    assert_eq!(cobalt.report["density"], FieldStatus::Multiple);
+  }
+
+  #[test]
+  fn test_fire_properties() {
+    fn field(label: &str, value: &str) -> String {
+      format!(
+        "<table class=\"block\"><tr><td><table class=\"feldmitlabel\"><tr><td class=\"vortext\">{}</td><td> {}</td>\
+         </tr></table></td></tr></table>",
+        label, value
+      )
+    }
+    let subchapter = |number: &str, text: String| Subchapter {
+      number: number.into(),
+      text: Some(text),
+    };
+
+    // explosion limits in an unexpected subchapter are found, too
+    let json = GestisResponse {
+      zvg_number: "010420".into(),
+      name: "Ethanol".into(),
+      chapters: vec![Chapter {
+        number: "0600".into(),
+        subchapters: vec![
+          subchapter("0607", field("Flammpunkt:", "12 °C")),
+          subchapter("0608", field("Zündtemperatur:", "400 °C")),
+          subchapter(
+            "0610",
+            field("Untere Explosionsgrenze (UEG):", "3,1 Vol%") + &field("Obere Explosionsgrenze (OEG):", "27,7 Vol%"),
+          ),
+        ],
+      }],
+      aliases: Vec::new(),
+    };
+
+    let data = parse_response(&json, Language::German).unwrap();
+    assert_eq!(data.flash_point, Some("12 °C".into()));
+    assert_eq!(data.ignition_temperature, Some("400 °C".into()));
+    assert_eq!(data.explosion_limits, Some("3,1 ... 27,7 Vol%".into()));
+    assert_eq!(data.density, None);
+  }
+
+  #[test]
+  fn test_report() {
+    let data = parse_response(&load_substance_json("tests/assets/calcium.json"), Language::German).unwrap();
+    assert_eq!(data.report["boilingPoint"], FieldStatus::Found);
+    // the article has no subchapter for the flash point
+    assert_eq!(data.report["flashPoint"], FieldStatus::Missing);
+
+    // the melting point subchapter only says that the substance decomposes
+    let data = parse_response(
+      &load_substance_json("tests/assets/cobaltdinitrat.json"),
+      Language::German,
+    )
+    .unwrap();
+    assert_eq!(data.melting_point, Some("Zersetzung".into()));
+    assert_eq!(data.report["meltingPoint"], FieldStatus::Found);
+    // listed by the MAK commission, but without values
+    assert_eq!(data.report["mak"], FieldStatus::Empty);
+  }
+
+  #[test]
+  fn test_toxicity() {
+    let data = parse_response(
+      &load_substance_json("tests/assets/cobaltdinitrat.json"),
+      Language::German,
+    )
+    .unwrap();
+    let toxicity = data.toxicity.unwrap();
+    assert_eq!(toxicity.len(), 2);
+    assert_eq!(toxicity[0].kind, ToxicityType::LD50);
+    assert_eq!(toxicity[0].route, "oral");
+    assert_eq!(toxicity[0].species, "Ratte");
+    assert_eq!(toxicity[1].value.as_str(), "434 mg/kg");
+    // the hydrate and the anhydrous salt are listed
+    assert_eq!(data.lethal_dose, Some("691 mg/kg".into()));
+    assert_eq!(data.report["lethalDose"], FieldStatus::Multiple);
+    assert_eq!(data.report["toxicity"], FieldStatus::Found);
+  }
+
+  #[test]
+  fn test_toxicity_without_values() {
+    let json = GestisResponse {
+      zvg_number: "010420".into(),
+      name: "Ethanol".into(),
+      chapters: vec![Chapter {
+        number: "0500".into(),
+        subchapters: vec![Subchapter {
+          number: "0501".into(),
+          text: Some("<table class=\"block\"><tr><td>Es liegen keine Angaben vor.</td></tr></table>".into()),
+        }],
+      }],
+      aliases: Vec::new(),
+    };
+    let data = parse_response(&json, Language::German).unwrap();
+    assert_eq!(data.toxicity, None);
+    assert_eq!(data.lethal_dose, None);
+    assert_eq!(data.report["toxicity"], FieldStatus::Empty);
+    assert_eq!(data.report["lethalDose"], FieldStatus::Empty);
+  }
+
+  /// An article with the given workplace limit subchapters, each one is a list of label and value rows.
+  fn workplace_limits(subchapters: &[(&str, &[(&str, &str)])]) -> GestisResponse {
+    GestisResponse {
+      zvg_number: "010420".into(),
+      name: "Ethanol".into(),
+      chapters: vec![Chapter {
+        number: "1100".into(),
+        subchapters: subchapters
+          .iter()
+          .map(|(number, rows)| Subchapter {
+            number: number.to_string(),
+            text: Some(format!(
+              "<table class=\"block\">{}</table>",
+              rows
+                .iter()
+                .map(|(label, value)| format!("<tr><td><b>{}</b></td><td>{}</td></tr>", label, value))
+                .collect::<String>()
+            )),
+          })
+          .collect(),
+      }],
+      aliases: Vec::new(),
+    }
+  }
+
+  #[test]
+  fn test_mak() {
+    // the DFG values are the same, so only TRGS 900 is the source
+    let json = workplace_limits(&[
+      (
+        "1201",
+        &[
+          ("Arbeitsplatzgrenzwert:", "200 ml/m³ (ppm) bzw. 380 mg/m³"),
+          ("Quelle:", "TRGS 900"),
+        ],
+      ),
+      ("1203", &[("MAK-Wert:", "200 ml/m³ (ppm) bzw. 380 mg/m³")]),
+    ]);
+    let data = parse_response(&json, Language::German).unwrap();
+    assert_eq!(data.mak, Some("200 ppm, 380 mg/m³ (TRGS 900)".into()));
+
+    // the missing unit is taken from the DFG
+    let json = workplace_limits(&[
+      ("1201", &[("Arbeitsplatzgrenzwert:", "500 ml/m³ (ppm)")]),
+      (
+        "1203",
+        &[("MAK-Wert:", "200 ml/m³ (ppm) bzw. 380 mg/m³"), ("Quelle:", "DFG")],
+      ),
+    ]);
+    let data = parse_response(&json, Language::German).unwrap();
+    assert_eq!(data.mak, Some("500 ppm, 380 mg/m³ (TRGS 900, DFG)".into()));
+
+    // only recommended by the DFG
+    let json = workplace_limits(&[("1203", &[("MAK-Wert:", "380 mg/m³")])]);
+    assert_eq!(get_mak(&json, Labels::of(Language::German)).0, "mak2");
+    let data = parse_response(&json, Language::German).unwrap();
+    assert_eq!(data.mak, Some("380 mg/m³ (DFG)".into()));
+
+    // errors point to the subchapter that can't be read
+    let json = workplace_limits(&[
+      ("1201", &[("Arbeitsplatzgrenzwert:", "500 ml/m³ (ppm)")]),
+      ("1203", &[("MAK-Wert:</i>", "380 mg/m³")]),
+    ]);
+    let data = parse_response(&json, Language::German).unwrap();
+    assert!(matches!(
+      &data.report["mak"],
+      FieldStatus::ParseError { subchapter: Some(subchapter), .. } if subchapter == "1203"
+    ));
+  }
+
+  #[test]
+  fn test_mak_without_limit_values() {
+    // the MAK commission lists cobalt nitrate, but without any values
+    let data = parse_response(
+      &load_substance_json("tests/assets/cobaltdinitrat.json"),
+      Language::German,
+    )
+    .unwrap();
+    assert_eq!(data.mak, None);
+
+    // the recorded article only has the recommendations of the MAK commission
+    let json = load_substance_json("tests/assets/cobaltdinitrat.json");
+    let labels = Labels::of(Language::German);
+    assert!(matches!(
+      get_workplace_limit(&json, labels, "mak1", "TRGS 900"),
+      Ok(Extracted::Missing)
+    ));
+    assert!(matches!(
+      get_workplace_limit(&json, labels, "mak2", "DFG"),
+      Ok(Extracted::Empty)
+    ));
+    assert_eq!(get_mak(&json, labels).0, "mak1");
   }
 }
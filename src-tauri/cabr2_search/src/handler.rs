@@ -1,34 +1,90 @@
-use std::{borrow::Borrow, collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 use lazy_static::lazy_static;
-use tokio::sync::Mutex;
+use tokio::sync::RwLock;
 
-use cabr2_types::SubstanceData;
+use cabr2_types::ProviderMapping;
 
 use crate::{
+  cache,
   error::{Result, SearchError},
-  types::{Provider, ProviderInfo, SearchArguments, SearchResponse, SearchType},
+  types::{Language, ParsedSubstanceData, Provider, ProviderInfo, SearchArguments, SearchResponse, SearchType},
 };
 
+type ProvidersMap = Arc<RwLock<HashMap<&'static str, Arc<dyn Provider + Send + Sync>>>>;
+
 lazy_static! {
-  pub static ref REGISTERED_PROVIDERS: Arc<Mutex<HashMap<&'static str, Box<dyn Provider + Send + Sync>>>> =
-    Arc::new(Mutex::new(HashMap::new()));
+  // Providers are only registered once at startup, afterwards the map is only read.
+  // The providers are cloned out of the map, so the lock is not held while a request is running.
+  pub static ref REGISTERED_PROVIDERS: ProvidersMap = Arc::new(RwLock::new(HashMap::new()));
 }
 
 pub async fn init_providers() {
-  #[cfg(feature = "gestis")]
-  let agent = ureq::AgentBuilder::new()
-    .user_agent(&format!("cabr2/v{}", env!("CARGO_PKG_VERSION")))
-    .build();
+  let _cache_config = match cabr2_config::read_config().await {
+    Ok(config) => config.cache,
+    Err(err) => {
+      log::warn!("reading config failed, using default cache settings: {}", err);
+      Default::default()
+    }
+  };
 
-  let mut _providers = REGISTERED_PROVIDERS.lock().await;
+  #[cfg(any(feature = "gestis", feature = "pubchem"))]
+  let client = reqwest::Client::builder()
+    .user_agent(format!("cabr2/v{}", env!("CARGO_PKG_VERSION")))
+    .build()
+    .unwrap();
+
+  let mut _providers = REGISTERED_PROVIDERS.write().await;
   #[cfg(feature = "gestis")]
-  _providers.insert("gestis", Box::new(crate::gestis::Gestis::new(agent)));
+  _providers.insert(
+    "gestis",
+    cache::wrap(&_cache_config, "gestis", crate::gestis::Gestis::new(client.clone())),
+  );
+  #[cfg(feature = "pubchem")]
+  _providers.insert(
+    "pubchem",
+    cache::wrap(&_cache_config, "pubchem", crate::pubchem::PubChem::new(client)),
+  );
+  #[cfg(feature = "local")]
+  match crate::local::Local::load(crate::local::get_database_path()).await {
+    Ok(Some(local)) => {
+      _providers.insert("local", Arc::new(local));
+    }
+    Ok(None) => log::info!("no local substance database found"),
+    Err(err) => log::error!("loading local substance database failed: {:?}", err),
+  }
+}
+
+/// The language of the substance data follows the language of the ui.
+async fn get_language() -> Language {
+  match cabr2_config::read_config().await {
+    Ok(config) => Language::from_locale(&config.global.language),
+    Err(err) => {
+      log::warn!("reading config failed, using default language: {}", err);
+      Language::German
+    }
+  }
+}
+
+async fn get_provider(provider: &str) -> Result<Arc<dyn Provider + Send + Sync>> {
+  match REGISTERED_PROVIDERS.read().await.get(provider) {
+    Some(provider) => Ok(provider.clone()),
+    None => Err(SearchError::UnknownProvider(provider.into())),
+  }
+}
+
+pub async fn get_provider_mapping() -> ProviderMapping {
+  REGISTERED_PROVIDERS
+    .read()
+    .await
+    .iter()
+    .map(|(id, provider)| (id.to_string(), provider.get_name()))
+    .collect()
 }
 
 pub async fn get_available_providers() -> Result<Vec<ProviderInfo>> {
   let mut providers: Vec<ProviderInfo> = REGISTERED_PROVIDERS
-    .lock()
+    .read()
     .await
     .iter()
     .map(|(key, provider)| ProviderInfo {
@@ -53,11 +109,10 @@ pub async fn get_quick_search_suggestions(
     return Ok(vec![]);
   }
 
-  if let Some(provider) = REGISTERED_PROVIDERS.lock().await.get(&provider.borrow()) {
-    return provider.get_quick_search_suggestions(search_type, pattern);
-  }
-
-  Err(SearchError::UnknownProvider(provider))
+  get_provider(&provider)
+    .await?
+    .get_quick_search_suggestions(get_language().await, search_type, pattern)
+    .await
 }
 
 pub async fn get_search_results(provider: String, arguments: SearchArguments) -> Result<Vec<SearchResponse>> {
@@ -74,17 +129,16 @@ pub async fn get_search_results(provider: String, arguments: SearchArguments) ->
     return Ok(vec![]);
   }
 
-  if let Some(provider) = REGISTERED_PROVIDERS.lock().await.get(&provider.borrow()) {
-    return provider.get_search_results(arguments);
-  }
-
-  Err(SearchError::UnknownProvider(provider))
+  get_provider(&provider)
+    .await?
+    .get_search_results(get_language().await, arguments)
+    .await
 }
 
-pub async fn get_substance_data(provider: String, identifier: String) -> Result<SubstanceData> {
-  if let Some(provider) = REGISTERED_PROVIDERS.lock().await.get(&provider.borrow()) {
-    return provider.get_substance_data(identifier);
-  }
-
-  Err(SearchError::UnknownProvider(provider))
+/// Returns the substance data together with a report how every field was extracted.
+pub async fn get_substance_data(provider: String, identifier: String) -> Result<ParsedSubstanceData> {
+  get_provider(&provider)
+    .await?
+    .get_substance_data(get_language().await, identifier)
+    .await
 }
@@ -2,6 +2,7 @@
 #![allow(clippy::unnecessary_unwrap)]
 #![allow(clippy::upper_case_acronyms)]
 
+mod cache;
 mod error;
 pub mod handler;
 mod types;
@@ -9,5 +10,14 @@ mod types;
 #[cfg(feature = "gestis")]
 mod gestis;
 
+#[cfg(feature = "local")]
+mod local;
+
+#[cfg(feature = "pubchem")]
+mod pubchem;
+
 #[cfg(feature = "tauri_plugin")]
 pub mod plugin;
+
+#[cfg(feature = "webserver")]
+pub mod webserver;
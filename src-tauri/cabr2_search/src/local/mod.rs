@@ -0,0 +1,318 @@
+pub mod types;
+
+use std::{collections::HashSet, io::ErrorKind, path::PathBuf};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::fs;
+
+use cabr2_config::DATA_DIR;
+use cabr2_types::{Data, Source, SubstanceData};
+
+use self::types::LocalSubstance;
+use crate::{
+  error::{Result, SearchError},
+  types::{Language, ParsedSubstanceData, Provider, RawSubstanceData, SearchArguments, SearchResponse, SearchType},
+};
+
+const DATABASE_FILE: &str = "substances.jsonl";
+const SUGGESTION_LIMIT: usize = 10;
+
+/// The database is placed in the data directory next to the templates.
+pub fn get_database_path() -> PathBuf {
+  let mut path = DATA_DIR.clone();
+  path.push(DATABASE_FILE);
+
+  path
+}
+
+/// Substances curated by the institute, stored as one json object per line.
+pub struct Local {
+  path: PathBuf,
+  modified: DateTime<Utc>,
+  substances: Vec<LocalSubstance>,
+}
+
+impl Local {
+  /// Reads the database, returns `None` if the file does not exist.
+  pub async fn load(path: PathBuf) -> Result<Option<Local>> {
+    let contents = match fs::read_to_string(&path).await {
+      Ok(contents) => contents,
+      Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+      Err(err) => return Err(err.into()),
+    };
+    let modified = match fs::metadata(&path).await?.modified() {
+      Ok(modified) => modified.into(),
+      Err(_) => Utc::now(),
+    };
+
+    let substances = parse_database(&contents);
+    log::info!("loaded {} substances from {:?}", substances.len(), path);
+
+    Ok(Some(Local {
+      path,
+      modified,
+      substances,
+    }))
+  }
+
+  fn get_substance(&self, identifier: &str) -> Result<&LocalSubstance> {
+    match self.substances.iter().find(|s| s.id == identifier) {
+      Some(substance) => Ok(substance),
+      None => Err(SearchError::UnknownSubstance(identifier.into())),
+    }
+  }
+}
+
+/// Invalid lines and duplicate ids are skipped, so one typo doesn't hide the whole database.
+fn parse_database(contents: &str) -> Vec<LocalSubstance> {
+  let mut ids = HashSet::new();
+
+  contents
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .filter_map(|(index, line)| match serde_json::from_str::<LocalSubstance>(line) {
+      Ok(substance) if ids.insert(substance.id.clone()) => Some(substance),
+      Ok(substance) => {
+        log::warn!("line {}: duplicate id '{}'", index + 1, substance.id);
+        None
+      }
+      Err(err) => {
+        log::warn!("line {}: invalid substance: {}", index + 1, err);
+        None
+      }
+    })
+    .collect()
+}
+
+/// Returns the values of the substance that are searched for the search type.
+fn search_fields<'a>(substance: &'a LocalSubstance, search_type: &SearchType) -> Vec<&'a str> {
+  let names = Some(substance.name.as_str())
+    .into_iter()
+    .chain(substance.alternative_names.iter().map(|n| n.as_str()));
+  let numbers = Some(substance.id.as_str()).into_iter().chain(substance.cas.as_deref());
+
+  match search_type {
+    SearchType::ChemicalName => names.collect(),
+    SearchType::ChemicalFormula => substance.molecular_formula.as_deref().into_iter().collect(),
+    SearchType::Numbers => numbers.collect(),
+    SearchType::FullText => names
+      .chain(numbers)
+      .chain(substance.molecular_formula.as_deref())
+      .collect(),
+  }
+}
+
+fn matches(value: &str, pattern: &str, exact: bool) -> bool {
+  let value = value.to_lowercase();
+  let pattern = pattern.trim().to_lowercase();
+
+  if exact {
+    value == pattern
+  } else {
+    value.contains(&pattern)
+  }
+}
+
+#[async_trait]
+impl Provider for Local {
+  fn get_name(&self) -> String {
+    "Local".into()
+  }
+
+  async fn get_quick_search_suggestions(
+    &self,
+    _: Language,
+    search_type: SearchType,
+    pattern: String,
+  ) -> Result<Vec<String>> {
+    let pattern = pattern.trim().to_lowercase();
+
+    let mut suggestions: Vec<String> = self
+      .substances
+      .iter()
+      .flat_map(|s| search_fields(s, &search_type))
+      .filter(|value| match search_type {
+        SearchType::FullText => value.to_lowercase().contains(&pattern),
+        _ => value.to_lowercase().starts_with(&pattern),
+      })
+      .map(|value| value.to_string())
+      .collect();
+    suggestions.sort_unstable();
+    suggestions.dedup();
+    suggestions.truncate(SUGGESTION_LIMIT);
+
+    Ok(suggestions)
+  }
+
+  async fn get_search_results(&self, _: Language, arguments: SearchArguments) -> Result<Vec<SearchResponse>> {
+    Ok(
+      self
+        .substances
+        .iter()
+        // all arguments have to match
+        .filter(|s| {
+          arguments.arguments.iter().all(|a| {
+            search_fields(s, &a.search_type)
+              .into_iter()
+              .any(|value| matches(value, &a.pattern, arguments.exact))
+          })
+        })
+        .map(|s| SearchResponse {
+          zvg_number: s.id.clone(),
+          cas_number: s.cas.clone(),
+          name: s.name.clone(),
+        })
+        .collect(),
+    )
+  }
+
+  async fn get_raw_substance_data(&self, _: Language, identifier: String) -> Result<RawSubstanceData> {
+    let substance = self.get_substance(&identifier)?;
+
+    Ok(RawSubstanceData {
+      url: format!("{}#{}", self.path.display(), identifier),
+      fetched: self.modified,
+      data: serde_json::to_value(substance)?,
+    })
+  }
+
+  fn parse_substance_data(&self, _: Language, raw: RawSubstanceData) -> Result<ParsedSubstanceData> {
+    let substance: LocalSubstance = serde_json::from_value(raw.data)?;
+
+    Ok(ParsedSubstanceData::new(SubstanceData {
+      name: Data::new(substance.name),
+      alternative_names: substance.alternative_names,
+      cas: Data::new(substance.cas),
+      molecular_formula: Data::new(substance.molecular_formula),
+      molar_mass: Data::new(substance.molar_mass),
+      melting_point: Data::new(substance.melting_point),
+      boiling_point: Data::new(substance.boiling_point),
+      density: Data::new(substance.density),
+      flash_point: Data::new(substance.flash_point),
+      ignition_temperature: Data::new(substance.ignition_temperature),
+      explosion_limits: Data::new(substance.explosion_limits),
+      water_hazard_class: Data::new(substance.water_hazard_class),
+      lethal_dose: Data::new(substance.lethal_dose),
+      toxicity: Data::new(substance.toxicity),
+      signal_word: Data::new(substance.signal_word),
+      mak: Data::new(substance.mak),
+      reactions: Data::new(substance.reactions),
+      amount: None,
+      h_phrases: Data::new(substance.h_phrases),
+      p_phrases: Data::new(substance.p_phrases),
+      symbols: Data::new(substance.symbols),
+      source: Source {
+        provider: "local".into(),
+        url: raw.url,
+        last_updated: raw.fetched,
+      },
+
+      checked: false,
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::types::SearchArgument;
+
+  use super::*;
+
+  async fn local() -> Local {
+    Local::load("tests/assets/local/substances.jsonl".into())
+      .await
+      .unwrap()
+      .unwrap()
+  }
+
+  #[tokio::test]
+  async fn test_invalid_lines_are_skipped() {
+    // the fixture contains one line with missing name and one duplicate id
+    assert_eq!(local().await.substances.len(), 3);
+  }
+
+  #[tokio::test]
+  async fn test_missing_database() {
+    assert!(Local::load("tests/assets/local/missing.jsonl".into())
+      .await
+      .unwrap()
+      .is_none());
+  }
+
+  #[tokio::test]
+  async fn test_suggestions() {
+    let local = local().await;
+
+    assert_eq!(
+      local
+        .get_quick_search_suggestions(Language::German, SearchType::ChemicalName, "tris".into())
+        .await
+        .unwrap(),
+      vec!["TRIS-Puffer", "Tris(hydroxymethyl)aminomethan-Puffer"]
+    );
+    assert_eq!(
+      local
+        .get_quick_search_suggestions(Language::German, SearchType::Numbers, "77".into())
+        .await
+        .unwrap(),
+      vec!["77-86-1"]
+    );
+  }
+
+  #[tokio::test]
+  async fn test_search_results() {
+    let local = local().await;
+    let search = |exact, arguments: Vec<(SearchType, &str)>| {
+      local.get_search_results(
+        Language::German,
+        SearchArguments {
+          exact,
+          arguments: arguments
+            .into_iter()
+            .map(|(search_type, pattern)| SearchArgument {
+              search_type,
+              pattern: pattern.into(),
+            })
+            .collect(),
+        },
+      )
+    };
+
+    let results = search(false, vec![(SearchType::ChemicalName, "puffer")]).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let results = search(
+      false,
+      vec![(SearchType::ChemicalName, "puffer"), (SearchType::Numbers, "77-86-1")],
+    )
+    .await
+    .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].zvg_number, "tris-buffer");
+
+    let results = search(true, vec![(SearchType::ChemicalName, "puffer")]).await.unwrap();
+    assert!(results.is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_substance_data() {
+    let local = local().await;
+    let data = local
+      .get_substance_data(Language::German, "tris-buffer".into())
+      .await
+      .unwrap()
+      .substance_data;
+
+    assert_eq!(data.name.original_data, "TRIS-Puffer");
+    assert_eq!(data.symbols.original_data, vec!["ghs07"]);
+    assert_eq!(data.source.provider, "local");
+    assert!(data.source.url.ends_with("substances.jsonl#tris-buffer"));
+
+    assert!(matches!(
+      local.get_substance_data(Language::German, "unknown".into()).await,
+      Err(SearchError::UnknownSubstance(_))
+    ));
+  }
+}
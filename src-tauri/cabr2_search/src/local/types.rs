@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+use cabr2_types::{Quantity, Toxicity};
+
+/// One line of the database, only `id` and `name` are required.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalSubstance {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub alternative_names: Vec<String>,
+  pub cas: Option<String>,
+  pub molecular_formula: Option<String>,
+  pub molar_mass: Option<Quantity>,
+  pub melting_point: Option<Quantity>,
+  pub boiling_point: Option<Quantity>,
+  pub density: Option<Quantity>,
+  pub flash_point: Option<Quantity>,
+  pub ignition_temperature: Option<Quantity>,
+  pub explosion_limits: Option<Quantity>,
+  pub water_hazard_class: Option<String>,
+  #[serde(default)]
+  pub h_phrases: Vec<(String, String)>,
+  #[serde(default)]
+  pub p_phrases: Vec<(String, String)>,
+  pub signal_word: Option<String>,
+  #[serde(default)]
+  pub symbols: Vec<String>,
+  pub lethal_dose: Option<Quantity>,
+  #[serde(default)]
+  pub toxicity: Vec<Toxicity>,
+  pub mak: Option<Quantity>,
+  #[serde(default)]
+  pub reactions: Vec<String>,
+}
@@ -1,13 +1,11 @@
-use std::collections::HashMap;
-
 use tauri::{async_runtime, plugin::Plugin, Invoke, Params, Window};
 
-use cabr2_types::SubstanceData;
+use cabr2_types::ProviderMapping;
 
 use crate::{
   error::Result,
   handler::{self, init_providers},
-  types::{ProviderInfo, SearchArguments, SearchResponse, SearchType},
+  types::{ParsedSubstanceData, ProviderInfo, SearchArguments, SearchResponse, SearchType},
 };
 
 #[tauri::command]
@@ -26,7 +24,7 @@ pub async fn search(provider: String, arguments: SearchArguments) -> Result<Vec<
 }
 
 #[tauri::command]
-pub async fn get_substance_data(provider: String, identifier: String) -> Result<SubstanceData> {
+pub async fn get_substance_data(provider: String, identifier: String) -> Result<ParsedSubstanceData> {
   handler::get_substance_data(provider, identifier).await
 }
 
@@ -48,14 +46,8 @@ impl<M: Params> Search<M> {
     }
   }
 
-  pub fn get_provider_mapping(&self) -> HashMap<String, String> {
-    let providers = async_runtime::block_on(handler::REGISTERED_PROVIDERS.lock());
-    let mut mapping = HashMap::new();
-    for (id, provider) in providers.iter() {
-      mapping.insert(id.to_string(), provider.get_name());
-    }
-
-    mapping
+  pub fn get_provider_mapping(&self) -> ProviderMapping {
+    async_runtime::block_on(handler::get_provider_mapping())
   }
 }
 
@@ -0,0 +1,355 @@
+pub mod pug_view;
+pub mod types;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::{Client, StatusCode, Url};
+use serde::de::DeserializeOwned;
+
+use cabr2_types::{ghs, Data, Quantity, Source, SubstanceData};
+
+use self::types::{AutocompleteResponse, CidsResponse, PropertiesResponse, PugViewResponse, SynonymsResponse};
+use crate::{
+  error::{Result, SearchError},
+  types::{
+    Language, ParsedSubstanceData, Provider, RawSubstanceData, SearchArgument, SearchArguments, SearchResponse,
+    SearchType,
+  },
+};
+
+const BASE_URL: &str = "https://pubchem.ncbi.nlm.nih.gov/rest";
+const COMPOUND_URL: &str = "https://pubchem.ncbi.nlm.nih.gov/compound";
+const SUGGESTION_LIMIT: &str = "10";
+// every result needs a title and its synonyms, so the list is kept short
+const SEARCH_RESULT_LIMIT: usize = 25;
+
+pub struct PubChem {
+  client: Client,
+}
+
+impl PubChem {
+  pub fn new(client: Client) -> PubChem {
+    PubChem { client }
+  }
+
+  async fn make_request<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+    match self.client.get(url.clone()).send().await {
+      Ok(response) if response.status().is_success() => {
+        log::debug!("{} - {}", response.status(), &url);
+        Ok(response.json().await?)
+      }
+      Ok(response) => {
+        log::error!("{} - {}", response.status(), &url);
+        match response.status() {
+          StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => Err(SearchError::RateLimit),
+          status => Err(SearchError::RequestError(status.as_u16())),
+        }
+      }
+      Err(err) => {
+        log::error!("error when requesting url: {} -> {:?}", &url, err);
+        Err(SearchError::Logged)
+      }
+    }
+  }
+
+  /// Returns the compound ids matching the argument, PubChem answers with 404 if nothing is found.
+  async fn get_cids(&self, argument: &SearchArgument, exact: bool) -> Result<Vec<u64>> {
+    let pattern = argument.pattern.trim();
+    if let SearchType::Numbers = argument.search_type {
+      // plain numbers are compound ids
+      if let Ok(cid) = pattern.parse::<u64>() {
+        return Ok(vec![cid]);
+      }
+    }
+
+    let url = match argument.search_type {
+      SearchType::ChemicalFormula => url(&["pug", "compound", "fastformula", pattern, "cids", "JSON"]),
+      // CAS numbers are listed as synonyms, so they can be searched like names
+      SearchType::ChemicalName | SearchType::Numbers | SearchType::FullText => {
+        let mut url = url(&["pug", "compound", "name", pattern, "cids", "JSON"]);
+        let name_type = match (&argument.search_type, exact) {
+          (SearchType::FullText, _) | (_, false) => "word",
+          _ => "complete",
+        };
+        url.query_pairs_mut().append_pair("name_type", name_type);
+        url
+      }
+    };
+
+    match self.make_request::<CidsResponse>(url).await {
+      Ok(response) => Ok(response.identifier_list.cids),
+      Err(SearchError::RequestError(404)) => Ok(Vec::new()),
+      Err(err) => Err(err),
+    }
+  }
+}
+
+#[async_trait]
+impl Provider for PubChem {
+  fn get_name(&self) -> String {
+    "PubChem".into()
+  }
+
+  async fn get_quick_search_suggestions(
+    &self,
+    _: Language,
+    search_type: SearchType,
+    pattern: String,
+  ) -> Result<Vec<String>> {
+    if let SearchType::ChemicalFormula = search_type {
+      // there is no autocompletion for formulas, the formula is suggested if it is known
+      let argument = SearchArgument {
+        search_type,
+        pattern: pattern.clone(),
+      };
+      if self.get_cids(&argument, true).await?.is_empty() {
+        return Ok(Vec::new());
+      }
+      return Ok(vec![pattern]);
+    }
+
+    let mut url = url(&["autocomplete", "compound", pattern.trim(), "json"]);
+    url.query_pairs_mut().append_pair("limit", SUGGESTION_LIMIT);
+    let response: AutocompleteResponse = self.make_request(url).await?;
+
+    Ok(response.dictionary_terms.compound)
+  }
+
+  async fn get_search_results(&self, _: Language, arguments: SearchArguments) -> Result<Vec<SearchResponse>> {
+    let mut cids: Option<Vec<u64>> = None;
+
+    // all arguments have to match
+    for argument in arguments.arguments.iter() {
+      let found = self.get_cids(argument, arguments.exact).await?;
+      cids = Some(match cids {
+        Some(cids) => cids.into_iter().filter(|cid| found.contains(cid)).collect(),
+        None => found,
+      });
+    }
+
+    let mut cids = cids.unwrap_or_default();
+    if cids.is_empty() {
+      return Ok(Vec::new());
+    }
+    cids.truncate(SEARCH_RESULT_LIMIT);
+
+    let cid_list = cids.iter().map(|cid| cid.to_string()).collect::<Vec<_>>().join(",");
+    let properties: PropertiesResponse = self
+      .make_request(url(&["pug", "compound", "cid", &cid_list, "property", "Title", "JSON"]))
+      .await?;
+    let synonyms: SynonymsResponse = self
+      .make_request(url(&["pug", "compound", "cid", &cid_list, "synonyms", "JSON"]))
+      .await?;
+
+    Ok(search_responses(properties, synonyms))
+  }
+
+  async fn get_raw_substance_data(&self, _: Language, identifier: String) -> Result<RawSubstanceData> {
+    let url = url(&["pug_view", "data", "compound", identifier.trim(), "JSON"]);
+
+    Ok(RawSubstanceData {
+      data: self.make_request(url.clone()).await?,
+      fetched: Utc::now(),
+      url: url.into(),
+    })
+  }
+
+  fn parse_substance_data(&self, language: Language, raw: RawSubstanceData) -> Result<ParsedSubstanceData> {
+    let json: PugViewResponse = serde_json::from_value(raw.data)?;
+    let record = json.record;
+    log::info!(
+      "extracting data for: {} [{}] ...",
+      record.record_title,
+      record.record_number
+    );
+
+    let ghs = pug_view::get_ghs_classification(&record);
+
+    Ok(ParsedSubstanceData::new(SubstanceData {
+      name: Data::new(record.record_title.clone()),
+      alternative_names: Vec::new(),
+      cas: Data::new(pug_view::get_cas(&record)),
+      molecular_formula: Data::new(pug_view::get_molecular_formula(&record)),
+      molar_mass: Data::new(pug_view::get_molar_mass(&record).as_deref().map(Quantity::parse)),
+      melting_point: Data::new(pug_view::get_melting_point(&record).as_deref().map(Quantity::parse)),
+      boiling_point: Data::new(pug_view::get_boiling_point(&record).as_deref().map(Quantity::parse)),
+      density: Data::new(None),
+      flash_point: Data::new(pug_view::get_flash_point(&record).as_deref().map(Quantity::parse)),
+      ignition_temperature: Data::new(
+        pug_view::get_ignition_temperature(&record)
+          .as_deref()
+          .map(Quantity::parse),
+      ),
+      explosion_limits: Data::new(None),
+      // german classification, not available
+      water_hazard_class: Data::new(None),
+      lethal_dose: Data::new(None),
+      toxicity: Data::new(Vec::new()),
+      signal_word: Data::new(ghs.signal_word),
+      mak: Data::new(None),
+      reactions: Data::new(Vec::new()),
+      amount: None,
+      h_phrases: Data::new(localize_statements(ghs.h_phrases, language)),
+      p_phrases: Data::new(localize_statements(ghs.p_phrases, language)),
+      symbols: Data::new(ghs.symbols),
+      source: Source {
+        provider: "pubchem".into(),
+        url: format!("{}/{}", COMPOUND_URL, record.record_number),
+        last_updated: raw.fetched,
+      },
+
+      checked: false,
+    }))
+  }
+}
+
+/// PubChem only has english hazard statements and no texts for precautionary statements,
+/// the official texts are used where available.
+fn localize_statements(statements: Vec<(String, String)>, language: Language) -> Vec<(String, String)> {
+  statements
+    .into_iter()
+    .map(|(code, text)| {
+      let text = ghs::get_statement(&code, language).unwrap_or(text);
+      (code, text)
+    })
+    .collect()
+}
+
+/// Builds an url below `BASE_URL`, the segments are escaped.
+fn url(segments: &[&str]) -> Url {
+  let mut url = Url::parse(BASE_URL).unwrap();
+  url.path_segments_mut().unwrap().extend(segments);
+  url
+}
+
+/// Combines titles and synonyms, the first synonym that looks like a CAS number is used.
+fn search_responses(properties: PropertiesResponse, synonyms: SynonymsResponse) -> Vec<SearchResponse> {
+  let synonyms = synonyms.information_list.information;
+
+  properties
+    .property_table
+    .properties
+    .into_iter()
+    .map(|p| {
+      let cid = p.cid;
+      SearchResponse {
+        cas_number: synonyms
+          .iter()
+          .find(|s| s.cid == cid)
+          .and_then(|s| s.synonyms.iter().find(|s| is_cas_number(s)).cloned()),
+        name: p.title.unwrap_or_else(|| cid.to_string()),
+        zvg_number: cid.to_string(),
+      }
+    })
+    .collect()
+}
+
+/// Checks the format `2-7 digits - 2 digits - 1 digit` and the check digit.
+fn is_cas_number(value: &str) -> bool {
+  let parts: Vec<&str> = value.split('-').collect();
+  if parts.len() != 3 || !parts.iter().all(|p| p.chars().all(|c| c.is_ascii_digit())) {
+    return false;
+  }
+  if !(2..=7).contains(&parts[0].len()) || parts[1].len() != 2 || parts[2].len() != 1 {
+    return false;
+  }
+
+  let digits: Vec<u32> = parts[0]
+    .chars()
+    .chain(parts[1].chars())
+    .map(|c| c.to_digit(10).unwrap())
+    .collect();
+  let checksum: u32 = digits.iter().rev().enumerate().map(|(i, d)| (i as u32 + 1) * d).sum();
+
+  checksum % 10 == parts[2].parse::<u32>().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{fs::File, io::BufReader};
+
+  use serde::de::DeserializeOwned;
+
+  use super::*;
+  use crate::types::FieldStatus;
+
+  fn load_json<T: DeserializeOwned>(name: &str) -> T {
+    let file = File::open(format!("tests/assets/pubchem/{}.json", name)).unwrap();
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader).unwrap()
+  }
+
+  fn pubchem() -> PubChem {
+    PubChem::new(Client::builder().user_agent("cabr2/testing").build().unwrap())
+  }
+
+  #[test]
+  fn test_parsing_ethanol() {
+    let raw = RawSubstanceData {
+      url: url(&["pug_view", "data", "compound", "702", "JSON"]).into(),
+      fetched: Utc::now(),
+      data: load_json("ethanol"),
+    };
+    let parsed = pubchem().parse_substance_data(Language::English, raw).unwrap();
+    let data = parsed.substance_data;
+
+    assert_eq!(data.name.original_data, "Ethanol");
+    assert_eq!(data.cas.original_data, Some("64-17-5".into()));
+    assert_eq!(data.molecular_formula.original_data, Some("C2H6O".into()));
+    assert_eq!(data.molar_mass.original_data, Some("46.07 g/mol".into()));
+    assert_eq!(data.melting_point.original_data, Some("-114.1 °C".into()));
+    assert_eq!(data.boiling_point.original_data, Some("78.2 °C".into()));
+    assert_eq!(data.signal_word.original_data, Some("Danger".into()));
+    assert_eq!(data.symbols.original_data, vec!["ghs02", "ghs07"]);
+    assert_eq!(
+      data.h_phrases.original_data,
+      vec![
+        ("H225".into(), "Highly flammable liquid and vapour.".into()),
+        ("H319".into(), "Causes serious eye irritation.".into()),
+      ]
+    );
+    assert_eq!(data.p_phrases.original_data.len(), 14);
+    assert_eq!(data.p_phrases.original_data[8].0, "P303+P361+P353");
+    assert!(data.p_phrases.original_data[8].1.starts_with("IF ON SKIN (or hair):"));
+    assert_eq!(data.source.url, "https://pubchem.ncbi.nlm.nih.gov/compound/702");
+    assert_eq!(parsed.report["boilingPoint"], FieldStatus::Found);
+    assert_eq!(parsed.report["waterHazardClass"], FieldStatus::Missing);
+  }
+
+  #[test]
+  fn test_search_responses() {
+    let responses = search_responses(load_json("properties"), load_json("synonyms"));
+
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].zvg_number, "702");
+    assert_eq!(responses[0].name, "Ethanol");
+    assert_eq!(responses[0].cas_number, Some("64-17-5".into()));
+    assert_eq!(responses[1].cas_number, Some("1516-08-1".into()));
+  }
+
+  #[test]
+  fn test_response_types() {
+    let suggestions: AutocompleteResponse = load_json("autocomplete");
+    assert_eq!(suggestions.dictionary_terms.compound[0], "ethanol");
+
+    let cids: CidsResponse = load_json("cids");
+    assert_eq!(cids.identifier_list.cids, vec![702]);
+  }
+
+  #[test]
+  fn test_is_cas_number() {
+    assert!(is_cas_number("64-17-5"));
+    assert!(is_cas_number("7732-18-5"));
+    assert!(!is_cas_number("64-17-4"));
+    assert!(!is_cas_number("200-578-6"));
+    assert!(!is_cas_number("ethanol"));
+  }
+
+  #[test]
+  fn test_url_escaping() {
+    assert_eq!(
+      url(&["pug", "compound", "name", "acetic acid/water", "cids", "JSON"]).as_str(),
+      "https://pubchem.ncbi.nlm.nih.gov/rest/pug/compound/name/acetic%20acid%2Fwater/cids/JSON"
+    );
+  }
+}
@@ -0,0 +1,236 @@
+use super::types::{Information, InformationValue, Record, Section};
+
+/* #region helpers */
+
+/// Follows the TOC headings of `path` through the nested sections.
+fn find_section<'a>(sections: &'a [Section], path: &[&str]) -> Option<&'a Section> {
+  let (heading, rest) = path.split_first()?;
+  let section = sections.iter().find(|s| s.toc_heading == *heading)?;
+
+  if rest.is_empty() {
+    Some(section)
+  } else {
+    find_section(&section.section, rest)
+  }
+}
+
+fn information<'a>(record: &'a Record, path: &[&str]) -> &'a [Information] {
+  match find_section(&record.section, path) {
+    Some(section) => &section.information,
+    None => &[],
+  }
+}
+
+impl InformationValue {
+  /// Returns the values as text, numbers are joined with their unit.
+  fn strings(&self) -> Vec<String> {
+    if !self.string_with_markup.is_empty() {
+      return self
+        .string_with_markup
+        .iter()
+        .map(|s| match &self.unit {
+          Some(unit) => format!("{} {}", s.string.trim(), unit),
+          None => s.string.trim().to_string(),
+        })
+        .collect();
+    }
+
+    self
+      .number
+      .iter()
+      .map(|n| match &self.unit {
+        Some(unit) => format!("{} {}", n, unit),
+        None => n.to_string(),
+      })
+      .collect()
+  }
+}
+
+fn first_string(record: &Record, path: &[&str]) -> Option<String> {
+  information(record, path)
+    .iter()
+    .flat_map(|i| i.value.strings())
+    .find(|s| !s.is_empty())
+}
+
+/* #endregion */
+
+pub fn get_cas(record: &Record) -> Option<String> {
+  first_string(record, &["Names and Identifiers", "Other Identifiers", "CAS"])
+}
+
+pub fn get_molecular_formula(record: &Record) -> Option<String> {
+  first_string(record, &["Names and Identifiers", "Molecular Formula"])
+}
+
+pub fn get_molar_mass(record: &Record) -> Option<String> {
+  first_string(
+    record,
+    &[
+      "Chemical and Physical Properties",
+      "Computed Properties",
+      "Molecular Weight",
+    ],
+  )
+}
+
+pub fn get_melting_point(record: &Record) -> Option<String> {
+  get_temperature(record, "Melting Point")
+}
+
+pub fn get_boiling_point(record: &Record) -> Option<String> {
+  get_temperature(record, "Boiling Point")
+}
+
+pub fn get_flash_point(record: &Record) -> Option<String> {
+  get_temperature(record, "Flash Point")
+}
+
+pub fn get_ignition_temperature(record: &Record) -> Option<String> {
+  get_temperature(record, "Autoignition Temperature")
+}
+
+/// PubChem lists the values of all sources, the first one in degree celsius is used.
+fn get_temperature(record: &Record, heading: &str) -> Option<String> {
+  information(
+    record,
+    &["Chemical and Physical Properties", "Experimental Properties", heading],
+  )
+  .iter()
+  .flat_map(|i| i.value.strings())
+  .find(|s| s.contains("°C"))
+}
+
+/* #region GHS classification */
+
+#[derive(Debug, Default)]
+pub struct GhsClassification {
+  pub h_phrases: Vec<(String, String)>,
+  pub p_phrases: Vec<(String, String)>,
+  pub signal_word: Option<String>,
+  pub symbols: Vec<String>,
+}
+
+/// Extracts the classification of the first source, mixing sources could produce contradicting data.
+pub fn get_ghs_classification(record: &Record) -> GhsClassification {
+  let information = information(
+    record,
+    &["Safety and Hazards", "Hazards Identification", "GHS Classification"],
+  );
+  let mut classification = GhsClassification::default();
+
+  let reference = match information.first() {
+    Some(first) => first.reference_number,
+    None => return classification,
+  };
+
+  for info in information.iter().filter(|i| i.reference_number == reference) {
+    match info.name.as_deref() {
+      Some("Pictogram(s)") => {
+        classification.symbols = info
+          .value
+          .string_with_markup
+          .iter()
+          .flat_map(|s| s.markup.iter())
+          .filter(|m| m.markup_type.as_deref() == Some("Icon"))
+          .filter_map(|m| m.url.as_deref())
+          .filter_map(symbol_from_url)
+          .collect();
+      }
+      Some("Signal") => classification.signal_word = info.value.strings().into_iter().next(),
+      Some("GHS Hazard Statements") => {
+        classification.h_phrases = info
+          .value
+          .string_with_markup
+          .iter()
+          .filter_map(|s| parse_h_phrase(&s.string))
+          .collect();
+      }
+      Some("Precautionary Statement Codes") => {
+        classification.p_phrases = info
+          .value
+          .string_with_markup
+          .iter()
+          .flat_map(|s| parse_p_codes(&s.string))
+          .map(|code| (code, String::new()))
+          .collect();
+      }
+      _ => (),
+    }
+  }
+
+  classification
+}
+
+/// `https://pubchem.ncbi.nlm.nih.gov/images/ghs/GHS02.svg` -> `ghs02`
+fn symbol_from_url(url: &str) -> Option<String> {
+  let file = url.rsplit('/').next()?;
+  let name = file.split('.').next()?.to_lowercase();
+
+  if name.starts_with("ghs") {
+    Some(name)
+  } else {
+    None
+  }
+}
+
+/// `H225 (99.82%): Highly Flammable liquid and vapor [Danger Flammable liquids]`
+/// -> `("H225", "Highly Flammable liquid and vapor")`
+fn parse_h_phrase(statement: &str) -> Option<(String, String)> {
+  let mut parts = statement.splitn(2, ':');
+  // drops the percentage of notifications
+  let code = parts.next()?.split_whitespace().next()?;
+  if !code.starts_with(&['H', 'E'][..]) {
+    return None;
+  }
+
+  let text = parts.next().unwrap_or_default();
+  let text = match text.rfind(" [") {
+    Some(index) => &text[..index],
+    None => text,
+  };
+
+  Some((code.into(), text.trim().into()))
+}
+
+/// `P210, P233, P403+P235, and P501` -> `["P210", "P233", "P403+P235", "P501"]`
+fn parse_p_codes(codes: &str) -> Vec<String> {
+  codes
+    .split(',')
+    .map(|c| c.trim().trim_start_matches("and ").trim())
+    .filter(|c| c.starts_with('P') && c[1..].starts_with(|c: char| c.is_ascii_digit()))
+    .map(|c| c.into())
+    .collect()
+}
+
+/* #endregion */
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_h_phrase() {
+    assert_eq!(
+      parse_h_phrase("H319 (87.42%): Causes serious eye irritation [Warning Serious eye damage/eye irritation]"),
+      Some(("H319".into(), "Causes serious eye irritation".into()))
+    );
+    assert_eq!(
+      parse_h_phrase("H225: Highly Flammable liquid and vapor"),
+      Some(("H225".into(), "Highly Flammable liquid and vapor".into()))
+    );
+    assert_eq!(parse_h_phrase("Not Classified"), None);
+  }
+
+  #[test]
+  fn test_parse_p_codes() {
+    assert_eq!(
+      parse_p_codes("P210, P305+P351+P338, and P501"),
+      vec!["P210", "P305+P351+P338", "P501"]
+    );
+    assert!(
+      parse_p_codes("(The corresponding statement to each P-code can be found at the GHS Classification page.)")
+        .is_empty()
+    );
+  }
+}
@@ -0,0 +1,135 @@
+use serde::Deserialize;
+
+/* #region PUG-REST */
+
+#[derive(Debug, Deserialize)]
+pub struct AutocompleteResponse {
+  #[serde(default)]
+  pub dictionary_terms: DictionaryTerms,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DictionaryTerms {
+  #[serde(default)]
+  pub compound: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CidsResponse {
+  pub identifier_list: IdentifierList,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdentifierList {
+  #[serde(rename = "CID")]
+  pub cids: Vec<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PropertiesResponse {
+  pub property_table: PropertyTable,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PropertyTable {
+  pub properties: Vec<Properties>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Properties {
+  #[serde(rename = "CID")]
+  pub cid: u64,
+  pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SynonymsResponse {
+  pub information_list: InformationList,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InformationList {
+  pub information: Vec<Synonyms>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Synonyms {
+  #[serde(rename = "CID")]
+  pub cid: u64,
+  #[serde(default, rename = "Synonym")]
+  pub synonyms: Vec<String>,
+}
+
+/* #endregion */
+
+/* #region PUG-View */
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PugViewResponse {
+  pub record: Record,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Record {
+  pub record_number: u64,
+  pub record_title: String,
+  #[serde(default)]
+  pub section: Vec<Section>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Section {
+  #[serde(rename = "TOCHeading")]
+  pub toc_heading: String,
+  #[serde(default)]
+  pub section: Vec<Section>,
+  #[serde(default)]
+  pub information: Vec<Information>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Information {
+  pub reference_number: Option<u64>,
+  pub name: Option<String>,
+  pub value: InformationValue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InformationValue {
+  #[serde(default)]
+  pub string_with_markup: Vec<StringWithMarkup>,
+  #[serde(default)]
+  pub number: Vec<f64>,
+  pub unit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StringWithMarkup {
+  pub string: String,
+  #[serde(default)]
+  pub markup: Vec<Markup>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Markup {
+  #[serde(rename = "URL")]
+  pub url: Option<String>,
+  #[serde(rename = "Type")]
+  pub markup_type: Option<String>,
+}
+
+/* #endregion */
@@ -1,14 +1,111 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
+pub use cabr2_types::Language;
 use cabr2_types::SubstanceData;
 
 use super::error::Result;
 
+/// All calls get the language of the user, providers without localized data can ignore it.
+#[async_trait]
 pub trait Provider {
   fn get_name(&self) -> String;
-  fn get_quick_search_suggestions(&self, search_type: SearchType, pattern: String) -> Result<Vec<String>>;
-  fn get_search_results(&self, arguments: SearchArguments) -> Result<Vec<SearchResponse>>;
-  fn get_substance_data(&self, identifier: String) -> Result<SubstanceData>;
+  async fn get_quick_search_suggestions(
+    &self,
+    language: Language,
+    search_type: SearchType,
+    pattern: String,
+  ) -> Result<Vec<String>>;
+  async fn get_search_results(&self, language: Language, arguments: SearchArguments) -> Result<Vec<SearchResponse>>;
+
+  /// Fetches the unprocessed data of a substance as it was returned by the source.
+  async fn get_raw_substance_data(&self, language: Language, identifier: String) -> Result<RawSubstanceData>;
+
+  /// Extracts the `SubstanceData` from the data returned by `get_raw_substance_data`.
+  fn parse_substance_data(&self, language: Language, raw: RawSubstanceData) -> Result<ParsedSubstanceData>;
+
+  async fn get_substance_data(&self, language: Language, identifier: String) -> Result<ParsedSubstanceData> {
+    let raw = self.get_raw_substance_data(language, identifier).await?;
+    self.parse_substance_data(language, raw)
+  }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RawSubstanceData {
+  pub url: String,
+  pub fetched: DateTime<Utc>,
+  pub data: Value,
+}
+
+/// How a field of the substance data was extracted from the source.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "status")]
+pub enum FieldStatus {
+  Found,
+  /// The source has no information about the field.
+  Missing,
+  /// The source states that there is no value, e.g. for substances that are not hazardous to water.
+  Empty,
+  /// The source lists several values, the first one is used and should be checked.
+  Multiple,
+  /// The value could not be extracted, probably the layout of the source has changed.
+  #[serde(rename_all = "camelCase")]
+  ParseError {
+    message: String,
+    chapter: Option<String>,
+    subchapter: Option<String>,
+  },
+}
+
+/// The status of the extracted fields, the keys are the names of the fields in `SubstanceData`.
+pub type ParseReport = BTreeMap<&'static str, FieldStatus>;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedSubstanceData {
+  pub substance_data: SubstanceData,
+  /// Lets the ui highlight the fields that need to be checked manually.
+  pub report: ParseReport,
+}
+
+impl ParsedSubstanceData {
+  /// For providers without details about the extraction every field is either found or missing.
+  pub fn new(substance_data: SubstanceData) -> Self {
+    let data = &substance_data;
+    let fields = [
+      ("cas", data.cas.original_data.is_some()),
+      ("molecularFormula", data.molecular_formula.original_data.is_some()),
+      ("molarMass", data.molar_mass.original_data.is_some()),
+      ("meltingPoint", data.melting_point.original_data.is_some()),
+      ("boilingPoint", data.boiling_point.original_data.is_some()),
+      ("density", data.density.original_data.is_some()),
+      ("flashPoint", data.flash_point.original_data.is_some()),
+      ("ignitionTemperature", data.ignition_temperature.original_data.is_some()),
+      ("explosionLimits", data.explosion_limits.original_data.is_some()),
+      ("waterHazardClass", data.water_hazard_class.original_data.is_some()),
+      ("hPhrases", !data.h_phrases.original_data.is_empty()),
+      ("pPhrases", !data.p_phrases.original_data.is_empty()),
+      ("signalWord", data.signal_word.original_data.is_some()),
+      ("symbols", !data.symbols.original_data.is_empty()),
+      ("lethalDose", data.lethal_dose.original_data.is_some()),
+      ("toxicity", !data.toxicity.original_data.is_empty()),
+      ("mak", data.mak.original_data.is_some()),
+      ("reactions", !data.reactions.original_data.is_empty()),
+    ];
+    let report = fields
+      .iter()
+      .map(|(field, found)| match found {
+        true => (*field, FieldStatus::Found),
+        false => (*field, FieldStatus::Missing),
+      })
+      .collect();
+
+    ParsedSubstanceData { substance_data, report }
+  }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -26,14 +123,14 @@ pub enum SearchType {
   FullText,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SearchArgument {
   pub search_type: SearchType,
   pub pattern: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct SearchArguments {
   #[serde(default)]
   pub exact: bool,
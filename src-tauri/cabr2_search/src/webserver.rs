@@ -0,0 +1,70 @@
+use serde::Deserialize;
+use warp::{Filter, Rejection, Reply};
+
+use cabr2_types::webserver::into_reply;
+
+use crate::{
+  handler,
+  types::{SearchArguments, SearchType},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchSuggestionsArguments {
+  provider: String,
+  pattern: String,
+  search_type: SearchType,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchRequestArguments {
+  provider: String,
+  arguments: SearchArguments,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubstanceDataArguments {
+  provider: String,
+  identifier: String,
+}
+
+/// Returns all routes of this crate, they are available under `/search/<command>`.
+pub fn get_routes() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+  let get_available_providers = warp::path!("get_available_providers")
+    .and(warp::get())
+    .and_then(|| async { Ok::<_, Rejection>(into_reply(handler::get_available_providers().await)) });
+
+  let search_suggestions = warp::path!("search_suggestions")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: SearchSuggestionsArguments| async move {
+      Ok::<_, Rejection>(into_reply(
+        handler::get_quick_search_suggestions(args.provider, args.search_type, args.pattern).await,
+      ))
+    });
+
+  let search = warp::path!("search")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: SearchRequestArguments| async move {
+      Ok::<_, Rejection>(into_reply(
+        handler::get_search_results(args.provider, args.arguments).await,
+      ))
+    });
+
+  let get_substance_data = warp::path!("get_substance_data")
+    .and(warp::post())
+    .and(warp::body::json())
+    .and_then(|args: SubstanceDataArguments| async move {
+      Ok::<_, Rejection>(into_reply(
+        handler::get_substance_data(args.provider, args.identifier).await,
+      ))
+    });
+
+  warp::path("search").and(
+    get_available_providers
+      .or(search_suggestions)
+      .or(search)
+      .or(get_substance_data),
+  )
+}
@@ -0,0 +1,234 @@
+use thiserror::Error;
+
+use crate::{Amount, Quantity, Unit};
+
+/// Converted values are rounded to this many significant digits, more can't be weighed or measured anyway.
+const SIGNIFICANT_DIGITS: i32 = 4;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ConversionError {
+  #[error("invalid amount: {0}")]
+  InvalidValue(String),
+
+  #[error("{0} can't be converted into {1}")]
+  Incompatible(String, String),
+
+  #[error("molar mass is missing or invalid")]
+  MissingMolarMass,
+
+  #[error("density is missing or invalid")]
+  MissingDensity,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+  Mass,
+  Volume,
+  Substance,
+}
+
+/// Splits a unit into what it measures, whether it is a concentration (per litre of solution)
+/// and the factor into gram, millilitre or mol.
+fn describe(unit: &Unit) -> Option<(Kind, bool, f64)> {
+  use Unit::*;
+  Some(match unit {
+    Gram => (Kind::Mass, false, 1.0),
+    Milligram => (Kind::Mass, false, 1e-3),
+    Microgram => (Kind::Mass, false, 1e-6),
+    Litre => (Kind::Volume, false, 1e3),
+    Milliliter => (Kind::Volume, false, 1.0),
+    Microliter => (Kind::Volume, false, 1e-3),
+    Mol => (Kind::Substance, false, 1.0),
+    Millimol => (Kind::Substance, false, 1e-3),
+
+    SolutionGram => (Kind::Mass, true, 1.0),
+    SolutionMilligram | MilligramPerLiter => (Kind::Mass, true, 1e-3),
+    // 1 % (v/v) are 10 ml per litre
+    SolutionRelative => (Kind::Volume, true, 10.0),
+    SolutionMol => (Kind::Substance, true, 1.0),
+    SolutionMillimol => (Kind::Substance, true, 1e-3),
+    SolutionMicromol => (Kind::Substance, true, 1e-6),
+
+    _ => return None,
+  })
+}
+
+/// The molar mass in g/mol, values without unit are expected to be in g/mol.
+fn molar_mass(quantity: Option<&Quantity>) -> Result<f64, ConversionError> {
+  let quantity = quantity.ok_or(ConversionError::MissingMolarMass)?;
+  match (quantity.unit(), quantity.number()) {
+    (None, Some(value)) | (Some(Unit::GramPerMol), Some(value)) if is_positive(value) => Ok(value),
+    _ => Err(ConversionError::MissingMolarMass),
+  }
+}
+
+/// The density in g/ml, values without unit are expected to be in g/cm³.
+fn density(quantity: Option<&Quantity>) -> Result<f64, ConversionError> {
+  let quantity = quantity.ok_or(ConversionError::MissingDensity)?;
+  let factor = match quantity.unit() {
+    None => 1.0,
+    Some(Unit::Custom(symbol)) => match symbol.as_str() {
+      "g/cm³" | "g/cm3" | "g/ml" | "g/mL" | "kg/l" | "kg/L" => 1.0,
+      "kg/m³" | "kg/m3" | "g/l" | "g/L" => 1e-3,
+      _ => return Err(ConversionError::MissingDensity),
+    },
+    Some(Unit::SolutionGram) => 1e-3,
+    _ => return Err(ConversionError::MissingDensity),
+  };
+  match quantity.number() {
+    Some(value) if is_positive(value) => Ok(value * factor),
+    _ => Err(ConversionError::MissingDensity),
+  }
+}
+
+/// Amounts and properties must be finite and greater than zero, anything else can't be weighed or measured.
+#[inline]
+fn is_positive(value: f64) -> bool {
+  value.is_finite() && value > 0.0
+}
+
+/// Converts an amount into another unit, e.g. the mmol of a recipe into the gram to weigh.
+///
+/// Mass, volume and amount of substance are converted with the molar mass and the density of the substance,
+/// those are only needed if the conversion requires them. Concentrations can only be converted into concentrations.
+pub fn convert(
+  amount: &Amount,
+  unit: &Unit,
+  molar_mass: Option<&Quantity>,
+  density: Option<&Quantity>,
+) -> Result<Amount, ConversionError> {
+  let incompatible = || ConversionError::Incompatible(amount.unit.clone().into(), unit.clone().into());
+  let invalid = || ConversionError::InvalidValue(amount.value.clone());
+  let value: f64 = amount.value.trim().replace(',', ".").parse().map_err(|_| invalid())?;
+  if !is_positive(value) {
+    return Err(invalid());
+  }
+
+  let (from_kind, from_solution, from_factor) = describe(&amount.unit).ok_or_else(incompatible)?;
+  let (to_kind, to_solution, to_factor) = describe(unit).ok_or_else(incompatible)?;
+  if from_solution != to_solution {
+    return Err(incompatible());
+  }
+
+  let mut value = value * from_factor;
+  if from_kind != to_kind {
+    // every conversion between kinds goes through the mass
+    value = match from_kind {
+      Kind::Mass => value,
+      Kind::Volume => value * self::density(density)?,
+      Kind::Substance => value * self::molar_mass(molar_mass)?,
+    };
+    value = match to_kind {
+      Kind::Mass => value,
+      Kind::Volume => value / self::density(density)?,
+      Kind::Substance => value / self::molar_mass(molar_mass)?,
+    };
+  }
+
+  // e.g. an overflow of a huge amount
+  let value = value / to_factor;
+  if !value.is_finite() {
+    return Err(invalid());
+  }
+
+  Ok(Amount {
+    value: format_value(value),
+    unit: unit.clone(),
+  })
+}
+
+fn format_value(value: f64) -> String {
+  if value == 0.0 {
+    return "0".into();
+  }
+
+  let decimals = (SIGNIFICANT_DIGITS - 1 - value.abs().log10().floor() as i32).max(0) as usize;
+  let text = format!("{:.*}", decimals, value);
+  if text.contains('.') {
+    text.trim_end_matches('0').trim_end_matches('.').into()
+  } else {
+    text
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn amount(value: &str, unit: Unit) -> Amount {
+    Amount {
+      value: value.into(),
+      unit,
+    }
+  }
+
+  fn ethanol() -> (Quantity, Quantity) {
+    (Quantity::parse("46,07 g/mol"), Quantity::parse("0,79 g/cm³"))
+  }
+
+  #[test]
+  fn test_same_kind() {
+    let converted = convert(&amount("1,5", Unit::Litre), &Unit::Milliliter, None, None).unwrap();
+    assert_eq!(converted.value, "1500");
+    assert_eq!(converted.unit, Unit::Milliliter);
+
+    let converted = convert(&amount("250", Unit::Milligram), &Unit::Gram, None, None).unwrap();
+    assert_eq!(converted.value, "0.25");
+  }
+
+  #[test]
+  fn test_with_properties() {
+    let (molar_mass, density) = ethanol();
+    let molar_mass = Some(&molar_mass);
+    let density = Some(&density);
+
+    let grams = convert(&amount("10", Unit::Millimol), &Unit::Gram, molar_mass, None).unwrap();
+    assert_eq!(grams.value, "0.4607");
+    let millilitres = convert(&amount("1", Unit::Mol), &Unit::Milliliter, molar_mass, density).unwrap();
+    assert_eq!(millilitres.value, "58.32");
+    let mol = convert(&amount("79", Unit::Gram), &Unit::Mol, molar_mass, None).unwrap();
+    assert_eq!(mol.value, "1.715");
+
+    let concentration = convert(&amount("2", Unit::SolutionMol), &Unit::SolutionGram, molar_mass, None).unwrap();
+    assert_eq!(concentration.value, "92.14");
+  }
+
+  #[test]
+  fn test_errors() {
+    let (_, density) = ethanol();
+    assert_eq!(
+      convert(&amount("5", Unit::Millimol), &Unit::Gram, None, Some(&density)).unwrap_err(),
+      ConversionError::MissingMolarMass
+    );
+    assert_eq!(
+      convert(&amount("5", Unit::Gram), &Unit::Milliliter, None, None).unwrap_err(),
+      ConversionError::MissingDensity
+    );
+    assert_eq!(
+      convert(&amount("5", Unit::Gram), &Unit::SolutionGram, None, None).unwrap_err(),
+      ConversionError::Incompatible("g".into(), "g/l".into())
+    );
+    assert_eq!(
+      convert(&amount("fünf", Unit::Gram), &Unit::Milligram, None, None).unwrap_err(),
+      ConversionError::InvalidValue("fünf".into())
+    );
+    for value in ["NaN", "inf", "-5", "0", "1e308"].iter() {
+      assert_eq!(
+        convert(&amount(value, Unit::Litre), &Unit::Microliter, None, None).unwrap_err(),
+        ConversionError::InvalidValue(value.to_string())
+      );
+    }
+
+    let (molar_mass, _) = ethanol();
+    assert_eq!(
+      convert(
+        &amount("5", Unit::Mol),
+        &Unit::Milliliter,
+        Some(&molar_mass),
+        Some(&Quantity::parse("0 g/cm³"))
+      )
+      .unwrap_err(),
+      ConversionError::MissingDensity
+    );
+  }
+}
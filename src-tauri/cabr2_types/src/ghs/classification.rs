@@ -0,0 +1,500 @@
+use serde::{Deserialize, Serialize};
+
+use super::{get_statement, split_code, validation::required_labels};
+use crate::{
+  conversion::{self, ConversionError},
+  Amount, Language, Quantity, SubstanceData, Unit,
+};
+
+/// The acute toxicity estimates of the statements and the limits of the ATE of the mixture for each statement.
+type AcuteToxicity = (&'static [(&'static str, f64)], &'static [(f64, &'static str)]);
+
+/// Conversion values of the acute toxicity categories (CLP Annex I, table 3.1.2) for oral (mg/kg),
+/// dermal (mg/kg) and inhalation of vapours (mg/l). H300, H310 and H330 use the value of category 1.
+const ACUTE_TOXICITY: [AcuteToxicity; 3] = [
+  (
+    &[("H300", 0.5), ("H301", 100.0), ("H302", 500.0)],
+    &[(50.0, "H300"), (300.0, "H301"), (2000.0, "H302")],
+  ),
+  (
+    &[("H310", 5.0), ("H311", 300.0), ("H312", 1100.0)],
+    &[(200.0, "H310"), (1000.0, "H311"), (2000.0, "H312")],
+  ),
+  (
+    &[("H330", 0.05), ("H331", 3.0), ("H332", 11.0)],
+    &[(2.0, "H330"), (10.0, "H331"), (20.0, "H332")],
+  ),
+];
+
+/// Generic concentration limits in percent, the mixture gets the second statement
+/// if one component with the first statement reaches the limit.
+const CONCENTRATION_LIMITS: &[(&str, f64, &str)] = &[
+  ("H317", 1.0, "H317"),
+  ("H334", 1.0, "H334"),
+  ("H340", 0.1, "H340"),
+  ("H341", 1.0, "H341"),
+  ("H350", 0.1, "H350"),
+  ("H350i", 0.1, "H350i"),
+  ("H351", 1.0, "H351"),
+  ("H360", 0.3, "H360"),
+  ("H360F", 0.3, "H360F"),
+  ("H360D", 0.3, "H360D"),
+  ("H360FD", 0.3, "H360FD"),
+  ("H360Fd", 0.3, "H360Fd"),
+  ("H360Df", 0.3, "H360Df"),
+  ("H361", 3.0, "H361"),
+  ("H361f", 3.0, "H361f"),
+  ("H361d", 3.0, "H361d"),
+  ("H361fd", 3.0, "H361fd"),
+  ("H362", 0.3, "H362"),
+  ("H370", 10.0, "H370"),
+  ("H370", 1.0, "H371"),
+  ("H371", 10.0, "H371"),
+  ("H372", 10.0, "H372"),
+  ("H372", 1.0, "H373"),
+  ("H373", 10.0, "H373"),
+];
+
+/// Statements that are derived by the rules above, all others (e.g. physical hazards) can't be derived
+/// from the concentration and are kept as they are.
+const CLASSIFIED: &[&str] = &[
+  "H300", "H301", "H302", "H304", "H310", "H311", "H312", "H314", "H315", "H317", "H318", "H319", "H330", "H331",
+  "H332", "H334", "H335", "H336", "H340", "H341", "H350", "H350i", "H351", "H360", "H360F", "H360D", "H360FD",
+  "H360Fd", "H360Df", "H361", "H361f", "H361d", "H361fd", "H362", "H370", "H371", "H372", "H373", "H400", "H410",
+  "H411", "H412", "H413",
+];
+
+/// Statements that are not labelled if the first one is present.
+const SUPERSEDED: &[(&str, &str)] = &[
+  ("H314", "H315"),
+  ("H314", "H318"),
+  ("H314", "H319"),
+  ("H318", "H319"),
+  ("H370", "H371"),
+  ("H372", "H373"),
+  ("H410", "H400"),
+];
+
+/// A hazardous component of a mixture.
+#[derive(Debug)]
+pub struct Component<'a> {
+  pub h_phrases: Vec<&'a str>,
+  /// in percent by mass
+  pub concentration: f64,
+}
+
+impl Component<'_> {
+  fn has(&self, code: &str) -> bool {
+    self
+      .h_phrases
+      .iter()
+      .flat_map(|phrase| split_code(phrase))
+      .any(|part| part == code)
+  }
+}
+
+/// A substance of a mixture and its concentration, e.g. in `SOLUTIONRELATIVE` or `SOLUTIONMOL`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MixtureComponent {
+  pub substance_data: SubstanceData,
+  pub concentration: Amount,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Classification {
+  pub h_phrases: Vec<(String, String)>,
+  pub symbols: Vec<String>,
+  /// Localized like the signal words of the providers.
+  pub signal_word: Option<String>,
+}
+
+/// Classifies a mixture by the additivity rules and generic concentration limits of the CLP regulation.
+/// Specific concentration limits and M-factors of single substances are not known and not considered.
+pub fn classify(components: &[Component], language: Language) -> Classification {
+  let sum = |codes: &[&str]| -> f64 {
+    components
+      .iter()
+      .filter(|c| codes.iter().any(|code| c.has(code)))
+      .map(|c| c.concentration)
+      .sum()
+  };
+
+  let mut h_phrases: Vec<&str> = Vec::new();
+
+  // acute toxicity: 100 / ATEmix = sum(Ci / ATEi)
+  for (values, limits) in ACUTE_TOXICITY.iter() {
+    let toxicity: f64 = components
+      .iter()
+      .filter_map(|c| {
+        values
+          .iter()
+          .find(|(code, _)| c.has(code))
+          .map(|(_, ate)| c.concentration / ate)
+      })
+      .sum();
+    if toxicity > 0.0 {
+      let ate = 100.0 / toxicity;
+      if let Some((_, code)) = limits.iter().find(|(limit, _)| ate <= *limit) {
+        h_phrases.push(code);
+      }
+    }
+  }
+
+  // skin corrosion and eye damage
+  let corrosive = sum(&["H314"]);
+  let eye_damage = sum(&["H314", "H318"]);
+  if corrosive >= 5.0 {
+    h_phrases.push("H314");
+  } else if corrosive >= 1.0 || 10.0 * corrosive + sum(&["H315"]) >= 10.0 {
+    h_phrases.push("H315");
+  }
+  if eye_damage >= 3.0 {
+    h_phrases.push("H318");
+  } else if 10.0 * eye_damage + sum(&["H319"]) >= 10.0 {
+    h_phrases.push("H319");
+  }
+
+  for (component_code, limit, code) in CONCENTRATION_LIMITS.iter() {
+    if components
+      .iter()
+      .any(|c| c.has(component_code) && c.concentration >= *limit)
+    {
+      h_phrases.push(code);
+    }
+  }
+  for code in ["H335", "H336"].iter() {
+    if sum(&[*code]) >= 20.0 {
+      h_phrases.push(code);
+    }
+  }
+  if sum(&["H304"]) >= 10.0 {
+    h_phrases.push("H304");
+  }
+
+  // aquatic toxicity, all M-factors are expected to be 1
+  if sum(&["H400", "H410"]) >= 25.0 {
+    h_phrases.push("H400");
+  }
+  let (chronic_1, chronic_2, chronic_3) = (sum(&["H410"]), sum(&["H411"]), sum(&["H412"]));
+  if chronic_1 >= 25.0 {
+    h_phrases.push("H410");
+  } else if 10.0 * chronic_1 + chronic_2 >= 25.0 {
+    h_phrases.push("H411");
+  } else if 100.0 * chronic_1 + 10.0 * chronic_2 + chronic_3 >= 25.0 {
+    h_phrases.push("H412");
+  } else if chronic_1 + chronic_2 + chronic_3 + sum(&["H413"]) >= 25.0 {
+    h_phrases.push("H413");
+  }
+
+  for component in components.iter() {
+    for phrase in component.h_phrases.iter() {
+      if !split_code(phrase).any(|part| CLASSIFIED.contains(&part)) {
+        h_phrases.push(phrase);
+      }
+    }
+  }
+
+  let present = h_phrases.clone();
+  h_phrases.retain(|code| !SUPERSEDED.iter().any(|(by, c)| c == code && present.contains(by)));
+  // EUH statements are listed after the H statements
+  h_phrases.sort_by_key(|code| (code.starts_with("EUH"), *code));
+  h_phrases.dedup();
+
+  let (symbols, signal_word) = required_labels(&h_phrases);
+  Classification {
+    h_phrases: h_phrases
+      .into_iter()
+      .map(|code| (code.into(), get_statement(code, language).unwrap_or_default()))
+      .collect(),
+    symbols: symbols.into_iter().map(String::from).collect(),
+    signal_word: signal_word.map(|word| word.localized(language).into()),
+  }
+}
+
+/// The concentration of a solution in percent by mass, `None` if the amount is no concentration.
+/// Solutions are expected to have the density of water and volume percent are used as mass percent.
+fn concentration(amount: &Amount, molar_mass: Option<&Quantity>) -> Result<Option<f64>, ConversionError> {
+  let (unit, factor) = match amount.unit {
+    Unit::SolutionRelative => (Unit::SolutionRelative, 1.0),
+    // 10 g/l are 1 %
+    Unit::SolutionMol
+    | Unit::SolutionMillimol
+    | Unit::SolutionMicromol
+    | Unit::SolutionGram
+    | Unit::SolutionMilligram => (Unit::SolutionGram, 0.1),
+    _ => return Ok(None),
+  };
+
+  let converted = conversion::convert(amount, &unit, molar_mass, None)?;
+  Ok(converted.value.parse::<f64>().ok().map(|value| value * factor))
+}
+
+/// Classifies a mixture of substances in a non hazardous solvent by the current data of its components.
+pub fn classify_mixture(
+  components: &[MixtureComponent],
+  language: Language,
+) -> Result<Classification, ConversionError> {
+  let components = components
+    .iter()
+    .map(|component| {
+      let substance = &component.substance_data;
+      let molar_mass = substance.molar_mass.current().as_ref();
+      let concentration = concentration(&component.concentration, molar_mass)?.ok_or_else(|| {
+        ConversionError::Incompatible(
+          component.concentration.unit.clone().into(),
+          Unit::SolutionRelative.into(),
+        )
+      })?;
+      Ok(Component {
+        h_phrases: substance
+          .h_phrases
+          .current()
+          .iter()
+          .map(|(code, _)| code.as_str())
+          .collect(),
+        concentration,
+      })
+    })
+    .collect::<Result<Vec<_>, ConversionError>>()?;
+
+  Ok(classify(&components, language))
+}
+
+/// Classifies the solution of a substance in a non hazardous solvent and suggests the result as modified data.
+///
+/// The classification is based on the original data of the pure substance. Only fields that differ from the pure
+/// substance and weren't edited by the user are written, a missing signal word is suggested as empty string.
+/// Substances without a concentration are left untouched.
+pub fn classify_solution(substance: &mut SubstanceData, language: Language) -> Result<(), ConversionError> {
+  let amount = match &substance.amount {
+    Some(amount) => amount,
+    None => return Ok(()),
+  };
+  let molar_mass = substance.molar_mass.current().as_ref();
+  let concentration = match concentration(amount, molar_mass)? {
+    Some(concentration) => concentration,
+    None => return Ok(()),
+  };
+
+  let classification = classify(
+    &[Component {
+      h_phrases: substance
+        .h_phrases
+        .original_data
+        .iter()
+        .map(|(code, _)| code.as_str())
+        .collect(),
+      concentration,
+    }],
+    language,
+  );
+
+  let h_phrases = &mut substance.h_phrases;
+  let codes_changed = !classification
+    .h_phrases
+    .iter()
+    .map(|(code, _)| code)
+    .eq(h_phrases.original_data.iter().map(|(code, _)| code));
+  if h_phrases.modified_data.is_none() && codes_changed {
+    h_phrases.modified_data = Some(classification.h_phrases);
+  }
+
+  let symbols = &mut substance.symbols;
+  if symbols.modified_data.is_none() && classification.symbols != symbols.original_data {
+    symbols.modified_data = Some(classification.symbols);
+  }
+
+  let signal_word = &mut substance.signal_word;
+  let signal_word_changed = match (&classification.signal_word, &signal_word.original_data) {
+    (Some(word), Some(original)) => !word.eq_ignore_ascii_case(original),
+    (None, None) => false,
+    _ => true,
+  };
+  if signal_word.modified_data.is_none() && signal_word_changed {
+    // `None` wouldn't survive the serialization, the ui also uses an empty string for a removed signal word
+    signal_word.modified_data = Some(Some(classification.signal_word.unwrap_or_default()));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Data;
+
+  fn classify_one(h_phrases: &[&'static str], concentration: f64) -> Classification {
+    classify(
+      &[Component {
+        h_phrases: h_phrases.to_vec(),
+        concentration,
+      }],
+      Language::German,
+    )
+  }
+
+  fn codes(classification: &Classification) -> Vec<&str> {
+    classification.h_phrases.iter().map(|(code, _)| code.as_str()).collect()
+  }
+
+  /// Hydrochloric acid with the given amount.
+  fn acid(amount: Option<(&str, Unit)>) -> SubstanceData {
+    let phrases = ["H290", "H314", "H335"];
+    SubstanceData {
+      name: Data::new("Salzsäure".into()),
+      molecular_formula: Data::new(Some("HCl".into())),
+      molar_mass: Data::new(Some(Quantity::parse("36,46 g/mol"))),
+      h_phrases: Data::new(phrases.iter().map(|c| (c.to_string(), String::new())).collect()),
+      signal_word: Data::new(Some("Gefahr".into())),
+      symbols: Data::new(vec!["ghs05".into(), "ghs07".into()]),
+      amount: amount.map(|(value, unit)| Amount {
+        value: value.into(),
+        unit,
+      }),
+      ..SubstanceData::default()
+    }
+  }
+
+  #[test]
+  fn test_corrosive_solution() {
+    // hydrochloric acid
+    let acid = ["H290", "H314", "H335"];
+
+    let concentrated = classify_one(&acid, 25.0);
+    assert_eq!(codes(&concentrated), vec!["H290", "H314", "H335"]);
+    assert_eq!(concentrated.symbols, vec!["ghs05", "ghs07"]);
+    assert_eq!(concentrated.signal_word.as_deref(), Some("Gefahr"));
+
+    let diluted = classify_one(&acid, 2.0);
+    assert_eq!(codes(&diluted), vec!["H290", "H315", "H319"]);
+    assert_eq!(diluted.symbols, vec!["ghs05"]);
+    assert_eq!(diluted.signal_word.as_deref(), Some("Achtung"));
+  }
+
+  #[test]
+  fn test_acute_toxicity() {
+    let methanol = ["H225", "H301+H311+H331", "H370"];
+    let classification = classify_one(&methanol, 5.0);
+    assert_eq!(codes(&classification), vec!["H225", "H302", "H371"]);
+    assert_eq!(classification.symbols, vec!["ghs02", "ghs07", "ghs08"]);
+
+    let classification = classify_one(&["H302"], 10.0);
+    assert!(classification.h_phrases.is_empty());
+    assert!(classification.symbols.is_empty());
+    assert_eq!(classification.signal_word, None);
+  }
+
+  #[test]
+  fn test_several_components() {
+    let classification = classify(
+      &[
+        Component {
+          h_phrases: vec!["H411"],
+          concentration: 15.0,
+        },
+        Component {
+          h_phrases: vec!["H410", "EUH066"],
+          concentration: 1.0,
+        },
+      ],
+      Language::English,
+    );
+    assert_eq!(codes(&classification), vec!["H411", "EUH066"]);
+    assert_eq!(classification.symbols, vec!["ghs09"]);
+  }
+
+  #[test]
+  fn test_concentration() {
+    let relative = Amount {
+      value: "10".into(),
+      unit: Unit::SolutionRelative,
+    };
+    assert_eq!(concentration(&relative, None).unwrap(), Some(10.0));
+
+    let molar = Amount {
+      value: "2".into(),
+      unit: Unit::SolutionMol,
+    };
+    let molar_mass = Quantity::parse("40 g/mol");
+    assert_eq!(concentration(&molar, Some(&molar_mass)).unwrap(), Some(8.0));
+    assert!(concentration(&molar, None).is_err());
+
+    let pure = Amount {
+      value: "5".into(),
+      unit: Unit::Gram,
+    };
+    assert_eq!(concentration(&pure, None).unwrap(), None);
+  }
+
+  #[test]
+  fn test_classify_solution() {
+    let mut substance = acid(Some(("2", Unit::SolutionRelative)));
+    substance.symbols.modified_data = Some(vec!["ghs05".into(), "ghs07".into()]);
+    classify_solution(&mut substance, Language::German).unwrap();
+
+    let h_phrases = substance.h_phrases.modified_data.as_ref().unwrap();
+    assert_eq!(h_phrases[1].0, "H315");
+    assert!(!h_phrases[1].1.is_empty());
+    // edited by the user
+    assert_eq!(
+      substance.symbols.modified_data,
+      Some(vec!["ghs05".into(), "ghs07".into()])
+    );
+    assert_eq!(substance.signal_word.modified_data, Some(Some("Achtung".into())));
+
+    let mut substance = acid(Some(("0,05", Unit::SolutionRelative)));
+    // without H290 nothing is left
+    substance.h_phrases.original_data.remove(0);
+    classify_solution(&mut substance, Language::German).unwrap();
+    assert_eq!(substance.symbols.modified_data, Some(vec![]));
+    assert_eq!(substance.signal_word.modified_data, Some(Some(String::new())));
+
+    let json = serde_json::to_string(&substance).unwrap();
+    let substance: SubstanceData = serde_json::from_str(&json).unwrap();
+    assert_eq!(substance.signal_word.modified_data, Some(Some(String::new())));
+    assert_eq!(substance.signal_word.original_data.as_deref(), Some("Gefahr"));
+
+    let mut pure = acid(Some(("5", Unit::Gram)));
+    classify_solution(&mut pure, Language::German).unwrap();
+    assert!(pure.h_phrases.modified_data.is_none());
+    assert!(pure.signal_word.modified_data.is_none());
+  }
+
+  #[test]
+  fn test_classify_mixture() {
+    let mixture = [
+      MixtureComponent {
+        substance_data: acid(None),
+        concentration: Amount {
+          value: "3".into(),
+          unit: Unit::SolutionRelative,
+        },
+      },
+      MixtureComponent {
+        substance_data: acid(None),
+        concentration: Amount {
+          value: "0,5".into(),
+          unit: Unit::SolutionMol,
+        },
+      },
+    ];
+    // 3 % + 1,8 % are corrosive
+    let classification = classify_mixture(&mixture, Language::English).unwrap();
+    assert_eq!(codes(&classification), vec!["H290", "H315", "H318"]);
+    assert_eq!(classification.signal_word.as_deref(), Some("Danger"));
+
+    let pure = [MixtureComponent {
+      substance_data: acid(None),
+      concentration: Amount {
+        value: "5".into(),
+        unit: Unit::Gram,
+      },
+    }];
+    assert_eq!(
+      classify_mixture(&pure, Language::English).unwrap_err(),
+      ConversionError::Incompatible("g".into(), "% (v/v)".into())
+    );
+  }
+}
@@ -0,0 +1,198 @@
+mod classification;
+mod statements;
+mod validation;
+
+use self::statements::{HAZARD_STATEMENTS, PRECAUTIONARY_STATEMENTS};
+use crate::Language;
+
+pub use self::{
+  classification::{classify, classify_mixture, classify_solution, Classification, Component, MixtureComponent},
+  validation::{validate, ValidationWarning},
+};
+
+type Statements = [(&'static str, &'static str, &'static str)];
+
+/// Returns the official text of a hazard (`H225`, `EUH066`) or precautionary (`P305+P351+P338`) statement.
+///
+/// The parts of a combination may be in any order (`H330+H300`), combinations without an own text in the regulation
+/// are composed from the texts of their parts.
+pub fn get_statement(code: &str, language: Language) -> Option<String> {
+  let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+  let statements = if code.starts_with('P') {
+    PRECAUTIONARY_STATEMENTS
+  } else {
+    HAZARD_STATEMENTS
+  };
+  if let Some(text) = lookup(statements, &code, language) {
+    return Some(text.into());
+  }
+
+  if !code.contains('+') {
+    return None;
+  }
+  split_code(&code)
+    .map(|part| lookup(statements, part, language))
+    .collect::<Option<Vec<&str>>>()
+    .map(|texts| texts.join(" "))
+}
+
+/// Returns true if the code and all parts of a combination are hazard statements of the regulation.
+pub fn is_known_hazard(code: &str) -> bool {
+  is_known(HAZARD_STATEMENTS, code)
+}
+
+/// Returns true if the code and all parts of a combination are precautionary statements of the regulation.
+pub fn is_known_precaution(code: &str) -> bool {
+  is_known(PRECAUTIONARY_STATEMENTS, code)
+}
+
+/// Returns true if the text looks like a statement code (`H225`, `EUH066`, `H360Fd`, `P305+P351+P338`),
+/// it doesn't have to be part of the regulation.
+pub fn is_code(text: &str) -> bool {
+  split_code(text).all(|part| {
+    let number = part
+      .strip_prefix("EUH")
+      .or_else(|| part.strip_prefix('H'))
+      .or_else(|| part.strip_prefix('P'))
+      .unwrap_or_default();
+    let digits = number.chars().take_while(|c| c.is_ascii_digit()).count();
+    digits == 3 && number[3..].chars().all(|c| c.is_ascii_alphabetic())
+  })
+}
+
+/// `P305 + P351+P338` -> `["P305", "P351", "P338"]`
+fn split_code(code: &str) -> impl Iterator<Item = &str> {
+  code.split('+').map(|part| part.trim())
+}
+
+fn sorted_parts(code: &str) -> Vec<&str> {
+  let mut parts: Vec<&str> = split_code(code).collect();
+  parts.sort_unstable();
+  parts
+}
+
+fn is_known(statements: &Statements, code: &str) -> bool {
+  split_code(code).all(|part| lookup(statements, part, Language::German).is_some())
+}
+
+/// Fills the statements without text, e.g. after importing a file that only stores the codes.
+/// Statements with unknown codes are left empty.
+pub fn fill_statements(statements: &mut [(String, String)], language: Language) {
+  for (code, text) in statements.iter_mut().filter(|(_, text)| text.is_empty()) {
+    if let Some(statement) = get_statement(code, language) {
+      *text = statement;
+    }
+  }
+}
+
+fn lookup(statements: &Statements, code: &str, language: Language) -> Option<&'static str> {
+  statements
+    .iter()
+    .find(|(c, _, _)| *c == code)
+    .or_else(|| {
+      if !code.contains('+') {
+        return None;
+      }
+      let parts = sorted_parts(code);
+      statements
+        .iter()
+        .find(|(c, _, _)| c.contains('+') && sorted_parts(c) == parts)
+    })
+    .map(|(_, german, english)| match language {
+      Language::German => *german,
+      Language::English => *english,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use super::*;
+
+  #[test]
+  fn test_catalogue() {
+    let mut codes = HashSet::new();
+    for (code, german, english) in HAZARD_STATEMENTS.iter().chain(PRECAUTIONARY_STATEMENTS.iter()) {
+      assert!(codes.insert(code), "{} is listed twice", code);
+      assert!(!german.is_empty() && !english.is_empty(), "{} has no text", code);
+    }
+  }
+
+  #[test]
+  fn test_get_statement() {
+    assert_eq!(
+      get_statement("H225", Language::German).unwrap(),
+      "Flüssigkeit und Dampf leicht entzündbar."
+    );
+    assert_eq!(
+      get_statement("H225", Language::English).unwrap(),
+      "Highly flammable liquid and vapour."
+    );
+    assert_eq!(
+      get_statement("H360Fd", Language::English).unwrap(),
+      "May damage fertility. Suspected of damaging the unborn child."
+    );
+    assert_eq!(get_statement("H999", Language::German), None);
+  }
+
+  #[test]
+  fn test_combined_statements() {
+    assert_eq!(
+      get_statement("P305 + P351 + P338", Language::English).unwrap(),
+      "IF IN EYES: Rinse cautiously with water for several minutes. Remove contact lenses, if present and easy to do. \
+       Continue rinsing."
+    );
+    // not listed in the regulation
+    assert_eq!(
+      get_statement("P210+P233", Language::German).unwrap(),
+      "Von Hitze, heißen Oberflächen, Funken, offenen Flammen und anderen Zündquellen fernhalten. Nicht rauchen. \
+       Behälter dicht verschlossen halten."
+    );
+    assert_eq!(get_statement("P210+P999", Language::German), None);
+
+    // the official texts are used for all combinations of the regulation
+    assert_eq!(
+      get_statement("H300+H310+H330", Language::German).unwrap(),
+      "Lebensgefahr bei Verschlucken, Hautkontakt oder Einatmen."
+    );
+    assert_eq!(
+      get_statement("H331 + H311 + H301", Language::English).unwrap(),
+      "Toxic if swallowed, in contact with skin or if inhaled."
+    );
+    assert_eq!(
+      get_statement("P302+P335+P334", Language::English).unwrap(),
+      "IF ON SKIN: Brush off loose particles from skin. Immerse in cool water [or wrap in wet bandages]."
+    );
+  }
+
+  #[test]
+  fn test_codes() {
+    assert!(is_known_hazard("H301 + H311"));
+    assert!(is_known_hazard("EUH066"));
+    assert!(!is_known_hazard("P210"));
+    assert!(is_known_precaution("P210+P233"));
+    assert!(!is_known_precaution("P210+P999"));
+
+    assert!(is_code("H360Fd"));
+    assert!(is_code("P305+P351+P338"));
+    assert!(is_code("EUH999"));
+    assert!(!is_code("Hinweis"));
+    assert!(!is_code("P21"));
+    assert!(!is_code(""));
+  }
+
+  #[test]
+  fn test_fill_statements() {
+    let mut statements = vec![
+      ("H319".to_string(), String::new()),
+      ("H225".to_string(), "Leicht entzündbar".to_string()),
+      ("H999".to_string(), String::new()),
+    ];
+    fill_statements(&mut statements, Language::German);
+
+    assert_eq!(statements[0].1, "Verursacht schwere Augenreizung.");
+    assert_eq!(statements[1].1, "Leicht entzündbar");
+    assert_eq!(statements[2].1, "");
+  }
+}
@@ -0,0 +1,1069 @@
+//! Texts of the hazard and precautionary statements from Annex III of the CLP regulation (EC) No 1272/2008.
+//!
+//! Every entry is `(code, german, english)`, combined statements are only listed if the regulation defines a text
+//! for them. Placeholders the supplier has to fill in are kept as `…`.
+
+/* #region hazard statements */
+
+pub const HAZARD_STATEMENTS: &[(&str, &str, &str)] = &[
+  // physical hazards
+  ("H200", "Instabil, explosiv.", "Unstable explosive."),
+  (
+    "H201",
+    "Explosiv, Gefahr der Massenexplosion.",
+    "Explosive; mass explosion hazard.",
+  ),
+  (
+    "H202",
+    "Explosiv; große Gefahr durch Splitter, Spreng- und Wurfstücke.",
+    "Explosive, severe projection hazard.",
+  ),
+  (
+    "H203",
+    "Explosiv; Gefahr durch Feuer, Luftdruck oder Splitter, Spreng- und Wurfstücke.",
+    "Explosive; fire, blast or projection hazard.",
+  ),
+  (
+    "H204",
+    "Gefahr durch Feuer oder Splitter, Spreng- und Wurfstücke.",
+    "Fire or projection hazard.",
+  ),
+  (
+    "H205",
+    "Gefahr der Massenexplosion bei Feuer.",
+    "May mass explode in fire.",
+  ),
+  (
+    "H206",
+    "Gefahr durch Feuer, Druckstoß oder Sprengstücke; erhöhte Explosionsgefahr, wenn das Desensibilisierungsmittel \
+     reduziert wird.",
+    "Fire, blast or projection hazard; increased risk of explosion if desensitising agent is reduced.",
+  ),
+  (
+    "H207",
+    "Gefahr durch Feuer oder Sprengstücke; erhöhte Explosionsgefahr, wenn das Desensibilisierungsmittel reduziert \
+     wird.",
+    "Fire or projection hazard; increased risk of explosion if desensitising agent is reduced.",
+  ),
+  (
+    "H208",
+    "Gefahr durch Feuer; erhöhte Explosionsgefahr, wenn das Desensibilisierungsmittel reduziert wird.",
+    "Fire hazard; increased risk of explosion if desensitising agent is reduced.",
+  ),
+  ("H220", "Extrem entzündbares Gas.", "Extremely flammable gas."),
+  ("H221", "Entzündbares Gas.", "Flammable gas."),
+  ("H222", "Extrem entzündbares Aerosol.", "Extremely flammable aerosol."),
+  ("H223", "Entzündbares Aerosol.", "Flammable aerosol."),
+  (
+    "H224",
+    "Flüssigkeit und Dampf extrem entzündbar.",
+    "Extremely flammable liquid and vapour.",
+  ),
+  (
+    "H225",
+    "Flüssigkeit und Dampf leicht entzündbar.",
+    "Highly flammable liquid and vapour.",
+  ),
+  (
+    "H226",
+    "Flüssigkeit und Dampf entzündbar.",
+    "Flammable liquid and vapour.",
+  ),
+  ("H228", "Entzündbarer Feststoff.", "Flammable solid."),
+  (
+    "H229",
+    "Behälter steht unter Druck: Kann bei Erwärmung bersten.",
+    "Pressurised container: May burst if heated.",
+  ),
+  (
+    "H230",
+    "Kann auch in Abwesenheit von Luft explosionsartig reagieren.",
+    "May react explosively even in the absence of air.",
+  ),
+  (
+    "H231",
+    "Kann auch in Abwesenheit von Luft bei erhöhtem Druck und/oder erhöhter Temperatur explosionsartig reagieren.",
+    "May react explosively even in the absence of air at elevated pressure and/or temperature.",
+  ),
+  (
+    "H232",
+    "Kann sich bei Kontakt mit Luft spontan entzünden.",
+    "May ignite spontaneously if exposed to air.",
+  ),
+  (
+    "H240",
+    "Erwärmung kann Explosion verursachen.",
+    "Heating may cause an explosion.",
+  ),
+  (
+    "H241",
+    "Erwärmung kann Brand oder Explosion verursachen.",
+    "Heating may cause a fire or explosion.",
+  ),
+  ("H242", "Erwärmung kann Brand verursachen.", "Heating may cause a fire."),
+  (
+    "H250",
+    "Entzündet sich in Berührung mit Luft von selbst.",
+    "Catches fire spontaneously if exposed to air.",
+  ),
+  (
+    "H251",
+    "Selbsterhitzungsfähig; kann in Brand geraten.",
+    "Self-heating; may catch fire.",
+  ),
+  (
+    "H252",
+    "In großen Mengen selbsterhitzungsfähig; kann in Brand geraten.",
+    "Self-heating in large quantities; may catch fire.",
+  ),
+  (
+    "H260",
+    "In Berührung mit Wasser entstehen entzündbare Gase, die sich spontan entzünden können.",
+    "In contact with water releases flammable gases which may ignite spontaneously.",
+  ),
+  (
+    "H261",
+    "In Berührung mit Wasser entstehen entzündbare Gase.",
+    "In contact with water releases flammable gases.",
+  ),
+  (
+    "H270",
+    "Kann Brand verursachen oder verstärken; Oxidationsmittel.",
+    "May cause or intensify fire; oxidiser.",
+  ),
+  (
+    "H271",
+    "Kann Brand oder Explosion verursachen; starkes Oxidationsmittel.",
+    "May cause fire or explosion; strong oxidiser.",
+  ),
+  (
+    "H272",
+    "Kann Brand verstärken; Oxidationsmittel.",
+    "May intensify fire; oxidiser.",
+  ),
+  (
+    "H280",
+    "Enthält Gas unter Druck; kann bei Erwärmung explodieren.",
+    "Contains gas under pressure; may explode if heated.",
+  ),
+  (
+    "H281",
+    "Enthält tiefgekühltes Gas; kann Kälteverbrennungen oder -verletzungen verursachen.",
+    "Contains refrigerated gas; may cause cryogenic burns or injury.",
+  ),
+  (
+    "H290",
+    "Kann gegenüber Metallen korrosiv sein.",
+    "May be corrosive to metals.",
+  ),
+  // health hazards
+  ("H300", "Lebensgefahr bei Verschlucken.", "Fatal if swallowed."),
+  ("H301", "Giftig bei Verschlucken.", "Toxic if swallowed."),
+  (
+    "H302",
+    "Gesundheitsschädlich bei Verschlucken.",
+    "Harmful if swallowed.",
+  ),
+  (
+    "H304",
+    "Kann bei Verschlucken und Eindringen in die Atemwege tödlich sein.",
+    "May be fatal if swallowed and enters airways.",
+  ),
+  ("H310", "Lebensgefahr bei Hautkontakt.", "Fatal in contact with skin."),
+  ("H311", "Giftig bei Hautkontakt.", "Toxic in contact with skin."),
+  (
+    "H312",
+    "Gesundheitsschädlich bei Hautkontakt.",
+    "Harmful in contact with skin.",
+  ),
+  (
+    "H314",
+    "Verursacht schwere Verätzungen der Haut und schwere Augenschäden.",
+    "Causes severe skin burns and eye damage.",
+  ),
+  ("H315", "Verursacht Hautreizungen.", "Causes skin irritation."),
+  (
+    "H317",
+    "Kann allergische Hautreaktionen verursachen.",
+    "May cause an allergic skin reaction.",
+  ),
+  ("H318", "Verursacht schwere Augenschäden.", "Causes serious eye damage."),
+  (
+    "H319",
+    "Verursacht schwere Augenreizung.",
+    "Causes serious eye irritation.",
+  ),
+  ("H330", "Lebensgefahr bei Einatmen.", "Fatal if inhaled."),
+  ("H331", "Giftig bei Einatmen.", "Toxic if inhaled."),
+  ("H332", "Gesundheitsschädlich bei Einatmen.", "Harmful if inhaled."),
+  (
+    "H334",
+    "Kann bei Einatmen Allergie, asthmaartige Symptome oder Atembeschwerden verursachen.",
+    "May cause allergy or asthma symptoms or breathing difficulties if inhaled.",
+  ),
+  ("H335", "Kann die Atemwege reizen.", "May cause respiratory irritation."),
+  (
+    "H336",
+    "Kann Schläfrigkeit und Benommenheit verursachen.",
+    "May cause drowsiness or dizziness.",
+  ),
+  (
+    "H340",
+    "Kann genetische Defekte verursachen.",
+    "May cause genetic defects.",
+  ),
+  (
+    "H341",
+    "Kann vermutlich genetische Defekte verursachen.",
+    "Suspected of causing genetic defects.",
+  ),
+  ("H350", "Kann Krebs erzeugen.", "May cause cancer."),
+  (
+    "H350i",
+    "Kann bei Einatmen Krebs erzeugen.",
+    "May cause cancer by inhalation.",
+  ),
+  (
+    "H351",
+    "Kann vermutlich Krebs erzeugen.",
+    "Suspected of causing cancer.",
+  ),
+  (
+    "H360",
+    "Kann die Fruchtbarkeit beeinträchtigen oder das Kind im Mutterleib schädigen.",
+    "May damage fertility or the unborn child.",
+  ),
+  (
+    "H360F",
+    "Kann die Fruchtbarkeit beeinträchtigen.",
+    "May damage fertility.",
+  ),
+  (
+    "H360D",
+    "Kann das Kind im Mutterleib schädigen.",
+    "May damage the unborn child.",
+  ),
+  (
+    "H360FD",
+    "Kann die Fruchtbarkeit beeinträchtigen. Kann das Kind im Mutterleib schädigen.",
+    "May damage fertility. May damage the unborn child.",
+  ),
+  (
+    "H360Fd",
+    "Kann die Fruchtbarkeit beeinträchtigen. Kann vermutlich das Kind im Mutterleib schädigen.",
+    "May damage fertility. Suspected of damaging the unborn child.",
+  ),
+  (
+    "H360Df",
+    "Kann das Kind im Mutterleib schädigen. Kann vermutlich die Fruchtbarkeit beeinträchtigen.",
+    "May damage the unborn child. Suspected of damaging fertility.",
+  ),
+  (
+    "H361",
+    "Kann vermutlich die Fruchtbarkeit beeinträchtigen oder das Kind im Mutterleib schädigen.",
+    "Suspected of damaging fertility or the unborn child.",
+  ),
+  (
+    "H361f",
+    "Kann vermutlich die Fruchtbarkeit beeinträchtigen.",
+    "Suspected of damaging fertility.",
+  ),
+  (
+    "H361d",
+    "Kann vermutlich das Kind im Mutterleib schädigen.",
+    "Suspected of damaging the unborn child.",
+  ),
+  (
+    "H361fd",
+    "Kann vermutlich die Fruchtbarkeit beeinträchtigen. Kann vermutlich das Kind im Mutterleib schädigen.",
+    "Suspected of damaging fertility. Suspected of damaging the unborn child.",
+  ),
+  (
+    "H362",
+    "Kann Säuglinge über die Muttermilch schädigen.",
+    "May cause harm to breast-fed children.",
+  ),
+  ("H370", "Schädigt die Organe.", "Causes damage to organs."),
+  ("H371", "Kann die Organe schädigen.", "May cause damage to organs."),
+  (
+    "H372",
+    "Schädigt die Organe bei längerer oder wiederholter Exposition.",
+    "Causes damage to organs through prolonged or repeated exposure.",
+  ),
+  (
+    "H373",
+    "Kann die Organe schädigen bei längerer oder wiederholter Exposition.",
+    "May cause damage to organs through prolonged or repeated exposure.",
+  ),
+  (
+    "H300+H310",
+    "Lebensgefahr bei Verschlucken oder Hautkontakt.",
+    "Fatal if swallowed or in contact with skin.",
+  ),
+  (
+    "H300+H330",
+    "Lebensgefahr bei Verschlucken oder Einatmen.",
+    "Fatal if swallowed or if inhaled.",
+  ),
+  (
+    "H310+H330",
+    "Lebensgefahr bei Hautkontakt oder Einatmen.",
+    "Fatal in contact with skin or if inhaled.",
+  ),
+  (
+    "H300+H310+H330",
+    "Lebensgefahr bei Verschlucken, Hautkontakt oder Einatmen.",
+    "Fatal if swallowed, in contact with skin or if inhaled.",
+  ),
+  (
+    "H301+H311",
+    "Giftig bei Verschlucken oder Hautkontakt.",
+    "Toxic if swallowed or in contact with skin.",
+  ),
+  (
+    "H301+H331",
+    "Giftig bei Verschlucken oder Einatmen.",
+    "Toxic if swallowed or if inhaled.",
+  ),
+  (
+    "H311+H331",
+    "Giftig bei Hautkontakt oder Einatmen.",
+    "Toxic in contact with skin or if inhaled.",
+  ),
+  (
+    "H301+H311+H331",
+    "Giftig bei Verschlucken, Hautkontakt oder Einatmen.",
+    "Toxic if swallowed, in contact with skin or if inhaled.",
+  ),
+  (
+    "H302+H312",
+    "Gesundheitsschädlich bei Verschlucken oder Hautkontakt.",
+    "Harmful if swallowed or in contact with skin.",
+  ),
+  (
+    "H302+H332",
+    "Gesundheitsschädlich bei Verschlucken oder Einatmen.",
+    "Harmful if swallowed or if inhaled.",
+  ),
+  (
+    "H312+H332",
+    "Gesundheitsschädlich bei Hautkontakt oder Einatmen.",
+    "Harmful in contact with skin or if inhaled.",
+  ),
+  (
+    "H302+H312+H332",
+    "Gesundheitsschädlich bei Verschlucken, Hautkontakt oder Einatmen.",
+    "Harmful if swallowed, in contact with skin or if inhaled.",
+  ),
+  // environmental hazards
+  (
+    "H400",
+    "Sehr giftig für Wasserorganismen.",
+    "Very toxic to aquatic life.",
+  ),
+  (
+    "H410",
+    "Sehr giftig für Wasserorganismen mit langfristiger Wirkung.",
+    "Very toxic to aquatic life with long lasting effects.",
+  ),
+  (
+    "H411",
+    "Giftig für Wasserorganismen, mit langfristiger Wirkung.",
+    "Toxic to aquatic life with long lasting effects.",
+  ),
+  (
+    "H412",
+    "Schädlich für Wasserorganismen, mit langfristiger Wirkung.",
+    "Harmful to aquatic life with long lasting effects.",
+  ),
+  (
+    "H413",
+    "Kann für Wasserorganismen schädlich sein, mit langfristiger Wirkung.",
+    "May cause long lasting harmful effects to aquatic life.",
+  ),
+  (
+    "H420",
+    "Schädigt die öffentliche Gesundheit und die Umwelt durch Ozonabbau in der äußeren Atmosphäre.",
+    "Harms public health and the environment by destroying ozone in the upper atmosphere.",
+  ),
+  // supplemental hazard information
+  ("EUH001", "In trockenem Zustand explosiv.", "Explosive when dry."),
+  ("EUH014", "Reagiert heftig mit Wasser.", "Reacts violently with water."),
+  (
+    "EUH018",
+    "Kann bei Verwendung explosionsfähige / entzündbare Dampf/Luft-Gemische bilden.",
+    "In use may form flammable/explosive vapour-air mixture.",
+  ),
+  (
+    "EUH019",
+    "Kann explosionsfähige Peroxide bilden.",
+    "May form explosive peroxides.",
+  ),
+  (
+    "EUH029",
+    "Entwickelt bei Berührung mit Wasser giftige Gase.",
+    "Contact with water liberates toxic gas.",
+  ),
+  (
+    "EUH031",
+    "Entwickelt bei Berührung mit Säure giftige Gase.",
+    "Contact with acids liberates toxic gas.",
+  ),
+  (
+    "EUH032",
+    "Entwickelt bei Berührung mit Säure sehr giftige Gase.",
+    "Contact with acids liberates very toxic gas.",
+  ),
+  (
+    "EUH044",
+    "Explosionsgefahr bei Erhitzen unter Einschluss.",
+    "Risk of explosion if heated under confinement.",
+  ),
+  (
+    "EUH066",
+    "Wiederholter Kontakt kann zu spröder oder rissiger Haut führen.",
+    "Repeated exposure may cause skin dryness or cracking.",
+  ),
+  ("EUH070", "Giftig bei Berührung mit den Augen.", "Toxic by eye contact."),
+  (
+    "EUH071",
+    "Wirkt ätzend auf die Atemwege.",
+    "Corrosive to the respiratory tract.",
+  ),
+  (
+    "EUH201",
+    "Enthält Blei. Nicht für den Anstrich von Gegenständen verwenden, die von Kindern gekaut oder gelutscht werden \
+     könnten.",
+    "Contains lead. Should not be used on surfaces liable to be chewed or sucked by children.",
+  ),
+  ("EUH201A", "Achtung! Enthält Blei.", "Warning! Contains lead."),
+  (
+    "EUH202",
+    "Cyanacrylat. Gefahr. Klebt innerhalb von Sekunden Haut und Augenlider zusammen. Darf nicht in die Hände von \
+     Kindern gelangen.",
+    "Cyanoacrylate. Danger. Bonds skin and eyes in seconds. Keep out of the reach of children.",
+  ),
+  (
+    "EUH203",
+    "Enthält Chrom(VI). Kann allergische Reaktionen hervorrufen.",
+    "Contains chromium (VI). May produce an allergic reaction.",
+  ),
+  (
+    "EUH204",
+    "Enthält Isocyanate. Kann allergische Reaktionen hervorrufen.",
+    "Contains isocyanates. May produce an allergic reaction.",
+  ),
+  (
+    "EUH205",
+    "Enthält epoxidhaltige Verbindungen. Kann allergische Reaktionen hervorrufen.",
+    "Contains epoxy constituents. May produce an allergic reaction.",
+  ),
+  (
+    "EUH206",
+    "Achtung! Nicht zusammen mit anderen Produkten verwenden, da gefährliche Gase (Chlor) freigesetzt werden können.",
+    "Warning! Do not use together with other products. May release dangerous gases (chlorine).",
+  ),
+  (
+    "EUH207",
+    "Achtung! Enthält Cadmium. Bei der Verwendung entstehen gefährliche Dämpfe. Hinweise des Herstellers beachten. \
+     Sicherheitsanweisungen einhalten.",
+    "Warning! Contains cadmium. Dangerous fumes are formed during use. See information supplied by the manufacturer. \
+     Comply with the safety instructions.",
+  ),
+  (
+    "EUH208",
+    "Enthält …. Kann allergische Reaktionen hervorrufen.",
+    "Contains …. May produce an allergic reaction.",
+  ),
+  (
+    "EUH209",
+    "Kann bei Verwendung leicht entzündbar werden.",
+    "Can become highly flammable in use.",
+  ),
+  (
+    "EUH209A",
+    "Kann bei Verwendung entzündbar werden.",
+    "Can become flammable in use.",
+  ),
+  (
+    "EUH210",
+    "Sicherheitsdatenblatt auf Anfrage erhältlich.",
+    "Safety data sheet available on request.",
+  ),
+  (
+    "EUH211",
+    "Achtung! Beim Sprühen können gefährliche lungengängige Tröpfchen entstehen. Aerosol oder Nebel nicht einatmen.",
+    "Warning! Hazardous respirable droplets may be formed when sprayed. Do not breathe spray or mist.",
+  ),
+  (
+    "EUH212",
+    "Achtung! Bei der Verwendung können gefährliche lungengängige Stäube entstehen. Staub nicht einatmen.",
+    "Warning! Hazardous respirable dust may be formed when used. Do not breathe dust.",
+  ),
+  (
+    "EUH401",
+    "Zur Vermeidung von Risiken für Mensch und Umwelt die Gebrauchsanleitung einhalten.",
+    "To avoid risks to human health and the environment, comply with the instructions for use.",
+  ),
+];
+
+/* #endregion */
+
+/* #region precautionary statements */
+
+pub const PRECAUTIONARY_STATEMENTS: &[(&str, &str, &str)] = &[
+  // general
+  (
+    "P101",
+    "Ist ärztlicher Rat erforderlich, Verpackung oder Kennzeichnungsetikett des Produkts bereithalten.",
+    "If medical advice is needed, have product container or label at hand.",
+  ),
+  (
+    "P102",
+    "Darf nicht in die Hände von Kindern gelangen.",
+    "Keep out of reach of children.",
+  ),
+  (
+    "P103",
+    "Vor Gebrauch Kennzeichnungsetikett lesen.",
+    "Read label before use.",
+  ),
+  // prevention
+  (
+    "P201",
+    "Vor Gebrauch besondere Anweisungen einholen.",
+    "Obtain special instructions before use.",
+  ),
+  (
+    "P202",
+    "Vor Gebrauch alle Sicherheitshinweise lesen und verstehen.",
+    "Do not handle until all safety precautions have been read and understood.",
+  ),
+  (
+    "P210",
+    "Von Hitze, heißen Oberflächen, Funken, offenen Flammen und anderen Zündquellen fernhalten. Nicht rauchen.",
+    "Keep away from heat, hot surfaces, sparks, open flames and other ignition sources. No smoking.",
+  ),
+  (
+    "P211",
+    "Nicht gegen offene Flamme oder andere Zündquelle sprühen.",
+    "Do not spray on an open flame or other ignition source.",
+  ),
+  (
+    "P212",
+    "Erhitzen unter Einschluss und Reduzierung des Desensibilisierungsmittels vermeiden.",
+    "Avoid heating under confinement or reduction of the desensitising agent.",
+  ),
+  (
+    "P220",
+    "Von Kleidung und anderen brennbaren Materialien fernhalten.",
+    "Keep away from clothing and other combustible materials.",
+  ),
+  (
+    "P222",
+    "Keinen Kontakt mit Luft zulassen.",
+    "Do not allow contact with air.",
+  ),
+  (
+    "P223",
+    "Keinen Kontakt mit Wasser zulassen.",
+    "Do not allow contact with water.",
+  ),
+  ("P230", "Feucht halten mit ….", "Keep wetted with …."),
+  (
+    "P231",
+    "Inhalt unter inertem Gas/… handhaben und aufbewahren.",
+    "Handle and store contents under inert gas/….",
+  ),
+  ("P232", "Vor Feuchtigkeit schützen.", "Protect from moisture."),
+  (
+    "P233",
+    "Behälter dicht verschlossen halten.",
+    "Keep container tightly closed.",
+  ),
+  (
+    "P234",
+    "Nur in Originalverpackung aufbewahren.",
+    "Keep only in original packaging.",
+  ),
+  ("P235", "Kühl halten.", "Keep cool."),
+  (
+    "P240",
+    "Behälter und zu befüllende Anlage erden.",
+    "Ground and bond container and receiving equipment.",
+  ),
+  (
+    "P241",
+    "Explosionsgeschützte [elektrische/Lüftungs-/Beleuchtungs-/…] Geräte verwenden.",
+    "Use explosion-proof [electrical/ventilating/lighting/…] equipment.",
+  ),
+  ("P242", "Funkenarmes Werkzeug verwenden.", "Use non-sparking tools."),
+  (
+    "P243",
+    "Maßnahmen gegen elektrostatische Entladungen treffen.",
+    "Take action to prevent static discharges.",
+  ),
+  (
+    "P244",
+    "Ventile und Ausrüstungsteile öl- und fettfrei halten.",
+    "Keep valves and fittings free from oil and grease.",
+  ),
+  (
+    "P250",
+    "Nicht schleifen/stoßen/reiben/….",
+    "Do not subject to grinding/shock/friction/….",
+  ),
+  (
+    "P251",
+    "Nicht durchstechen oder verbrennen, auch nicht nach Gebrauch.",
+    "Do not pierce or burn, even after use.",
+  ),
+  (
+    "P260",
+    "Staub/Rauch/Gas/Nebel/Dampf/Aerosol nicht einatmen.",
+    "Do not breathe dust/fume/gas/mist/vapours/spray.",
+  ),
+  (
+    "P261",
+    "Einatmen von Staub/Rauch/Gas/Nebel/Dampf/Aerosol vermeiden.",
+    "Avoid breathing dust/fume/gas/mist/vapours/spray.",
+  ),
+  (
+    "P262",
+    "Nicht in die Augen, auf die Haut oder auf die Kleidung gelangen lassen.",
+    "Do not get in eyes, on skin, or on clothing.",
+  ),
+  (
+    "P263",
+    "Berührung während der Schwangerschaft und der Stillzeit vermeiden.",
+    "Avoid contact during pregnancy and while nursing.",
+  ),
+  (
+    "P264",
+    "Nach Gebrauch … gründlich waschen.",
+    "Wash … thoroughly after handling.",
+  ),
+  (
+    "P270",
+    "Bei Gebrauch nicht essen, trinken oder rauchen.",
+    "Do not eat, drink or smoke when using this product.",
+  ),
+  (
+    "P271",
+    "Nur im Freien oder in gut belüfteten Räumen verwenden.",
+    "Use only outdoors or in a well-ventilated area.",
+  ),
+  (
+    "P272",
+    "Kontaminierte Arbeitskleidung nicht außerhalb des Arbeitsplatzes tragen.",
+    "Contaminated work clothing should not be allowed out of the workplace.",
+  ),
+  (
+    "P273",
+    "Freisetzung in die Umwelt vermeiden.",
+    "Avoid release to the environment.",
+  ),
+  (
+    "P280",
+    "Schutzhandschuhe/Schutzkleidung/Augenschutz/Gesichtsschutz tragen.",
+    "Wear protective gloves/protective clothing/eye protection/face protection.",
+  ),
+  (
+    "P282",
+    "Schutzhandschuhe mit Kälteisolierung und zusätzlich Gesichtsschild oder Augenschutz tragen.",
+    "Wear cold insulating gloves and either face shield or eye protection.",
+  ),
+  (
+    "P283",
+    "Schwer entflammbare oder flammhemmende Kleidung tragen.",
+    "Wear fire resistant or flame retardant clothing.",
+  ),
+  (
+    "P284",
+    "[Bei unzureichender Belüftung] Atemschutz tragen.",
+    "[In case of inadequate ventilation] wear respiratory protection.",
+  ),
+  (
+    "P231+P232",
+    "Inhalt unter inertem Gas/… handhaben und aufbewahren. Vor Feuchtigkeit schützen.",
+    "Handle and store contents under inert gas/…. Protect from moisture.",
+  ),
+  // response
+  ("P301", "BEI VERSCHLUCKEN:", "IF SWALLOWED:"),
+  ("P302", "BEI BERÜHRUNG MIT DER HAUT:", "IF ON SKIN:"),
+  (
+    "P303",
+    "BEI BERÜHRUNG MIT DER HAUT (oder dem Haar):",
+    "IF ON SKIN (or hair):",
+  ),
+  ("P304", "BEI EINATMEN:", "IF INHALED:"),
+  ("P305", "BEI KONTAKT MIT DEN AUGEN:", "IF IN EYES:"),
+  ("P306", "BEI KONTAMINIERTER KLEIDUNG:", "IF ON CLOTHING:"),
+  (
+    "P308",
+    "BEI Exposition oder falls betroffen:",
+    "IF exposed or concerned:",
+  ),
+  (
+    "P310",
+    "Sofort GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "Immediately call a POISON CENTER/doctor/….",
+  ),
+  (
+    "P311",
+    "GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "Call a POISON CENTER/doctor/….",
+  ),
+  (
+    "P312",
+    "Bei Unwohlsein GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "Call a POISON CENTER/doctor/… if you feel unwell.",
+  ),
+  (
+    "P313",
+    "Ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "Get medical advice/attention.",
+  ),
+  (
+    "P314",
+    "Bei Unwohlsein ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "Get medical advice/attention if you feel unwell.",
+  ),
+  (
+    "P315",
+    "Sofort ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "Get immediate medical advice/attention.",
+  ),
+  (
+    "P320",
+    "Besondere Behandlung dringend erforderlich (siehe … auf diesem Kennzeichnungsetikett).",
+    "Specific treatment is urgent (see … on this label).",
+  ),
+  (
+    "P321",
+    "Besondere Behandlung (siehe … auf diesem Kennzeichnungsetikett).",
+    "Specific treatment (see … on this label).",
+  ),
+  ("P330", "Mund ausspülen.", "Rinse mouth."),
+  ("P331", "KEIN Erbrechen herbeiführen.", "Do NOT induce vomiting."),
+  ("P332", "Bei Hautreizung:", "If skin irritation occurs:"),
+  (
+    "P333",
+    "Bei Hautreizung oder -ausschlag:",
+    "If skin irritation or rash occurs:",
+  ),
+  (
+    "P334",
+    "In kaltes Wasser tauchen [oder nassen Verband anlegen].",
+    "Immerse in cool water [or wrap in wet bandages].",
+  ),
+  (
+    "P335",
+    "Lose Partikel von der Haut abbürsten.",
+    "Brush off loose particles from skin.",
+  ),
+  (
+    "P336",
+    "Vereiste Bereiche mit lauwarmem Wasser auftauen. Betroffenen Bereich nicht reiben.",
+    "Thaw frosted parts with lukewarm water. Do not rub affected area.",
+  ),
+  ("P337", "Bei anhaltender Augenreizung:", "If eye irritation persists:"),
+  (
+    "P338",
+    "Eventuell vorhandene Kontaktlinsen nach Möglichkeit entfernen. Weiter ausspülen.",
+    "Remove contact lenses, if present and easy to do. Continue rinsing.",
+  ),
+  (
+    "P340",
+    "Die Person an die frische Luft bringen und für ungehinderte Atmung sorgen.",
+    "Remove person to fresh air and keep comfortable for breathing.",
+  ),
+  (
+    "P342",
+    "Bei Symptomen der Atemwege:",
+    "If experiencing respiratory symptoms:",
+  ),
+  (
+    "P351",
+    "Einige Minuten lang behutsam mit Wasser ausspülen.",
+    "Rinse cautiously with water for several minutes.",
+  ),
+  ("P352", "Mit viel Wasser/… waschen.", "Wash with plenty of water/…."),
+  (
+    "P353",
+    "Haut mit Wasser abwaschen [oder duschen].",
+    "Rinse skin with water [or shower].",
+  ),
+  (
+    "P360",
+    "Kontaminierte Kleidung und Haut sofort mit viel Wasser abwaschen und danach Kleidung ausziehen.",
+    "Rinse immediately contaminated clothing and skin with plenty of water before removing clothes.",
+  ),
+  (
+    "P361",
+    "Alle kontaminierten Kleidungsstücke sofort ausziehen.",
+    "Take off immediately all contaminated clothing.",
+  ),
+  (
+    "P362",
+    "Kontaminierte Kleidung ausziehen.",
+    "Take off contaminated clothing.",
+  ),
+  (
+    "P363",
+    "Kontaminierte Kleidung vor erneutem Tragen waschen.",
+    "Wash contaminated clothing before reuse.",
+  ),
+  ("P364", "Und vor erneutem Tragen waschen.", "And wash it before reuse."),
+  ("P370", "Bei Brand:", "In case of fire:"),
+  (
+    "P371",
+    "Bei Großbrand und großen Mengen:",
+    "In case of major fire and large quantities:",
+  ),
+  ("P372", "Explosionsgefahr.", "Explosion risk."),
+  (
+    "P373",
+    "KEINE Brandbekämpfung, wenn das Feuer explosive Stoffe/Gemische/Erzeugnisse erreicht.",
+    "DO NOT fight fire when fire reaches explosives.",
+  ),
+  (
+    "P375",
+    "Wegen Explosionsgefahr Brand aus der Entfernung bekämpfen.",
+    "Fight fire remotely due to the risk of explosion.",
+  ),
+  (
+    "P376",
+    "Undichtigkeit beseitigen, wenn gefahrlos möglich.",
+    "Stop leak if safe to do so.",
+  ),
+  (
+    "P377",
+    "Brand von ausströmendem Gas: Nicht löschen, bis Undichtigkeit gefahrlos beseitigt werden kann.",
+    "Leaking gas fire: Do not extinguish, unless leak can be stopped safely.",
+  ),
+  ("P378", "… zum Löschen verwenden.", "Use … to extinguish."),
+  ("P380", "Umgebung räumen.", "Evacuate area."),
+  (
+    "P381",
+    "Bei Undichtigkeit alle Zündquellen entfernen.",
+    "In case of leakage, eliminate all ignition sources.",
+  ),
+  (
+    "P390",
+    "Verschüttete Mengen aufnehmen, um Materialschäden zu vermeiden.",
+    "Absorb spillage to prevent material damage.",
+  ),
+  ("P391", "Verschüttete Mengen aufnehmen.", "Collect spillage."),
+  (
+    "P301+P310",
+    "BEI VERSCHLUCKEN: Sofort GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "IF SWALLOWED: Immediately call a POISON CENTER/doctor/….",
+  ),
+  (
+    "P301+P312",
+    "BEI VERSCHLUCKEN: Bei Unwohlsein GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "IF SWALLOWED: Call a POISON CENTER/doctor/… if you feel unwell.",
+  ),
+  (
+    "P301+P330+P331",
+    "BEI VERSCHLUCKEN: Mund ausspülen. KEIN Erbrechen herbeiführen.",
+    "IF SWALLOWED: Rinse mouth. Do NOT induce vomiting.",
+  ),
+  (
+    "P302+P334",
+    "BEI BERÜHRUNG MIT DER HAUT: In kaltes Wasser tauchen oder nassen Verband anlegen.",
+    "IF ON SKIN: Immerse in cool water or wrap in wet bandages.",
+  ),
+  (
+    "P302+P335+P334",
+    "BEI BERÜHRUNG MIT DER HAUT: Lose Partikel von der Haut abbürsten. In kaltes Wasser tauchen [oder nassen Verband \
+     anlegen].",
+    "IF ON SKIN: Brush off loose particles from skin. Immerse in cool water [or wrap in wet bandages].",
+  ),
+  (
+    "P302+P352",
+    "BEI BERÜHRUNG MIT DER HAUT: Mit viel Wasser/… waschen.",
+    "IF ON SKIN: Wash with plenty of water/….",
+  ),
+  (
+    "P303+P361+P353",
+    "BEI BERÜHRUNG MIT DER HAUT (oder dem Haar): Alle kontaminierten Kleidungsstücke sofort ausziehen. Haut mit \
+     Wasser abwaschen [oder duschen].",
+    "IF ON SKIN (or hair): Take off immediately all contaminated clothing. Rinse skin with water [or shower].",
+  ),
+  (
+    "P304+P340",
+    "BEI EINATMEN: Die Person an die frische Luft bringen und für ungehinderte Atmung sorgen.",
+    "IF INHALED: Remove person to fresh air and keep comfortable for breathing.",
+  ),
+  (
+    "P305+P351+P338",
+    "BEI KONTAKT MIT DEN AUGEN: Einige Minuten lang behutsam mit Wasser ausspülen. Eventuell vorhandene \
+     Kontaktlinsen nach Möglichkeit entfernen. Weiter ausspülen.",
+    "IF IN EYES: Rinse cautiously with water for several minutes. Remove contact lenses, if present and easy to do. \
+     Continue rinsing.",
+  ),
+  (
+    "P306+P360",
+    "BEI KONTAKT MIT DER KLEIDUNG: Kontaminierte Kleidung und Haut sofort mit viel Wasser abwaschen und danach \
+     Kleidung ausziehen.",
+    "IF ON CLOTHING: Rinse immediately contaminated clothing and skin with plenty of water before removing clothes.",
+  ),
+  (
+    "P308+P311",
+    "BEI Exposition oder falls betroffen: GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "IF exposed or concerned: Call a POISON CENTER/doctor/….",
+  ),
+  (
+    "P308+P313",
+    "BEI Exposition oder falls betroffen: Ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "IF exposed or concerned: Get medical advice/attention.",
+  ),
+  (
+    "P332+P313",
+    "Bei Hautreizung: Ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "If skin irritation occurs: Get medical advice/attention.",
+  ),
+  (
+    "P333+P313",
+    "Bei Hautreizung oder -ausschlag: Ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "If skin irritation or rash occurs: Get medical advice/attention.",
+  ),
+  (
+    "P336+P315",
+    "Vereiste Bereiche mit lauwarmem Wasser auftauen. Betroffenen Bereich nicht reiben. Sofort ärztlichen Rat \
+     einholen/ärztliche Hilfe hinzuziehen.",
+    "Thaw frosted parts with lukewarm water. Do not rub affected area. Get immediate medical advice/attention.",
+  ),
+  (
+    "P337+P313",
+    "Bei anhaltender Augenreizung: Ärztlichen Rat einholen/ärztliche Hilfe hinzuziehen.",
+    "If eye irritation persists: Get medical advice/attention.",
+  ),
+  (
+    "P342+P311",
+    "Bei Symptomen der Atemwege: GIFTINFORMATIONSZENTRUM/Arzt/… anrufen.",
+    "If experiencing respiratory symptoms: Call a POISON CENTER/doctor/….",
+  ),
+  (
+    "P361+P364",
+    "Alle kontaminierten Kleidungsstücke sofort ausziehen und vor erneutem Tragen waschen.",
+    "Take off immediately all contaminated clothing and wash it before reuse.",
+  ),
+  (
+    "P362+P364",
+    "Kontaminierte Kleidung ausziehen und vor erneutem Tragen waschen.",
+    "Take off contaminated clothing and wash it before reuse.",
+  ),
+  (
+    "P370+P376",
+    "Bei Brand: Undichtigkeit beseitigen, wenn gefahrlos möglich.",
+    "In case of fire: Stop leak if safe to do so.",
+  ),
+  (
+    "P370+P378",
+    "Bei Brand: … zum Löschen verwenden.",
+    "In case of fire: Use … to extinguish.",
+  ),
+  (
+    "P370+P380+P375",
+    "Bei Brand: Umgebung räumen. Wegen Explosionsgefahr Brand aus der Entfernung bekämpfen.",
+    "In case of fire: Evacuate area. Fight fire remotely due to the risk of explosion.",
+  ),
+  (
+    "P371+P380+P375",
+    "Bei Großbrand und großen Mengen: Umgebung räumen. Wegen Explosionsgefahr Brand aus der Entfernung bekämpfen.",
+    "In case of major fire and large quantities: Evacuate area. Fight fire remotely due to the risk of explosion.",
+  ),
+  (
+    "P370+P372+P380+P373",
+    "Bei Brand: Explosionsgefahr. Umgebung räumen. KEINE Brandbekämpfung, wenn das Feuer explosive \
+     Stoffe/Gemische/Erzeugnisse erreicht.",
+    "In case of fire: Explosion risk. Evacuate area. DO NOT fight fire when fire reaches explosives.",
+  ),
+  // storage
+  ("P401", "Aufbewahren gemäß ….", "Store in accordance with …."),
+  ("P402", "An einem trockenen Ort aufbewahren.", "Store in a dry place."),
+  (
+    "P403",
+    "An einem gut belüfteten Ort aufbewahren.",
+    "Store in a well-ventilated place.",
+  ),
+  (
+    "P404",
+    "In einem geschlossenen Behälter aufbewahren.",
+    "Store in a closed container.",
+  ),
+  ("P405", "Unter Verschluss aufbewahren.", "Store locked up."),
+  (
+    "P406",
+    "In korrosionsbeständigem/… Behälter mit widerstandsfähiger Innenauskleidung aufbewahren.",
+    "Store in a corrosion resistant/… container with a resistant inner liner.",
+  ),
+  (
+    "P407",
+    "Luftspalt zwischen Stapeln oder Paletten lassen.",
+    "Maintain air gap between stacks or pallets.",
+  ),
+  ("P410", "Vor Sonnenbestrahlung schützen.", "Protect from sunlight."),
+  (
+    "P411",
+    "Bei Temperaturen nicht über … °C aufbewahren.",
+    "Store at temperatures not exceeding … °C.",
+  ),
+  (
+    "P412",
+    "Nicht Temperaturen über 50 °C aussetzen.",
+    "Do not expose to temperatures exceeding 50 °C.",
+  ),
+  (
+    "P413",
+    "Schüttgut in Mengen von mehr als … kg bei Temperaturen nicht über … °C aufbewahren.",
+    "Store bulk masses greater than … kg at temperatures not exceeding … °C.",
+  ),
+  ("P420", "Getrennt aufbewahren.", "Store separately."),
+  (
+    "P402+P404",
+    "An einem trockenen Ort aufbewahren. In einem geschlossenen Behälter aufbewahren.",
+    "Store in a dry place. Store in a closed container.",
+  ),
+  (
+    "P403+P233",
+    "An einem gut belüfteten Ort aufbewahren. Behälter dicht verschlossen halten.",
+    "Store in a well-ventilated place. Keep container tightly closed.",
+  ),
+  (
+    "P403+P235",
+    "An einem gut belüfteten Ort aufbewahren. Kühl halten.",
+    "Store in a well-ventilated place. Keep cool.",
+  ),
+  (
+    "P410+P403",
+    "Vor Sonnenbestrahlung schützen. An einem gut belüfteten Ort aufbewahren.",
+    "Protect from sunlight. Store in a well-ventilated place.",
+  ),
+  (
+    "P410+P412",
+    "Vor Sonnenbestrahlung schützen und nicht Temperaturen über 50 °C aussetzen.",
+    "Protect from sunlight. Do not expose to temperatures exceeding 50 °C.",
+  ),
+  // disposal
+  (
+    "P501",
+    "Inhalt/Behälter … zuführen.",
+    "Dispose of contents/container to ….",
+  ),
+  (
+    "P502",
+    "Informationen zur Wiederverwendung oder Wiederverwertung beim Hersteller oder Lieferanten erfragen.",
+    "Refer to manufacturer or supplier for information on recovery or recycling.",
+  ),
+  (
+    "P503",
+    "Informationen zur Entsorgung/Wiederverwendung/Wiederverwertung beim Hersteller/Lieferanten/… erfragen.",
+    "Refer to manufacturer/supplier/… for information on disposal/recovery/recycling.",
+  ),
+];
+
+/* #endregion */
@@ -0,0 +1,357 @@
+use serde::Serialize;
+
+use super::{is_known_hazard, is_known_precaution, split_code};
+use crate::{Language, SubstanceData};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum SignalWord {
+  Warning,
+  Danger,
+}
+
+impl SignalWord {
+  fn matches(&self, word: &str) -> bool {
+    let word = word.trim().to_lowercase();
+    match self {
+      SignalWord::Warning => word == "achtung" || word == "warning",
+      SignalWord::Danger => word == "gefahr" || word == "danger",
+    }
+  }
+
+  pub(super) fn localized(&self, language: Language) -> &'static str {
+    match (self, language) {
+      (SignalWord::Warning, Language::German) => "Achtung",
+      (SignalWord::Warning, Language::English) => "Warning",
+      (SignalWord::Danger, Language::German) => "Gefahr",
+      (SignalWord::Danger, Language::English) => "Danger",
+    }
+  }
+}
+
+/// Pictograms and signal word every substance with the hazard statement is labelled with (CLP Annex I).
+/// Statements used for several categories with different labels only list what all categories have in common.
+const HAZARD_LABELS: &[Label] = &[
+  ("H200", &["ghs01"], Some(SignalWord::Danger)),
+  ("H201", &["ghs01"], Some(SignalWord::Danger)),
+  ("H202", &["ghs01"], Some(SignalWord::Danger)),
+  ("H203", &["ghs01"], Some(SignalWord::Danger)),
+  ("H204", &["ghs01"], Some(SignalWord::Warning)),
+  ("H205", &[], Some(SignalWord::Danger)),
+  ("H206", &["ghs02"], Some(SignalWord::Danger)),
+  ("H207", &["ghs02"], None),
+  ("H208", &["ghs02"], Some(SignalWord::Warning)),
+  ("H220", &["ghs02"], Some(SignalWord::Danger)),
+  ("H222", &["ghs02"], Some(SignalWord::Danger)),
+  ("H223", &["ghs02"], Some(SignalWord::Warning)),
+  ("H224", &["ghs02"], Some(SignalWord::Danger)),
+  ("H225", &["ghs02"], Some(SignalWord::Danger)),
+  ("H226", &["ghs02"], Some(SignalWord::Warning)),
+  ("H228", &["ghs02"], None),
+  ("H232", &["ghs02"], Some(SignalWord::Danger)),
+  ("H240", &["ghs01"], Some(SignalWord::Danger)),
+  ("H241", &["ghs01", "ghs02"], Some(SignalWord::Danger)),
+  ("H242", &["ghs02"], None),
+  ("H250", &["ghs02"], Some(SignalWord::Danger)),
+  ("H251", &["ghs02"], Some(SignalWord::Danger)),
+  ("H252", &["ghs02"], Some(SignalWord::Warning)),
+  ("H260", &["ghs02"], Some(SignalWord::Danger)),
+  ("H261", &["ghs02"], None),
+  ("H270", &["ghs03"], Some(SignalWord::Danger)),
+  ("H271", &["ghs03"], Some(SignalWord::Danger)),
+  ("H272", &["ghs03"], None),
+  ("H280", &["ghs04"], Some(SignalWord::Warning)),
+  ("H281", &["ghs04"], Some(SignalWord::Warning)),
+  ("H290", &["ghs05"], Some(SignalWord::Warning)),
+  ("H300", &["ghs06"], Some(SignalWord::Danger)),
+  ("H301", &["ghs06"], Some(SignalWord::Danger)),
+  ("H302", &["ghs07"], Some(SignalWord::Warning)),
+  ("H304", &["ghs08"], Some(SignalWord::Danger)),
+  ("H310", &["ghs06"], Some(SignalWord::Danger)),
+  ("H311", &["ghs06"], Some(SignalWord::Danger)),
+  ("H312", &["ghs07"], Some(SignalWord::Warning)),
+  ("H314", &["ghs05"], Some(SignalWord::Danger)),
+  ("H315", &["ghs07"], Some(SignalWord::Warning)),
+  ("H317", &["ghs07"], Some(SignalWord::Warning)),
+  ("H318", &["ghs05"], Some(SignalWord::Danger)),
+  ("H319", &["ghs07"], Some(SignalWord::Warning)),
+  ("H330", &["ghs06"], Some(SignalWord::Danger)),
+  ("H331", &["ghs06"], Some(SignalWord::Danger)),
+  ("H332", &["ghs07"], Some(SignalWord::Warning)),
+  ("H334", &["ghs08"], Some(SignalWord::Danger)),
+  ("H335", &["ghs07"], Some(SignalWord::Warning)),
+  ("H336", &["ghs07"], Some(SignalWord::Warning)),
+  ("H340", &["ghs08"], Some(SignalWord::Danger)),
+  ("H341", &["ghs08"], Some(SignalWord::Warning)),
+  ("H350", &["ghs08"], Some(SignalWord::Danger)),
+  ("H350i", &["ghs08"], Some(SignalWord::Danger)),
+  ("H351", &["ghs08"], Some(SignalWord::Warning)),
+  ("H360", &["ghs08"], Some(SignalWord::Danger)),
+  ("H360F", &["ghs08"], Some(SignalWord::Danger)),
+  ("H360D", &["ghs08"], Some(SignalWord::Danger)),
+  ("H360FD", &["ghs08"], Some(SignalWord::Danger)),
+  ("H360Fd", &["ghs08"], Some(SignalWord::Danger)),
+  ("H360Df", &["ghs08"], Some(SignalWord::Danger)),
+  ("H361", &["ghs08"], Some(SignalWord::Warning)),
+  ("H361f", &["ghs08"], Some(SignalWord::Warning)),
+  ("H361d", &["ghs08"], Some(SignalWord::Warning)),
+  ("H361fd", &["ghs08"], Some(SignalWord::Warning)),
+  ("H370", &["ghs08"], Some(SignalWord::Danger)),
+  ("H371", &["ghs08"], Some(SignalWord::Warning)),
+  ("H372", &["ghs08"], Some(SignalWord::Danger)),
+  ("H373", &["ghs08"], Some(SignalWord::Warning)),
+  ("H400", &["ghs09"], Some(SignalWord::Warning)),
+  ("H410", &["ghs09"], Some(SignalWord::Warning)),
+  ("H411", &["ghs09"], None),
+  ("H420", &["ghs07"], Some(SignalWord::Warning)),
+];
+
+type Label = (&'static str, &'static [&'static str], Option<SignalWord>);
+
+/// The labels of all known parts of the hazard statements.
+fn labels(h_phrases: &[&str]) -> Vec<Label> {
+  h_phrases
+    .iter()
+    .flat_map(|code| split_code(code))
+    .filter_map(|code| HAZARD_LABELS.iter().find(|(c, _, _)| *c == code))
+    .copied()
+    .collect()
+}
+
+/// Precedence rules of CLP article 26, some pictograms are not needed if a more severe one is present.
+fn is_superseded(symbol: &str, phrase: &str, labels: &[Label]) -> bool {
+  let required = |symbol: &str| labels.iter().any(|(_, symbols, _)| symbols.contains(&symbol));
+  match symbol {
+    "ghs07" => {
+      required("ghs06")
+        || (required("ghs05") && ["H315", "H319"].contains(&phrase))
+        || (labels.iter().any(|(p, _, _)| *p == "H334") && ["H315", "H317", "H319"].contains(&phrase))
+    }
+    "ghs04" => required("ghs02") || required("ghs06"),
+    _ => false,
+  }
+}
+
+/// Pictograms that may be omitted, but don't have to be, if a more severe one is present.
+fn is_optional(symbol: &str, labels: &[Label]) -> bool {
+  symbol == "ghs04" && labels.iter().any(|(_, symbols, _)| symbols.contains(&symbol))
+}
+
+/// The pictograms and signal word a substance or mixture with the hazard statements has to be labelled with.
+/// "Danger" replaces "Warning", phrases without a fixed signal word don't contribute one.
+pub(super) fn required_labels(h_phrases: &[&str]) -> (Vec<&'static str>, Option<SignalWord>) {
+  let labels = labels(h_phrases);
+  let mut symbols: Vec<&str> = labels
+    .iter()
+    .flat_map(|(phrase, symbols, _)| {
+      symbols
+        .iter()
+        .copied()
+        .filter(|symbol| !is_superseded(symbol, phrase, &labels))
+        .collect::<Vec<_>>()
+    })
+    .collect();
+  symbols.sort_unstable();
+  symbols.dedup();
+
+  let signal_word = labels.iter().filter_map(|(_, _, signal_word)| *signal_word).max();
+  (symbols, signal_word)
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum ValidationWarning {
+  /// The code is not part of the CLP regulation.
+  UnknownHPhrase {
+    code: String,
+  },
+  UnknownPPhrase {
+    code: String,
+  },
+  /// A pictogram required by the hazard statement is missing.
+  MissingSymbol {
+    symbol: String,
+    phrase: String,
+  },
+  /// The pictogram isn't required by any hazard statement or is superseded by a more severe one.
+  UnexpectedSymbol {
+    symbol: String,
+  },
+  /// The signal word doesn't match the most severe hazard statement, `found` is `None` if it is missing.
+  SignalWord {
+    expected: String,
+    found: Option<String>,
+  },
+}
+
+/// Checks the phrases, pictograms and signal word of the substance against each other.
+/// The expected signal word is reported in the given language.
+pub fn validate(substance: &SubstanceData, language: Language) -> Vec<ValidationWarning> {
+  let mut warnings = Vec::new();
+
+  let h_phrases: Vec<&str> = substance
+    .h_phrases
+    .current()
+    .iter()
+    .map(|(code, _)| code.as_str())
+    .collect();
+  for code in h_phrases.iter().filter(|code| !is_known_hazard(code)) {
+    warnings.push(ValidationWarning::UnknownHPhrase { code: code.to_string() });
+  }
+  for (code, _) in substance
+    .p_phrases
+    .current()
+    .iter()
+    .filter(|(code, _)| !is_known_precaution(code))
+  {
+    warnings.push(ValidationWarning::UnknownPPhrase { code: code.clone() });
+  }
+
+  let labels = labels(&h_phrases);
+  let symbols = substance.symbols.current();
+  for (phrase, phrase_symbols, _) in labels.iter() {
+    for symbol in phrase_symbols.iter() {
+      if !is_superseded(symbol, phrase, &labels) && !symbols.iter().any(|s| s == symbol) {
+        warnings.push(ValidationWarning::MissingSymbol {
+          symbol: symbol.to_string(),
+          phrase: phrase.to_string(),
+        });
+      }
+    }
+  }
+  let (required_symbols, _) = required_labels(&h_phrases);
+  for symbol in symbols
+    .iter()
+    .filter(|symbol| !required_symbols.contains(&symbol.as_str()) && !is_optional(symbol, &labels))
+  {
+    warnings.push(ValidationWarning::UnexpectedSymbol { symbol: symbol.clone() });
+  }
+
+  // phrases without a fixed signal word can't be checked
+  if let (_, Some(expected)) = required_labels(&h_phrases) {
+    // an empty signal word was removed by the user or by the classification of a solution
+    let found = substance
+      .signal_word
+      .current()
+      .as_deref()
+      .filter(|word| !word.is_empty());
+    let matches = match found {
+      Some(word) => expected.matches(word),
+      None => false,
+    };
+    if !matches {
+      warnings.push(ValidationWarning::SignalWord {
+        expected: expected.localized(language).into(),
+        found: found.map(String::from),
+      });
+    }
+  }
+
+  warnings
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Data;
+
+  fn substance(h_phrases: &[&str], p_phrases: &[&str], symbols: &[&str], signal_word: Option<&str>) -> SubstanceData {
+    let phrases = |codes: &[&str]| codes.iter().map(|c| (c.to_string(), String::new())).collect();
+    SubstanceData {
+      name: Data::new("Test".into()),
+      h_phrases: Data::new(phrases(h_phrases)),
+      p_phrases: Data::new(phrases(p_phrases)),
+      signal_word: Data::new(signal_word.map(|s| s.into())),
+      symbols: Data::new(symbols.iter().map(|s| s.to_string()).collect()),
+      ..SubstanceData::default()
+    }
+  }
+
+  #[test]
+  fn test_consistent_data() {
+    let ethanol = substance(
+      &["H225", "H319"],
+      &["P210", "P305+P351+P338"],
+      &["ghs02", "ghs07"],
+      Some("Gefahr"),
+    );
+    assert!(validate(&ethanol, Language::German).is_empty());
+
+    // ghs07 is superseded by ghs06
+    let methanol = substance(
+      &["H225", "H301+H311+H331", "H370"],
+      &[],
+      &["ghs02", "ghs06", "ghs08"],
+      Some("Danger"),
+    );
+    assert!(validate(&methanol, Language::English).is_empty());
+  }
+
+  #[test]
+  fn test_unknown_codes() {
+    let data = substance(&["H999", "Achtung"], &["P210+P999"], &[], None);
+    assert_eq!(
+      validate(&data, Language::German),
+      vec![
+        ValidationWarning::UnknownHPhrase { code: "H999".into() },
+        ValidationWarning::UnknownHPhrase { code: "Achtung".into() },
+        ValidationWarning::UnknownPPhrase {
+          code: "P210+P999".into()
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_symbols_and_signal_word() {
+    let data = substance(&["H300", "H319"], &[], &["ghs07"], Some("Achtung"));
+    assert_eq!(
+      validate(&data, Language::German),
+      vec![
+        ValidationWarning::MissingSymbol {
+          symbol: "ghs06".into(),
+          phrase: "H300".into()
+        },
+        ValidationWarning::UnexpectedSymbol { symbol: "ghs07".into() },
+        ValidationWarning::SignalWord {
+          expected: "Gefahr".into(),
+          found: Some("Achtung".into())
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_unexpected_symbols() {
+    // ghs07 is superseded by ghs06, ghs09 isn't required at all
+    let data = substance(&["H301", "H302"], &[], &["ghs06", "ghs07", "ghs09"], Some("Gefahr"));
+    assert_eq!(
+      validate(&data, Language::German),
+      vec![
+        ValidationWarning::UnexpectedSymbol { symbol: "ghs07".into() },
+        ValidationWarning::UnexpectedSymbol { symbol: "ghs09".into() },
+      ]
+    );
+
+    // ghs04 may be omitted next to ghs02, but it may also be shown
+    let data = substance(&["H220", "H280"], &[], &["ghs02", "ghs04"], Some("Gefahr"));
+    assert!(validate(&data, Language::German).is_empty());
+  }
+
+  #[test]
+  fn test_labels_of_explosives_and_desensitized_explosives() {
+    // Div. 1.5 has no pictogram
+    let data = substance(&["H205"], &[], &["ghs01"], Some("Gefahr"));
+    assert_eq!(
+      validate(&data, Language::German),
+      vec![ValidationWarning::UnexpectedSymbol { symbol: "ghs01".into() }]
+    );
+    assert_eq!(required_labels(&["H205"]), (vec![], Some(SignalWord::Danger)));
+
+    // category 2 is labelled with "Danger" and category 3 with "Warning"
+    for signal_word in ["Gefahr", "Achtung"].iter() {
+      let data = substance(&["H207"], &[], &["ghs02"], Some(signal_word));
+      assert!(validate(&data, Language::German).is_empty());
+    }
+    assert_eq!(required_labels(&["H207"]), (vec!["ghs02"], None));
+  }
+}
@@ -1,29 +1,55 @@
 #![allow(clippy::upper_case_acronyms)]
 
+pub mod conversion;
+pub mod ghs;
 pub mod logging;
+mod quantity;
+mod toxicity;
+
+#[cfg(feature = "webserver")]
+pub mod webserver;
 
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+pub use quantity::{Qualifier, Quantity, QuantityValue};
+pub use toxicity::{Toxicity, ToxicityType};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubstanceData {
   pub name: Data<String>,
   pub alternative_names: Vec<String>,
   pub cas: Data<Option<String>>,
   pub molecular_formula: Data<Option<String>>,
-  pub molar_mass: Data<Option<String>>,
-  pub melting_point: Data<Option<String>>,
-  pub boiling_point: Data<Option<String>>,
+  pub molar_mass: Data<Option<Quantity>>,
+  pub melting_point: Data<Option<Quantity>>,
+  pub boiling_point: Data<Option<Quantity>>,
+  #[serde(default)]
+  pub density: Data<Option<Quantity>>,
+  #[serde(default)]
+  pub flash_point: Data<Option<Quantity>>,
+  #[serde(default)]
+  pub ignition_temperature: Data<Option<Quantity>>,
+  /// The lower and upper explosion limit as range, e.g. `3,1 ... 27,7 Vol%`.
+  #[serde(default)]
+  pub explosion_limits: Data<Option<Quantity>>,
   pub water_hazard_class: Data<Option<String>>,
   pub h_phrases: Data<Vec<(String, String)>>,
   pub p_phrases: Data<Vec<(String, String)>>,
   pub signal_word: Data<Option<String>>,
   pub symbols: Data<Vec<String>>,
-  pub lethal_dose: Data<Option<String>>,
-  pub mak: Data<Option<String>>,
+  /// The value printed on the sheet, by default the one chosen by `Toxicity::preferred`.
+  pub lethal_dose: Data<Option<Quantity>>,
+  /// All acute toxicity values of the source.
+  #[serde(default)]
+  pub toxicity: Data<Vec<Toxicity>>,
+  pub mak: Data<Option<Quantity>>,
+  /// Dangerous reactions with other substances as described by the provider, one entry per line.
+  #[serde(default)]
+  pub reactions: Data<Vec<String>>,
   pub amount: Option<Amount>,
   pub source: Source,
 
@@ -31,7 +57,7 @@ pub struct SubstanceData {
   pub checked: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Data<T> {
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,6 +72,11 @@ impl<T> Data<T> {
       original_data: data,
     }
   }
+
+  /// The data modified by the user, otherwise the original data.
+  pub fn current(&self) -> &T {
+    self.modified_data.as_ref().unwrap_or(&self.original_data)
+  }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -56,13 +87,24 @@ pub struct Source {
   pub last_updated: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// A substance entered by the user.
+impl Default for Source {
+  fn default() -> Self {
+    Source {
+      provider: "custom".into(),
+      url: String::new(),
+      last_updated: Utc::now(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Amount {
   pub value: String,
   pub unit: Unit,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Unit {
   Litre,
@@ -131,4 +173,62 @@ impl std::convert::From<Unit> for std::string::String {
   }
 }
 
+impl Unit {
+  /// The inverse of `From<Unit> for String` for the units of physical properties, unknown symbols become `Custom`.
+  pub fn from_symbol(symbol: &str) -> Unit {
+    use Unit::*;
+    match symbol {
+      "l" | "L" => Litre,
+      "ml" | "mL" => Milliliter,
+      "µl" | "µL" => Microliter,
+      "g" => Gram,
+      "mg" => Milligram,
+      "µg" => Microgram,
+      "mol" => Mol,
+      "mmol" => Millimol,
+      "g/mol" => GramPerMol,
+      "mg/kg" => MilligramPerKilogram,
+      "mg/l" | "mg/L" => MilligramPerLiter,
+      // workplace limits of gases are given in ml/m³, which is the same as ppm
+      "ppm" | "ml/m³" => PartsPerMillion,
+      "°C" | "℃" => Celsius,
+      "°F" | "F" => Fahrenheit,
+      _ => Custom(symbol.into()),
+    }
+  }
+}
+
 pub type ProviderMapping = HashMap<String, String>;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Language {
+  German,
+  English,
+}
+
+/// The language of the original data of most providers.
+impl Default for Language {
+  fn default() -> Self {
+    Language::German
+  }
+}
+
+impl Language {
+  /// Maps the locale of the ui translation (`de_de`, `en_us`, ...) to the language of the substance data.
+  /// German is used for all locales without matching data.
+  pub fn from_locale(locale: &str) -> Language {
+    match locale.split('_').next() {
+      Some("en") => Language::English,
+      _ => Language::German,
+    }
+  }
+
+  /// Returns the ISO 639-1 code of the language
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Language::German => "de",
+      Language::English => "en",
+    }
+  }
+}
@@ -0,0 +1,405 @@
+use std::{cmp::Ordering, fmt};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Unit;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuantityValue {
+  Exact(f64),
+  Range(f64, f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Qualifier {
+  Approximately,
+  LessThan,
+  GreaterThan,
+  Decomposes,
+  Sublimes,
+}
+
+/// A physical quantity like a melting point or a molar mass.
+///
+/// The text is kept as it was written by the source, value, unit and qualifiers are parsed from it. Texts that
+/// aren't a quantity (e.g. `siehe Bemerkung`) are kept without value.
+/// Quantities are serialized as their text, so documents stay readable by older versions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity {
+  text: String,
+  value: Option<QuantityValue>,
+  unit: Option<Unit>,
+  qualifiers: Vec<Qualifier>,
+  /// Further values of the text, e.g. the mg/m³ of `200 ppm, 380 mg/m³`.
+  alternatives: Vec<Quantity>,
+}
+
+/* #region parsing */
+
+const APPROXIMATELY: [&str; 8] = ["ca.", "ca ", "approx.", "approx ", "etwa ", "about ", "~", "≈"];
+const LESS_THAN: [&str; 3] = ["<=", "<", "≤"];
+const GREATER_THAN: [&str; 3] = [">=", ">", "≥"];
+const DECOMPOSES: [&str; 3] = ["zers", "decomp", "dec."];
+const SUBLIMES: [&str; 1] = ["subl"];
+const RANGE_SEPARATORS: [&str; 7] = ["...", "…", "–", "—", "-", "bis ", "to "];
+
+fn strip_any<'a>(text: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+  prefixes
+    .iter()
+    .find(|prefix| match text.get(..prefix.len()) {
+      Some(start) => start.eq_ignore_ascii_case(prefix),
+      None => false,
+    })
+    .map(|prefix| text[prefix.len()..].trim_start())
+}
+
+/// Splits at `,` and `;` outside of parentheses, the number parser has already consumed decimal commas.
+fn split_values(text: &str) -> Vec<&str> {
+  let mut parts = Vec::new();
+  let mut depth = 0;
+  let mut start = 0;
+  for (i, c) in text.char_indices() {
+    match c {
+      '(' => depth += 1,
+      ')' => depth -= 1,
+      ',' | ';' if depth <= 0 => {
+        parts.push(&text[start..i]);
+        start = i + 1;
+      }
+      _ => (),
+    }
+  }
+  parts.push(&text[start..]);
+  parts
+}
+
+/// `-114,1 °C` -> `(-114.1, " °C")`, commas and points are both accepted as decimal separator.
+fn parse_number(text: &str) -> Option<(f64, &str)> {
+  let (sign, unsigned) = match text.chars().next()? {
+    c @ '-' | c @ '−' => (-1.0, &text[c.len_utf8()..]),
+    '+' => (1.0, &text[1..]),
+    _ => (1.0, text),
+  };
+
+  let bytes = unsigned.as_bytes();
+  let mut end = 0;
+  while end < bytes.len() {
+    let is_digit = bytes[end].is_ascii_digit();
+    let is_separator = (bytes[end] == b'.' || bytes[end] == b',')
+      && end > 0
+      && matches!(bytes.get(end + 1), Some(next) if next.is_ascii_digit());
+    if !is_digit && !is_separator {
+      break;
+    }
+    end += 1;
+  }
+  if end == 0 {
+    return None;
+  }
+
+  let number: f64 = unsigned[..end].replace(',', ".").parse().ok()?;
+  Some((sign * number, &unsigned[end..]))
+}
+
+impl Quantity {
+  /// Parses texts like `78 °C`, `ca. 180 °C (Zers.)`, `839 ... 841 °C` or `46,07 g/mol`.
+  pub fn parse(text: &str) -> Quantity {
+    let text = text.replace('\u{a0}', " ").trim().to_string();
+    let mut qualifiers = Vec::new();
+
+    let mut rest = text.as_str();
+    loop {
+      if let Some(stripped) = strip_any(rest, &APPROXIMATELY) {
+        qualifiers.push(Qualifier::Approximately);
+        rest = stripped;
+      } else if let Some(stripped) = strip_any(rest, &LESS_THAN) {
+        qualifiers.push(Qualifier::LessThan);
+        rest = stripped;
+      } else if let Some(stripped) = strip_any(rest, &GREATER_THAN) {
+        qualifiers.push(Qualifier::GreaterThan);
+        rest = stripped;
+      } else {
+        break;
+      }
+    }
+
+    let lowercase = text.to_lowercase();
+    if DECOMPOSES.iter().any(|keyword| lowercase.contains(keyword)) {
+      qualifiers.push(Qualifier::Decomposes);
+    }
+    if SUBLIMES.iter().any(|keyword| lowercase.contains(keyword)) {
+      qualifiers.push(Qualifier::Sublimes);
+    }
+
+    let (value, rest) = match parse_number(rest) {
+      Some((from, rest)) => {
+        let rest = rest.trim_start();
+        match strip_any(rest, &RANGE_SEPARATORS).and_then(parse_number) {
+          Some((to, rest)) => (Some(QuantityValue::Range(from, to)), rest),
+          None => (Some(QuantityValue::Exact(from)), rest),
+        }
+      }
+      None => (None, rest),
+    };
+
+    let unit = match value {
+      // the unit ends before comments like `(Zers.)` or further values
+      Some(_) => {
+        let unit = rest.split(&['(', ',', ';'][..]).next().unwrap_or_default();
+        let mut tokens = unit.split_whitespace();
+        match tokens.next() {
+          // `° C`
+          Some("°") => tokens.next().map(|token| Unit::from_symbol(&format!("°{}", token))),
+          Some(token)
+            if DECOMPOSES
+              .iter()
+              .chain(SUBLIMES.iter())
+              .any(|k| token.to_lowercase().contains(k)) =>
+          {
+            None
+          }
+          Some(token) => Some(Unit::from_symbol(token)),
+          None => None,
+        }
+      }
+      None => None,
+    };
+
+    let alternatives = match value {
+      Some(_) => split_values(rest)
+        .into_iter()
+        .skip(1)
+        .map(Quantity::parse)
+        .filter(|alternative| alternative.value.is_some() && alternative.unit.is_some())
+        .collect(),
+      None => Vec::new(),
+    };
+
+    Quantity {
+      text,
+      value,
+      unit,
+      qualifiers,
+      alternatives,
+    }
+  }
+
+  /// Creates a quantity from its parts, the text is generated.
+  pub fn new(value: QuantityValue, unit: Option<Unit>, qualifiers: Vec<Qualifier>) -> Quantity {
+    let mut text = String::new();
+    for qualifier in qualifiers.iter() {
+      match qualifier {
+        Qualifier::Approximately => text.push_str("ca. "),
+        Qualifier::LessThan => text.push_str("< "),
+        Qualifier::GreaterThan => text.push_str("> "),
+        _ => (),
+      }
+    }
+    match value {
+      QuantityValue::Exact(value) => text.push_str(&value.to_string()),
+      QuantityValue::Range(from, to) => text.push_str(&format!("{} – {}", from, to)),
+    }
+    if let Some(unit) = &unit {
+      text.push(' ');
+      text.push_str(&String::from(unit.clone()));
+    }
+    for qualifier in qualifiers.iter() {
+      match qualifier {
+        Qualifier::Decomposes => text.push_str(" (dec.)"),
+        Qualifier::Sublimes => text.push_str(" (subl.)"),
+        _ => (),
+      }
+    }
+
+    Quantity {
+      text,
+      value: Some(value),
+      unit,
+      qualifiers,
+      alternatives: Vec::new(),
+    }
+  }
+}
+
+/* #endregion */
+
+impl Quantity {
+  pub fn value(&self) -> Option<QuantityValue> {
+    self.value
+  }
+
+  /// Returns the exact value or the lower end of a range.
+  pub fn number(&self) -> Option<f64> {
+    match self.value? {
+      QuantityValue::Exact(value) => Some(value),
+      QuantityValue::Range(from, _) => Some(from),
+    }
+  }
+
+  pub fn unit(&self) -> Option<&Unit> {
+    self.unit.as_ref()
+  }
+
+  pub fn qualifiers(&self) -> &[Qualifier] {
+    &self.qualifiers
+  }
+
+  pub fn has_qualifier(&self, qualifier: Qualifier) -> bool {
+    self.qualifiers.contains(&qualifier)
+  }
+
+  pub fn alternatives(&self) -> &[Quantity] {
+    &self.alternatives
+  }
+
+  /// Returns this quantity or the alternative that is given in `unit`.
+  pub fn in_unit(&self, unit: &Unit) -> Option<&Quantity> {
+    std::iter::once(self)
+      .chain(self.alternatives.iter())
+      .find(|quantity| quantity.unit.as_ref() == Some(unit))
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.text
+  }
+
+  /// Converts the quantity into another unit, `None` if the units are not convertible.
+  /// Converted quantities lose the original text.
+  pub fn convert(&self, unit: Unit) -> Option<Quantity> {
+    if self.unit.as_ref() == Some(&unit) {
+      return Some(self.clone());
+    }
+
+    let convert: fn(f64) -> f64 = match (self.unit.as_ref()?, &unit) {
+      (Unit::Celsius, Unit::Fahrenheit) => |value| value * 9.0 / 5.0 + 32.0,
+      (Unit::Fahrenheit, Unit::Celsius) => |value| (value - 32.0) * 5.0 / 9.0,
+      _ => return None,
+    };
+    let value = match self.value? {
+      QuantityValue::Exact(value) => QuantityValue::Exact(convert(value)),
+      QuantityValue::Range(from, to) => QuantityValue::Range(convert(from), convert(to)),
+    };
+
+    Some(Quantity::new(value, Some(unit), self.qualifiers.clone()))
+  }
+
+  /// Compares the values, `None` if one of them has no value or the units can't be converted into each other.
+  pub fn compare(&self, other: &Quantity) -> Option<Ordering> {
+    let other = other.convert(self.unit.clone()?)?;
+    self.number()?.partial_cmp(&other.number()?)
+  }
+}
+
+impl From<&str> for Quantity {
+  fn from(text: &str) -> Quantity {
+    Quantity::parse(text)
+  }
+}
+
+impl fmt::Display for Quantity {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.text)
+  }
+}
+
+impl Serialize for Quantity {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.serialize_str(&self.text)
+  }
+}
+
+impl<'de> Deserialize<'de> for Quantity {
+  fn deserialize<D>(deserializer: D) -> Result<Quantity, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Ok(Quantity::parse(&String::deserialize(deserializer)?))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse() {
+    let quantity = Quantity::parse("-114,1 °C");
+    assert_eq!(quantity.value(), Some(QuantityValue::Exact(-114.1)));
+    assert_eq!(quantity.unit(), Some(&Unit::Celsius));
+    assert!(quantity.qualifiers().is_empty());
+    assert_eq!(quantity.to_string(), "-114,1 °C");
+
+    let quantity = Quantity::parse("839\u{a0}...\u{a0} 841 °C");
+    assert_eq!(quantity.value(), Some(QuantityValue::Range(839.0, 841.0)));
+    assert_eq!(quantity.unit(), Some(&Unit::Celsius));
+
+    let quantity = Quantity::parse("ca. 186 °C (Zers.)");
+    assert_eq!(quantity.value(), Some(QuantityValue::Exact(186.0)));
+    assert_eq!(quantity.unit(), Some(&Unit::Celsius));
+    assert_eq!(
+      quantity.qualifiers(),
+      &[Qualifier::Approximately, Qualifier::Decomposes]
+    );
+
+    assert_eq!(Quantity::parse(" 691 mg/kg").unit(), Some(&Unit::MilligramPerKilogram));
+    assert_eq!(Quantity::parse("182,95 g/mol").number(), Some(182.95));
+    let mak = Quantity::parse("200 ppm, 380 mg/m³ (TRGS 900, DFG)");
+    assert_eq!(mak.unit(), Some(&Unit::PartsPerMillion));
+    assert_eq!(mak.alternatives().len(), 1);
+    assert_eq!(
+      mak.in_unit(&Unit::Custom("mg/m³".into())).unwrap().number(),
+      Some(380.0)
+    );
+    assert_eq!(mak.in_unit(&Unit::PartsPerMillion), Some(&mak));
+    assert_eq!(mak.to_string(), "200 ppm, 380 mg/m³ (TRGS 900, DFG)");
+    assert!(Quantity::parse("46,07 g/mol").alternatives().is_empty());
+    assert!(Quantity::parse("78 °C (1013 hPa, 20 °C)").alternatives().is_empty());
+    assert_eq!(Quantity::parse("> 300 °C").qualifiers(), &[Qualifier::GreaterThan]);
+    assert_eq!(Quantity::parse("150 °C decomposes").unit(), Some(&Unit::Celsius));
+    assert_eq!(
+      Quantity::parse("-5 - -3 °C").value(),
+      Some(QuantityValue::Range(-5.0, -3.0))
+    );
+    assert_eq!(Quantity::parse("78").unit(), None);
+
+    let text = Quantity::parse("siehe Bemerkung");
+    assert_eq!(text.value(), None);
+    assert_eq!(text.to_string(), "siehe Bemerkung");
+  }
+
+  #[test]
+  fn test_new() {
+    let quantity = Quantity::new(
+      QuantityValue::Exact(180.5),
+      Some(Unit::Celsius),
+      vec![Qualifier::Approximately, Qualifier::Decomposes],
+    );
+    assert_eq!(quantity.to_string(), "ca. 180.5 °C (dec.)");
+    assert_eq!(Quantity::parse(quantity.as_str()), quantity);
+  }
+
+  #[test]
+  fn test_convert_and_compare() {
+    let celsius = Quantity::parse("100 °C");
+    let fahrenheit = celsius.convert(Unit::Fahrenheit).unwrap();
+    assert_eq!(fahrenheit.number(), Some(212.0));
+    assert_eq!(fahrenheit.convert(Unit::Celsius).unwrap().number(), Some(100.0));
+    assert_eq!(celsius.convert(Unit::GramPerMol), None);
+
+    let compare = |a: &str, b: &str| Quantity::parse(a).compare(&Quantity::parse(b));
+    assert_eq!(compare("78 °C", "200 °F"), Some(Ordering::Less));
+    assert_eq!(compare("-114 °C", "78 °C"), Some(Ordering::Less));
+    assert_eq!(compare("100 °C", "212 °F"), Some(Ordering::Equal));
+    assert_eq!(compare("78 °C", "46 g/mol"), None);
+  }
+
+  #[test]
+  fn test_serde() {
+    let quantity: Quantity = serde_json::from_str("\"46,07 g/mol\"").unwrap();
+    assert_eq!(quantity.unit(), Some(&Unit::GramPerMol));
+    assert_eq!(serde_json::to_string(&quantity).unwrap(), "\"46,07 g/mol\"");
+  }
+}
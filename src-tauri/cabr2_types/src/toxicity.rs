@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Quantity;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ToxicityType {
+  LD50,
+  LC50,
+}
+
+/// An acute toxicity value like `LD50 oral Ratte: 691 mg/kg`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Toxicity {
+  #[serde(rename = "type")]
+  pub kind: ToxicityType,
+  /// How the substance was applied as written by the source, e.g. `oral`, `dermal` or `inhalativ`.
+  pub route: String,
+  /// The tested species as written by the source, e.g. `Ratte` or `rat`.
+  pub species: String,
+  pub value: Quantity,
+}
+
+const ORAL: [&str; 1] = ["oral"];
+const RAT: [&str; 3] = ["ratte", "rat", "rats"];
+
+impl Toxicity {
+  /// Parses headings like `LD50 oral Ratte` or `LC50 inhalativ Ratte (4 h)`, other values (e.g. `LDLo`) are skipped.
+  pub fn parse(heading: &str, value: Quantity) -> Option<Toxicity> {
+    let mut words = heading.split_whitespace();
+    let kind = match words.next()?.to_uppercase().as_str() {
+      "LD50" => ToxicityType::LD50,
+      "LC50" => ToxicityType::LC50,
+      _ => return None,
+    };
+    let route = words.next()?.into();
+    let species = words.collect::<Vec<&str>>().join(" ");
+
+    Some(Toxicity {
+      kind,
+      route,
+      species,
+      value,
+    })
+  }
+
+  fn is_oral(&self) -> bool {
+    ORAL.contains(&self.route.to_lowercase().as_str())
+  }
+
+  fn is_rat(&self) -> bool {
+    let species = self.species.to_lowercase();
+    matches!(species.split_whitespace().next(), Some(first) if RAT.contains(&first))
+  }
+
+  /// Chooses the value printed as lethal dose if the user doesn't choose another one: the oral LD50 of rats
+  /// (used for the classification), then any oral LD50, then any LD50.
+  pub fn preferred(entries: &[Toxicity]) -> Option<&Toxicity> {
+    let ld50 = || entries.iter().filter(|t| t.kind == ToxicityType::LD50);
+    ld50()
+      .find(|t| t.is_oral() && t.is_rat())
+      .or_else(|| ld50().find(|t| t.is_oral()))
+      .or_else(|| ld50().next())
+  }
+
+  /// Whether there is more than one value that `preferred` could choose, e.g. for the hydrate and the anhydrous salt.
+  pub fn is_ambiguous(entries: &[Toxicity]) -> bool {
+    match Toxicity::preferred(entries) {
+      Some(preferred) => {
+        entries
+          .iter()
+          .filter(|t| t.kind == preferred.kind && t.route == preferred.route && t.species == preferred.species)
+          .count()
+          > 1
+      }
+      None => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn toxicity(heading: &str, value: &str) -> Toxicity {
+    Toxicity::parse(heading, Quantity::parse(value)).unwrap()
+  }
+
+  #[test]
+  fn test_parse() {
+    let inhalation = toxicity("LC50 inhalativ Ratte (4 h)", "20000 ppm");
+    assert_eq!(inhalation.kind, ToxicityType::LC50);
+    assert_eq!(inhalation.route, "inhalativ");
+    assert_eq!(inhalation.species, "Ratte (4 h)");
+    assert_eq!(inhalation.value.number(), Some(20000.0));
+
+    assert_eq!(Toxicity::parse("LDLo oral Mensch", Quantity::parse("1400 mg/kg")), None);
+    assert_eq!(Toxicity::parse("LD50", Quantity::parse("1400 mg/kg")), None);
+  }
+
+  #[test]
+  fn test_preferred() {
+    let entries = vec![
+      toxicity("LC50 inhalativ Ratte (4 h)", "20000 ppm"),
+      toxicity("LD50 dermal Kaninchen", "17100 mg/kg"),
+      toxicity("LD50 oral Maus", "3450 mg/kg"),
+      toxicity("LD50 oral Ratte", "7060 mg/kg"),
+    ];
+    assert_eq!(Toxicity::preferred(&entries), Some(&entries[3]));
+    assert_eq!(Toxicity::preferred(&entries[..3]), Some(&entries[2]));
+    assert_eq!(Toxicity::preferred(&entries[..2]), Some(&entries[1]));
+    assert_eq!(Toxicity::preferred(&entries[..1]), None);
+    assert!(!Toxicity::is_ambiguous(&entries));
+
+    let hydrates = vec![
+      toxicity("LD50 oral rat", "691 mg/kg"),
+      toxicity("LD50 oral rat", "434 mg/kg"),
+    ];
+    assert_eq!(Toxicity::preferred(&hydrates), Some(&hydrates[0]));
+    assert!(Toxicity::is_ambiguous(&hydrates));
+  }
+}
@@ -0,0 +1,15 @@
+use std::fmt::Display;
+
+use serde::Serialize;
+use warp::{http::StatusCode, reply};
+
+/// Converts the result of a handler into a json response, errors are returned with status code 500.
+pub fn into_reply<T: Serialize, E: Serialize + Display>(result: Result<T, E>) -> reply::WithStatus<reply::Json> {
+  match result {
+    Ok(value) => reply::with_status(reply::json(&value), StatusCode::OK),
+    Err(err) => {
+      log::warn!("request failed: {}", err);
+      reply::with_status(reply::json(&err), StatusCode::INTERNAL_SERVER_ERROR)
+    }
+  }
+}
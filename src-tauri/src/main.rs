@@ -31,6 +31,37 @@ compile_error!("you must specify one of these features: 'tauri_app', 'webserver'
 compile_error!("you can only use one of these features: 'tauri_app', 'webserver'!");
 
 #[cfg(feature = "webserver")]
-fn main() {
-  unimplemented!();
+#[tokio::main]
+async fn main() {
+  use std::net::SocketAddr;
+
+  use warp::Filter;
+
+  const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+  // must be initialized first
+  cabr2_logger::setup_logger().await.unwrap();
+
+  cabr2_search::handler::init_providers().await;
+  cabr2_load_save::handler::init_handlers(cabr2_search::handler::get_provider_mapping().await).await;
+
+  let address: SocketAddr = std::env::var("CABR2_ADDRESS")
+    .unwrap_or_else(|_| DEFAULT_ADDRESS.into())
+    .parse()
+    .expect("CABR2_ADDRESS is not a valid socket address");
+
+  let mut frontend_dir = cabr2_config::DATA_DIR.clone();
+  frontend_dir.push("dist");
+  frontend_dir.push("CaBr2");
+  log::debug!("serving frontend from: {:?}", frontend_dir);
+
+  let api = warp::path("api").and(warp::path("v1")).and(
+    cabr2_config::webserver::get_routes()
+      .or(cabr2_load_save::webserver::get_routes())
+      .or(cabr2_search::webserver::get_routes()),
+  );
+  let routes = api.or(warp::fs::dir(frontend_dir)).with(warp::log("cabr2::webserver"));
+
+  log::info!("listening on: http://{}", address);
+  warp::serve(routes).run(address).await;
 }
@@ -6,13 +6,23 @@ export class SubstanceData {
   molarMass: Data<string | undefined>;
   meltingPoint: Data<string | undefined>;
   boilingPoint: Data<string | undefined>;
+  density: Data<string | undefined>;
+  flashPoint: Data<string | undefined>;
+  ignitionTemperature: Data<string | undefined>;
+  /** lower and upper explosion limit as range, e.g. `3,1 ... 27,7 Vol%` */
+  explosionLimits: Data<string | undefined>;
   waterHazardClass: Data<string | undefined>;
   hPhrases: Data<[string, string][]>;
   pPhrases: Data<[string, string][]>;
   signalWord: Data<string | undefined>;
   symbols: Data<string[]>;
+  /** the value printed on the sheet, one of `toxicity` or entered by the user */
   lethalDose: Data<string | undefined>;
+  /** all acute toxicity values of the source */
+  toxicity: Data<Toxicity[]>;
   mak: Data<string | undefined>;
+  /** dangerous reactions with other substances, one entry per line */
+  reactions: Data<string[]>;
   amount: Amount | undefined;
   readonly source: Source;
   checked: boolean;
@@ -29,13 +39,19 @@ export class SubstanceData {
     this.molarMass = EMPTY_DATA();
     this.meltingPoint = EMPTY_DATA();
     this.boilingPoint = EMPTY_DATA();
+    this.density = EMPTY_DATA();
+    this.flashPoint = EMPTY_DATA();
+    this.ignitionTemperature = EMPTY_DATA();
+    this.explosionLimits = EMPTY_DATA();
     this.waterHazardClass = EMPTY_DATA();
     this.hPhrases = EMPTY_LIST_DATA();
     this.pPhrases = EMPTY_LIST_DATA();
     this.signalWord = EMPTY_DATA();
     this.symbols = EMPTY_LIST_DATA();
     this.lethalDose = EMPTY_DATA();
+    this.toxicity = EMPTY_LIST_DATA();
     this.mak = EMPTY_DATA();
+    this.reactions = EMPTY_LIST_DATA();
 
     this.alternativeNames = [];
 
@@ -93,6 +109,15 @@ export interface Data<T> {
   readonly originalData: T;
 }
 
+export interface Toxicity {
+  type: 'LD50' | 'LC50';
+  /** how the substance was applied as written by the source, e.g. `oral` */
+  route: string;
+  /** the tested species as written by the source, e.g. `Ratte` */
+  species: string;
+  value: string;
+}
+
 export interface Image {
   src: string;
   alt: string;
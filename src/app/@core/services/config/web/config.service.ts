@@ -1,31 +1,45 @@
+import { Observable, of } from 'rxjs';
+import { catchError } from 'rxjs/operators';
+import { HttpClient } from '@angular/common/http';
+import { Injectable } from '@angular/core';
+
+import { API_URL, backendError } from '../../native/web/browser.service';
 import { ConfigModel } from 'src/app/@core/models/config.model';
 import { GHSSymbols } from 'src/app/@core/models/global.model';
 import { IConfigService } from '../config.interface';
-import { Injectable } from '@angular/core';
-import { Observable } from 'rxjs';
 
-/* eslint-disable @typescript-eslint/no-unused-vars */
-// TODO use vars and remove this lines
+/** The config of the server can't be changed by the clients, so every browser keeps its own. */
+const CONFIG_KEY = 'cabr2.config';
+
 @Injectable()
 export class ConfigWebService implements IConfigService {
+  constructor(private http: HttpClient) {}
+
   getProgramVersion(): Observable<string> {
-    throw new Error('Method not implemented.');
+    return this.http.get<string>(`${API_URL}/config/get_program_version`).pipe(catchError(backendError));
   }
 
   getConfig(): Observable<ConfigModel> {
-    throw new Error('Method not implemented.');
+    const saved = localStorage.getItem(CONFIG_KEY);
+    if (saved) {
+      return of(JSON.parse(saved));
+    }
+    return this.http.get<ConfigModel>(`${API_URL}/config/get_config`).pipe(catchError(backendError));
   }
 
-  //@typescript-eslint-disable-next-line no-unused-vars
   saveConfig(config: ConfigModel): Observable<void> {
-    throw new Error('Method not implemented.');
+    return new Observable((sub) => {
+      localStorage.setItem(CONFIG_KEY, JSON.stringify(config));
+      sub.next();
+      sub.complete();
+    });
   }
 
   getHazardSymbols(): Observable<GHSSymbols> {
-    throw new Error('Method not implemented.');
+    return this.http.get<GHSSymbols>(`${API_URL}/config/get_hazard_symbols`).pipe(catchError(backendError));
   }
 
   getPromptHtml(name: string): Observable<string> {
-    throw new Error('Method not implemented.');
+    return this.http.post<string>(`${API_URL}/config/get_prompt_html`, { name }).pipe(catchError(backendError));
   }
 }
@@ -1,19 +1,41 @@
-import { II18nService, LocalizedStrings, LocalizedStringsHeader } from '../i18n.interface';
+import { HttpClient } from '@angular/common/http';
 import { Injectable } from '@angular/core';
-import Logger from 'src/app/@core/utils/logger';
+import { merge } from 'lodash';
 import { Observable } from 'rxjs';
+import { catchError } from 'rxjs/operators';
 
-/* eslint-disable @typescript-eslint/no-unused-vars */
-// TODO use vars and remove this lines
+import { API_URL, backendError } from '../../native/web/browser.service';
+import { getDefaultStrings, II18nService, LocalizedStrings, LocalizedStringsHeader } from '../i18n.interface';
+import Logger from 'src/app/@core/utils/logger';
 
 const logger = new Logger('i18n-service-web');
 
 @Injectable()
 export class I18nWebService implements II18nService {
+  constructor(private http: HttpClient) {}
+
   getAvailableLanguages(): Observable<LocalizedStringsHeader[]> {
-    throw new Error('Method not implemented.');
+    return this.http
+      .get<LocalizedStringsHeader[]>(`${API_URL}/config/get_available_languages`)
+      .pipe(catchError(backendError));
   }
+
   getLocalizedStrings(language: string): Observable<LocalizedStrings> {
-    throw new Error('Method not implemented.');
+    return new Observable((sub) => {
+      this.http
+        .post<LocalizedStrings>(`${API_URL}/config/get_localized_strings`, { language })
+        .pipe(catchError(backendError))
+        .subscribe(
+          (strings) => {
+            logger.trace('loading localized strings successful:', strings);
+            const mergedStrings = { ...getDefaultStrings() };
+            merge(mergedStrings, strings);
+            sub.next(mergedStrings);
+          },
+          (err) => {
+            logger.error('loading localized strings failed:', err);
+          },
+        );
+    });
   }
 }
@@ -11,7 +11,25 @@ export interface CaBr2Document {
   disposal: string[];
 }
 
+export type OptionKind =
+  | { type: 'boolean' }
+  | { type: 'integer'; min: number; max: number }
+  | { type: 'text' }
+  | { type: 'choice'; choices: string[] };
+
+/** An option of a loader or saver, without default the backend decides (e.g. from the config). */
+export type OptionSchema = OptionKind & {
+  key: string;
+  default?: unknown;
+};
+
+export type Options = Record<string, unknown>;
+
+export interface DocumentType extends DialogFilter {
+  options: OptionSchema[];
+}
+
 export interface DocumentTypes {
-  load: DialogFilter[];
-  save: DialogFilter[];
+  load: DocumentType[];
+  save: DocumentType[];
 }
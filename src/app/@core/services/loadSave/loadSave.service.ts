@@ -1,4 +1,4 @@
-import { CaBr2Document, DocumentTypes } from './loadSave.model';
+import { CaBr2Document, DocumentTypes, Options } from './loadSave.model';
 import { ILoadSaveService } from './loadSave.interface';
 import { INativeService } from '../native/native.interface';
 import { Injectable } from '@angular/core';
@@ -8,16 +8,17 @@ import { Observable } from 'rxjs';
 export class LoadSaveService implements ILoadSaveService {
   constructor(private tauriService: INativeService) {}
 
-  saveDocument(fileType: string, filename: string, document: CaBr2Document): Observable<string> {
+  saveDocument(fileType: string, filename: string, document: CaBr2Document, options?: Options): Observable<string> {
     return this.tauriService.promisified('plugin:cabr2_load_save|save_document', {
       fileType,
       filename,
       document,
+      options,
     });
   }
 
-  loadDocument(filename: string): Observable<CaBr2Document> {
-    return this.tauriService.promisified('plugin:cabr2_load_save|load_document', { filename });
+  loadDocument(filename: string, options?: Options): Observable<CaBr2Document> {
+    return this.tauriService.promisified('plugin:cabr2_load_save|load_document', { filename, options });
   }
 
   getAvailableDocumentTypes(): Observable<DocumentTypes> {
@@ -1,22 +1,84 @@
-import { CaBr2Document, DocumentTypes } from '../loadSave.model';
-import { ILoadSaveService } from '../loadSave.interface';
+import { HttpClient, HttpErrorResponse, HttpParams } from '@angular/common/http';
+import { from, Observable, throwError } from 'rxjs';
+import { catchError, finalize, map, switchMap } from 'rxjs/operators';
 import { Injectable } from '@angular/core';
-import { Observable } from 'rxjs';
 
-/* eslint-disable @typescript-eslint/no-unused-vars */
-// TODO use vars and remove this lines
+import { API_URL, backendError } from '../../native/web/browser.service';
+import { CaBr2Document, DocumentTypes, Options } from '../loadSave.model';
+import { ILoadSaveService } from '../loadSave.interface';
+
+/** Errors of requests with a file as response are blobs too. */
+const blobError = (err: HttpErrorResponse): Observable<never> => {
+  if (!(err.error instanceof Blob)) {
+    return backendError(err);
+  }
+  return from(err.error.text()).pipe(
+    switchMap((text) => {
+      try {
+        return throwError(JSON.parse(text));
+      } catch {
+        return throwError(text || err.message);
+      }
+    }),
+  );
+};
 
 @Injectable()
 export class LoadSaveService implements ILoadSaveService {
-  saveDocument(fileType: string, filename: string, document: CaBr2Document): Observable<string> {
-    throw new Error('Method not implemented.');
+  constructor(private http: HttpClient) {}
+
+  /**
+   * The generated file is downloaded by the browser as `filename`.
+   */
+  saveDocument(fileType: string, filename: string, document: CaBr2Document, options?: Options): Observable<string> {
+    return this.http
+      .post(`${API_URL}/load_save/save_document`, { fileType, document, options }, { responseType: 'blob' })
+      .pipe(
+        catchError(blobError),
+        map((file) => {
+          const url = URL.createObjectURL(file);
+          const link = window.document.createElement('a');
+          link.href = url;
+          link.download = filename;
+          link.click();
+          URL.revokeObjectURL(url);
+          return filename;
+        }),
+      );
   }
 
-  loadDocument(filename: string): Observable<CaBr2Document> {
-    throw new Error('Method not implemented.');
+  /**
+   * `filename` is the object url of the selected file with its name as fragment, see `BrowserService.open`.
+   */
+  loadDocument(filename: string, options?: Options): Observable<CaBr2Document> {
+    const separator = filename.lastIndexOf('#');
+    const name = decodeURIComponent(filename.slice(separator + 1));
+    const fileType = name.includes('.') ? name.slice(name.lastIndexOf('.') + 1) : '';
+    if (separator < 0 || !fileType) {
+      return throwError(`unknown file type: ${name}`);
+    }
+    const url = filename.slice(0, separator);
+
+    // the server parses values that are valid JSON, strings are passed as they are
+    let params = new HttpParams();
+    for (const [key, value] of Object.entries(options ?? {})) {
+      params = params.set(key, typeof value === 'string' ? value : JSON.stringify(value));
+    }
+
+    return this.http.get(url, { responseType: 'blob' }).pipe(
+      switchMap((file) =>
+        this.http.post<CaBr2Document>(`${API_URL}/load_save/load_document/${encodeURIComponent(fileType)}`, file, {
+          params,
+        }),
+      ),
+      catchError(backendError),
+      finalize(() => URL.revokeObjectURL(url)),
+    );
   }
 
   getAvailableDocumentTypes(): Observable<DocumentTypes> {
-    throw new Error('Method not implemented.');
+    return this.http
+      .get<DocumentTypes>(`${API_URL}/load_save/get_available_document_types`)
+      .pipe(catchError(backendError));
   }
 }
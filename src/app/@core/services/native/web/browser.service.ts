@@ -1,23 +1,83 @@
-import { INativeService } from '../native.interface';
+import { HttpClient, HttpErrorResponse } from '@angular/common/http';
+import { Observable, throwError } from 'rxjs';
+import { catchError } from 'rxjs/operators';
 import { Injectable } from '@angular/core';
-import { Observable } from 'rxjs';
 
-/* eslint-disable @typescript-eslint/no-unused-vars */
-// TODO use vars and remove this lines
+import { INativeService } from '../native.interface';
+
+/** The webserver serves the backend commands under this path next to the frontend. */
+export const API_URL = 'api/v1';
+
+/** The crates of the tauri plugins are served as `api/v1/<route>/<command>`. */
+const PLUGIN_ROUTES: Record<string, string> = {
+  cabr2_config: 'config',
+  cabr2_load_save: 'load_save',
+  cabr2_search: 'search',
+};
+
+/**
+ * The webserver replies with the serialized error of the backend, like the tauri commands do.
+ */
+export const backendError = (err: HttpErrorResponse): Observable<never> => throwError(err.error ?? err.message);
 
 @Injectable()
 export class BrowserService implements INativeService {
+  constructor(private http: HttpClient) {}
+
   openUrl(url: string, openWith?: string): Promise<void> {
-    throw new Error('Method not implemented.');
+    window.open(url, openWith ?? '_blank', 'noopener');
+    return Promise.resolve();
   }
-  open(options?: any): Observable<string | string[]> {
-    throw new Error('Method not implemented.');
+
+  /**
+   * The browser doesn't reveal the path of a file, so the selected file is returned as object url with its name as
+   * fragment, e.g. `blob:http://localhost/...#document.cb2`.
+   */
+  open(options?: { filters?: { extensions: string[] }[] }): Observable<string | string[]> {
+    return new Observable((sub) => {
+      const input = document.createElement('input');
+      input.type = 'file';
+      input.accept = (options?.filters ?? [])
+        .flatMap((filter) => filter.extensions)
+        .map((extension) => `.${extension}`)
+        .join(',');
+      input.onchange = () => {
+        const file = input.files?.item(0);
+        if (file) {
+          sub.next(`${URL.createObjectURL(file)}#${encodeURIComponent(file.name)}`);
+          sub.complete();
+        } else {
+          sub.error('no file selected');
+        }
+      };
+      input.click();
+    });
   }
-  save(options?: any): Observable<string | string[]> {
-    throw new Error('Method not implemented.');
+
+  /**
+   * Files are downloaded by the browser, so only the name of the file is returned.
+   */
+  save(options?: { filters?: { extensions: string[] }[] }): Observable<string | string[]> {
+    return new Observable((sub) => {
+      const extension = options?.filters?.[0]?.extensions[0];
+      sub.next(extension ? `cabr2.${extension}` : 'cabr2');
+      sub.complete();
+    });
   }
 
+  /**
+   * Sends a tauri command (e.g. `plugin:cabr2_config|get_config`) to the route of the webserver, commands without
+   * arguments are `GET` requests.
+   */
   promisified<T>(cmd: string, args?: any): Observable<T> {
-    throw new Error('Method not implemented.');
+    const matches = /^plugin:(\w+)\|(\w+)$/.exec(cmd);
+    const route = matches ? PLUGIN_ROUTES[matches[1]] : undefined;
+    if (!matches || !route) {
+      return throwError(`unknown command: ${cmd}`);
+    }
+
+    const url = `${API_URL}/${route}/${matches[2]}`;
+    const request = args === undefined ? this.http.get<T>(url) : this.http.post<T>(url, args);
+    return request.pipe(catchError(backendError));
   }
 }
@@ -1,4 +1,5 @@
 import {
+  ParsedSubstanceData,
   Provider,
   ProviderMapping,
   SearchArguments,
@@ -8,7 +9,6 @@ import {
 } from './provider.model';
 
 import { BehaviorSubject, Observable } from 'rxjs';
-import { SubstanceData } from '../../models/substances.model';
 
 export abstract class IProviderService {
   abstract searchTypeMappingsSubject: BehaviorSubject<SearchTypeMapping[]>;
@@ -70,8 +70,8 @@ export abstract class IProviderService {
   abstract search(provider: string, args: SearchArguments): Observable<SearchResult[]>;
 
   /**
-   * Returns the parsed data of a substance from the given provider or an error
-   * stating the cause of the failure when parsing the data.
+   * Returns the parsed data of a substance from the given provider together with
+   * the status of every field or an error stating the cause of the failure when parsing the data.
    */
-  abstract substanceData(provider: string, identifier: string): Observable<SubstanceData>;
+  abstract substanceData(provider: string, identifier: string): Observable<ParsedSubstanceData>;
 }
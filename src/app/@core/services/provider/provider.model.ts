@@ -1,3 +1,5 @@
+import { SubstanceData } from '../../models/substances.model';
+
 export type SearchType = 'chemicalName' | 'chemicalFormula' | 'numbers' | 'fullText';
 export const searchTypes: SearchType[] = ['chemicalName', 'chemicalFormula', 'numbers', 'fullText'];
 
@@ -28,3 +30,13 @@ export interface Provider {
 }
 
 export type ProviderMapping = Map<string, Provider>;
+
+export type FieldStatus =
+  | { status: 'found' | 'missing' | 'empty' | 'multiple' }
+  | { status: 'parseError'; message: string; chapter?: string; subchapter?: string };
+
+export interface ParsedSubstanceData {
+  substanceData: SubstanceData;
+  /** the status of every extracted field, keyed by the name of the field in SubstanceData */
+  report: Record<string, FieldStatus>;
+}
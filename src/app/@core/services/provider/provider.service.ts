@@ -6,6 +6,7 @@ import { GlobalModel } from '../../models/global.model';
 import { IProviderService } from './provider.interface';
 
 import {
+  ParsedSubstanceData,
   Provider,
   ProviderMapping,
   SearchArguments,
@@ -15,7 +16,6 @@ import {
   searchTypes,
 } from './provider.model';
 import { INativeService } from '../native/native.interface';
-import { SubstanceData } from '../../models/substances.model';
 
 @Injectable()
 export class ProviderService implements IProviderService {
@@ -54,7 +54,7 @@ export class ProviderService implements IProviderService {
     });
   }
 
-  substanceData(provider: string, identifier: string): Observable<SubstanceData> {
+  substanceData(provider: string, identifier: string): Observable<ParsedSubstanceData> {
     return this.nativeService.promisified('plugin:cabr2_search|get_substance_data', {
       provider,
       identifier,
@@ -1,19 +1,23 @@
 import {
+  ParsedSubstanceData,
   Provider,
   ProviderMapping,
   SearchArguments,
   SearchResult,
   SearchType,
   SearchTypeMapping,
+  searchTypes,
 } from '../provider.model';
 
 import { BehaviorSubject, Observable } from 'rxjs';
+import { catchError, first } from 'rxjs/operators';
+import { HttpClient } from '@angular/common/http';
 import { Injectable } from '@angular/core';
+
+import { API_URL, backendError } from '../../native/web/browser.service';
+import { GlobalModel } from 'src/app/@core/models/global.model';
 import { IProviderService } from '../provider.interface';
-import { SubstanceData } from 'src/app/@core/models/substances.model';
 
-/* eslint-disable @typescript-eslint/no-unused-vars */
-// TODO use vars and remove this lines
 @Injectable()
 export class ProviderService implements IProviderService {
   searchTypeMappingsSubject = new BehaviorSubject<SearchTypeMapping[]>([]);
@@ -22,16 +26,35 @@ export class ProviderService implements IProviderService {
   providerMappingsSubject = new BehaviorSubject<ProviderMapping>(new Map());
   providerMappingsObservable = this.providerMappingsSubject.asObservable();
 
+  constructor(private http: HttpClient, private globals: GlobalModel) {
+    this.globals.localizedStringsObservable.subscribe((strings) =>
+      this.searchTypeMappingsSubject.next(searchTypes.map((t) => ({ viewValue: strings.search.types[t], value: t }))),
+    );
+
+    this.getAvailableProviders()
+      .pipe(first())
+      .subscribe((providers) => this.providerMappingsSubject.next(new Map(providers.map((p) => [p.identifier, p]))));
+  }
+
   getAvailableProviders(): Observable<Provider[]> {
-    throw new Error('Method not implemented.');
+    return this.http.get<Provider[]>(`${API_URL}/search/get_available_providers`).pipe(catchError(backendError));
   }
+
   searchSuggestions(provider: string, searchType: SearchType, query: string): Observable<string[]> {
-    throw new Error('Method not implemented.');
+    return this.http
+      .post<string[]>(`${API_URL}/search/search_suggestions`, { provider, pattern: query, searchType })
+      .pipe(catchError(backendError));
   }
+
   search(provider: string, args: SearchArguments): Observable<SearchResult[]> {
-    throw new Error('Method not implemented.');
+    return this.http
+      .post<SearchResult[]>(`${API_URL}/search/search`, { provider, arguments: args })
+      .pipe(catchError(backendError));
   }
-  substanceData(provider: string, identifier: string): Observable<SubstanceData> {
-    throw new Error('Method not implemented.');
+
+  substanceData(provider: string, identifier: string): Observable<ParsedSubstanceData> {
+    return this.http
+      .post<ParsedSubstanceData>(`${API_URL}/search/get_substance_data`, { provider, identifier })
+      .pipe(catchError(backendError));
   }
 }
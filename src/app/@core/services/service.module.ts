@@ -1,3 +1,4 @@
+import { HttpClient, HttpClientModule } from '@angular/common/http';
 import { NgModule } from '@angular/core';
 
 import { BrowserService } from './native/web/browser.service';
@@ -18,70 +19,72 @@ import { TauriService } from './native/tauri.service';
 import { LoadSaveService as WebLoadSaveService } from './loadSave/web/loadSave.service';
 import { ProviderService as WebProviderService } from './provider/web/provider.service';
 
-const configFactory = (nativeService: INativeService): IConfigService => {
+const configFactory = (nativeService: INativeService, http: HttpClient): IConfigService => {
   if (environment.web) {
-    return new ConfigWebService();
+    return new ConfigWebService(http);
   } else {
     return new ConfigService(nativeService);
   }
 };
 
-const i18nFactory = (nativeService: INativeService): II18nService => {
+const i18nFactory = (nativeService: INativeService, http: HttpClient): II18nService => {
   if (environment.web) {
-    return new I18nWebService();
+    return new I18nWebService(http);
   } else {
     return new I18nService(nativeService);
   }
 };
 
-const loadSaveFactory = (nativeService: INativeService): ILoadSaveService => {
+const loadSaveFactory = (nativeService: INativeService, http: HttpClient): ILoadSaveService => {
   if (environment.web) {
-    return new WebLoadSaveService();
+    return new WebLoadSaveService(http);
   } else {
     return new LoadSaveService(nativeService);
   }
 };
 
-const nativeFactory = (): INativeService => {
+const nativeFactory = (http: HttpClient): INativeService => {
   if (environment.web) {
-    return new BrowserService();
+    return new BrowserService(http);
   } else {
     return new TauriService();
   }
 };
 
-const providerFactory = (nativeService: INativeService, globals: GlobalModel): IProviderService => {
+const providerFactory = (nativeService: INativeService, globals: GlobalModel, http: HttpClient): IProviderService => {
   if (environment.web) {
-    return new WebProviderService();
+    return new WebProviderService(http, globals);
   } else {
     return new ProviderService(nativeService, globals);
   }
 };
 @NgModule({
+  imports: [HttpClientModule],
   providers: [
     {
       provide: IConfigService,
       useFactory: configFactory,
-      deps: [INativeService],
+      deps: [INativeService, HttpClient],
     },
     {
       provide: II18nService,
       useFactory: i18nFactory,
-      deps: [INativeService],
+      deps: [INativeService, HttpClient],
     },
     {
       provide: ILoadSaveService,
       useFactory: loadSaveFactory,
-      deps: [INativeService],
+      deps: [INativeService, HttpClient],
     },
     {
       provide: INativeService,
       useFactory: nativeFactory,
+      deps: [HttpClient],
     },
     {
       provide: IProviderService,
       useFactory: providerFactory,
-      deps: [INativeService, GlobalModel],
+      deps: [INativeService, GlobalModel, HttpClient],
     },
   ],
 })
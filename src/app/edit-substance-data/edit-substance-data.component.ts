@@ -9,6 +9,7 @@ import {
   getViewValue,
   modifiedOrOriginal,
   SubstanceData,
+  Toxicity,
   Unit,
   unitGroups,
 } from '../@core/models/substances.model';
@@ -74,6 +75,10 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
       molarMass: modifiedOrOriginal(this.data.molarMass) ?? '',
       meltingPoint: modifiedOrOriginal(this.data.meltingPoint) ?? '',
       boilingPoint: modifiedOrOriginal(this.data.boilingPoint) ?? '',
+      density: modifiedOrOriginal(this.data.density) ?? '',
+      flashPoint: modifiedOrOriginal(this.data.flashPoint) ?? '',
+      ignitionTemperature: modifiedOrOriginal(this.data.ignitionTemperature) ?? '',
+      explosionLimits: modifiedOrOriginal(this.data.explosionLimits) ?? '',
       waterHazardClass: modifiedOrOriginal(this.data.waterHazardClass) ?? '',
       hPhrases: this.formBuilder.array(
         modifiedOrOriginal<[string, string][]>(this.data.hPhrases).map((hPhrase) => this.initHPhrases(hPhrase)),
@@ -85,6 +90,7 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
       symbols: this.formBuilder.array(modifiedOrOriginal(this.data.symbols)),
       lethalDose: modifiedOrOriginal(this.data.lethalDose) ?? '',
       mak: modifiedOrOriginal(this.data.mak) ?? '',
+      reactions: modifiedOrOriginal(this.data.reactions).join('\n'),
       amount: this.formBuilder.group({
         value: amount.value,
         unit: amount.unit,
@@ -110,6 +116,22 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
     return this.form?.get('amount') as FormGroup;
   }
 
+  get toxicity(): Toxicity[] {
+    return modifiedOrOriginal(this.data.toxicity);
+  }
+
+  /** e.g. `LD50 oral Ratte: 691 mg/kg` */
+  toxicityViewValue(toxicity: Toxicity): string {
+    return `${toxicity.type} ${toxicity.route} ${toxicity.species}: ${toxicity.value}`;
+  }
+
+  /** Prints the chosen toxicity value as lethal dose. */
+  selectToxicity(toxicity: Toxicity): void {
+    const control = this.form.get('lethalDose');
+    control?.setValue(toxicity.value);
+    control?.markAsTouched();
+  }
+
   initHPhrases(value: [string, string]): FormGroup {
     return this.formBuilder.group({
       hNumber: [value[0], Validators.pattern('^(H\\d{3}\\w?\\+?)+$')],
@@ -223,10 +245,15 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
       molarMass: this.data.molarMass.originalData ?? '',
       meltingPoint: this.data.meltingPoint.originalData ?? '',
       boilingPoint: this.data.boilingPoint.originalData ?? '',
+      density: this.data.density.originalData ?? '',
+      flashPoint: this.data.flashPoint.originalData ?? '',
+      ignitionTemperature: this.data.ignitionTemperature.originalData ?? '',
+      explosionLimits: this.data.explosionLimits.originalData ?? '',
       waterHazardClass: this.data.waterHazardClass.originalData ?? '',
       signalWord: this.data.signalWord.originalData ?? '',
       lethalDose: this.data.lethalDose.originalData ?? '',
       mak: this.data.mak.originalData ?? '',
+      reactions: this.data.reactions.originalData.join('\n'),
       amount: { value: '', unit: Unit.GRAM },
       hPhrases: this.data.hPhrases.originalData.map((phrase) => ({
         hNumber: phrase[0],
@@ -268,6 +295,10 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
       molarMass: this.evaluateForm('molarMass', this.data.molarMass),
       meltingPoint: this.evaluateForm('meltingPoint', this.data.meltingPoint),
       boilingPoint: this.evaluateForm('boilingPoint', this.data.boilingPoint),
+      density: this.evaluateForm('density', this.data.density),
+      flashPoint: this.evaluateForm('flashPoint', this.data.flashPoint),
+      ignitionTemperature: this.evaluateForm('ignitionTemperature', this.data.ignitionTemperature),
+      explosionLimits: this.evaluateForm('explosionLimits', this.data.explosionLimits),
       waterHazardClass: this.evaluateForm('waterHazardClass', this.data.waterHazardClass),
       hPhrases: this.evaluateFormArray(
         this.hPhrases,
@@ -283,6 +314,7 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
       symbols: this.evaluateFormArray(this.symbols, (symbol) => symbol?.value, this.data.symbols),
       lethalDose: this.evaluateForm('lethalDose', this.data.lethalDose),
       mak: this.evaluateForm('mak', this.data.mak),
+      reactions: this.evaluateLines('reactions', this.data.reactions),
 
       amount: this.evaluateAmount(),
     });
@@ -337,6 +369,25 @@ export class EditSubstanceDataComponent implements OnInit, OnDestroy {
     return currentData;
   }
 
+  /** Evaluates a textarea with one entry per line, empty lines are dropped. */
+  private evaluateLines(formControlName: string, currentData: Data<string[]>): Data<string[]> {
+    const control = this.form?.get(formControlName);
+
+    if (control?.touched) {
+      const lines = (control.value as string)
+        .split('\n')
+        .map((line) => line.trim())
+        .filter((line) => line.length > 0);
+      let retData: Data<string[]> = { originalData: currentData.originalData };
+      // if new value is still/again the original value don't set modified field
+      if (!compareArrays(lines, currentData.originalData)) {
+        retData = { ...retData, modifiedData: lines };
+      }
+      return retData;
+    }
+    return currentData;
+  }
+
   private evaluateFormArray<T>(
     formArray: FormArray,
     mapCallback: (value: AbstractControl) => T,
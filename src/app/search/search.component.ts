@@ -85,7 +85,11 @@ export class SearchComponent implements OnInit {
     dialogRef.afterClosed().subscribe((result) => {
       if (result) {
         this.providerService.substanceData(providerIdentifier, result.zvgNumber).subscribe(
-          (value) => {
+          ({ substanceData: value, report }) => {
+            const uncertain = Object.entries(report).filter(([, field]) => field.status !== 'found');
+            if (uncertain.length > 0) {
+              logger.debug('fields that need to be checked:', uncertain);
+            }
             const cas = this.modifiedOrOriginal(value.cas);
             if (
               cas &&